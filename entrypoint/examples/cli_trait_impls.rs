@@ -1,9 +1,11 @@
 //! override default trait impls w/ CLI args
 
+#![allow(unused_crate_dependencies)]
+
 use entrypoint::prelude::*;
 
 /// input args are minimal... use dotenv files to define stuff
-#[derive(entrypoint::clap::Parser, Debug)]
+#[derive(entrypoint::clap::Parser, EntrypointDefault, Clone, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// additional dotenv files to process; order matters!
@@ -28,16 +30,10 @@ impl DotEnvParserConfig for Args {
 }
 
 impl LoggerConfig for Args {
-    /// use value of env::var(LOG_LEVEL) (probably set via dotenv)
+    /// use value of `env::var(LOG_LEVEL)` (probably set via dotenv)
     /// default to "info" if undefined
-    fn default_log_level(&self) -> entrypoint::tracing_subscriber::filter::LevelFilter {
-        <entrypoint::tracing::Level as std::str::FromStr>::from_str(
-            std::env::var("LOG_LEVEL")
-                .unwrap_or(String::from("info"))
-                .as_str(),
-        )
-        .expect("failed to parse Level")
-        .into()
+    fn default_log_level_env(&self) -> Option<String> {
+        Some(String::from("LOG_LEVEL"))
     }
 }
 
@@ -1,10 +1,12 @@
 //! dynamic logging reload tui
 
+#![allow(unused_crate_dependencies)]
+
 use entrypoint::prelude::*;
 use std::io;
 use tokio::signal;
 
-#[derive(entrypoint::clap::Parser, DotEnvDefault, Debug)]
+#[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, Clone, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {}
 
@@ -27,10 +29,11 @@ impl LoggerConfig for Args {
     }
 }
 
-#[tokio::main]
-#[entrypoint::entrypoint]
+impl RuntimeConfig for Args {}
+
+#[entrypoint::entrypoint(tokio)]
 async fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
-    let logging = tokio::spawn(async {
+    let _logging = tokio::spawn(async {
         loop {
             tokio::time::sleep(std::time::Duration::from_millis(500)).await;
             trace!("this is a trace");
@@ -41,18 +44,18 @@ async fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
         }
     });
 
-    let cli = tokio::spawn(async {
+    let _cli = tokio::spawn(async {
         loop {
             let mut input = String::new();
-            if let Ok(bytes) = io::stdin().read_line(&mut input) {
+            if let Ok(_bytes) = io::stdin().read_line(&mut input) {
                 error!(input);
             }
         }
     });
 
-    let random_changes = tokio::spawn(async {
+    let _random_changes = tokio::spawn(async {
         loop {
-            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
             //let _ = reload.modify(|layer| *layer.filter_mut() = self.default_log_level());
             //let _ = reload.modify(|layer| *layer.inner_mut().writer_mut() = self.default_log_writer());
             // #FIXME - format
@@ -0,0 +1,27 @@
+//! application metadata attached to events
+
+/// the host name (if known)
+///
+/// Sourced from `HOSTNAME`/`COMPUTERNAME`, falling back to `/etc/hostname`.
+pub(crate) fn hostname() -> Option<String> {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok())
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|hostname| hostname.trim().to_owned())
+        .filter(|hostname| !hostname.is_empty())
+}
+
+/// root span w/ `app.name`, `app.version`, `pid`, and `hostname` fields
+///
+/// `ERROR` level, so it's enabled whenever anything is logged.
+pub(crate) fn span(command: &clap::Command) -> tracing::Span {
+    tracing::error_span!(
+        parent: None,
+        "app",
+        app.name = command.get_name(),
+        app.version = command.get_version(),
+        pid = std::process::id(),
+        hostname = hostname(),
+    )
+}
@@ -0,0 +1,194 @@
+//! build information (git commit, build timestamp, rustc version, etc.) embedded at compile time
+
+/// builtin flag that prints [`describe`] and exits
+pub(crate) const FLAG: &str = "--build-info";
+
+/// env var prefix of the values set by [`emit_build_info`] (i.e. read by [`build_info!`](crate::build_info!))
+const PREFIX: &str = "ENTRYPOINT_BUILD_";
+
+/// build information of the application, embedded at compile time
+///
+/// Populated by the [`build_info!`](crate::build_info!) macro from the values emitted by [`emit_build_info`]
+/// (in the application's build script). Refer to [`EntrypointConfig::build_info`](crate::EntrypointConfig::build_info).
+///
+/// Fields are [`None`] if unknown (e.g. no build script, or not built from a git checkout).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// git commit hash (abbreviated), suffixed w/ `-dirty` if there were uncommitted changes
+    pub git_commit: Option<&'static str>,
+    /// build timestamp (RFC 3339, UTC); `SOURCE_DATE_EPOCH` is honored for reproducible builds
+    pub timestamp: Option<&'static str>,
+    /// `rustc --version`
+    pub rustc: Option<&'static str>,
+    /// target triple (e.g. `x86_64-unknown-linux-gnu`)
+    pub target: Option<&'static str>,
+    /// cargo profile (i.e. `debug` or `release`)
+    pub profile: Option<&'static str>,
+    /// enabled cargo features of the application, comma separated
+    pub features: Option<&'static str>,
+}
+
+impl BuildInfo {
+    /// whether nothing is known (i.e. [`emit_build_info`] wasn't run)
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.git_commit.is_none()
+            && self.timestamp.is_none()
+            && self.rustc.is_none()
+            && self.target.is_none()
+            && self.profile.is_none()
+            && self.features.is_none()
+    }
+
+    /// known fields, as (label, value) pairs
+    fn fields(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        [
+            ("commit", self.git_commit),
+            ("built", self.timestamp),
+            ("rustc", self.rustc),
+            ("target", self.target),
+            ("profile", self.profile),
+            ("features", self.features),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| Some((label, value?)))
+    }
+}
+
+/// `version` followed by a line per known [`BuildInfo`] field (i.e. the long `--version` output)
+pub(crate) fn long_version(version: &str, info: &BuildInfo) -> String {
+    std::iter::once(version.to_owned())
+        .chain(
+            info.fields()
+                .map(|(label, value)| format!("{label:<9}{value}")),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// describe the supplied [`clap::Command`]'s build as JSON
+pub(crate) fn describe(command: &clap::Command, info: &BuildInfo) -> serde_json::Value {
+    serde_json::json!({
+        "name": command.get_name(),
+        "version": command.get_version(),
+        "git_commit": info.git_commit,
+        "timestamp": info.timestamp,
+        "rustc": info.rustc,
+        "target": info.target,
+        "profile": info.profile,
+        "features": info.features.map(|features| features.split(',').collect::<Vec<_>>()),
+    })
+}
+
+/// embed [`BuildInfo`] into the application; call this from its build script (i.e. `build.rs`)
+///
+/// Emits `cargo:rustc-env` instructions read by [`build_info!`](crate::build_info!)
+/// (which [`EntrypointDefault`](crate::macros::EntrypointDefault) uses).
+/// Requires `entrypoint` as a `[build-dependencies]` entry.
+///
+/// # Examples
+/// ```no_run
+/// // in build.rs `main`
+/// entrypoint::emit_build_info();
+/// ```
+pub fn emit_build_info() {
+    let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    if let Some(commit) = git(&["rev-parse", "--short=12", "HEAD"]) {
+        let dirty = git(&["status", "--porcelain", "--untracked-files=no"]).is_some();
+        emit(
+            "GIT_COMMIT",
+            &format!("{commit}{}", if dirty { "-dirty" } else { "" }),
+        );
+
+        // i.e. rebuild on commit/checkout
+        if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+            println!("cargo:rerun-if-changed={git_dir}/HEAD");
+            println!("cargo:rerun-if-changed={git_dir}/index");
+        }
+    }
+
+    let timestamp = var("SOURCE_DATE_EPOCH")
+        .and_then(|epoch| epoch.parse().ok())
+        .and_then(|epoch| chrono::DateTime::from_timestamp(epoch, 0))
+        .unwrap_or_else(chrono::Utc::now);
+    emit(
+        "TIMESTAMP",
+        &timestamp.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    );
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let rustc = var("RUSTC").unwrap_or_else(|| String::from("rustc"));
+    if let Some(version) = command(&rustc, &["--version"]) {
+        emit("RUSTC", &version);
+    }
+
+    if let Some(target) = var("TARGET") {
+        emit("TARGET", &target);
+    }
+
+    if let Some(profile) = var("PROFILE") {
+        emit("PROFILE", &profile);
+    }
+
+    let mut features: Vec<String> = std::env::vars()
+        .filter_map(|(name, _)| {
+            let feature = name.strip_prefix("CARGO_FEATURE_")?;
+            Some(feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+    emit("FEATURES", &features.join(","));
+}
+
+/// emit a `cargo:rustc-env` instruction for `name` (w/ the [`PREFIX`])
+fn emit(name: &str, value: &str) {
+    println!("cargo:rustc-env={PREFIX}{name}={value}");
+}
+
+/// trimmed stdout of a successful `git` command (w/ non-empty output)
+fn git(args: &[&str]) -> Option<String> {
+    command("git", args)
+}
+
+/// trimmed stdout of a successful `program` run (w/ non-empty output)
+fn command(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_owned()).filter(|out| !out.is_empty())
+}
+
+/// the [`BuildInfo`] embedded by [`emit_build_info`] (i.e. in the calling crate)
+///
+/// [`EntrypointDefault`](crate::macros::EntrypointDefault) implements [`EntrypointConfig::build_info`](crate::EntrypointConfig::build_info) w/ this.
+///
+/// # Examples
+/// ```
+/// # #[derive(clap::Parser)]
+/// # struct Args {}
+/// impl entrypoint::EntrypointConfig for Args {
+///     fn build_info() -> entrypoint::BuildInfo {
+///         entrypoint::build_info!()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        $crate::BuildInfo {
+            git_commit: option_env!("ENTRYPOINT_BUILD_GIT_COMMIT"),
+            timestamp: option_env!("ENTRYPOINT_BUILD_TIMESTAMP"),
+            rustc: option_env!("ENTRYPOINT_BUILD_RUSTC"),
+            target: option_env!("ENTRYPOINT_BUILD_TARGET"),
+            profile: option_env!("ENTRYPOINT_BUILD_PROFILE"),
+            features: match option_env!("ENTRYPOINT_BUILD_FEATURES") {
+                Some("") => None,
+                features => features,
+            },
+        }
+    };
+}
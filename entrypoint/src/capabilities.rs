@@ -0,0 +1,150 @@
+//! machine-readable (JSON) introspection of an entrypoint binary
+
+/// builtin flag that prints [`describe`] and exits
+pub(crate) const FLAG: &str = "--capabilities";
+
+/// entrypoint [features](crate#feature-flags) enabled at compile time
+pub(crate) fn features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+
+    if cfg!(feature = "macros") {
+        features.push("macros");
+    }
+
+    if cfg!(feature = "async-std") {
+        features.push("async-std");
+    }
+
+    if cfg!(feature = "bunyan") {
+        features.push("bunyan");
+    }
+
+    if cfg!(feature = "crash-report") {
+        features.push("crash-report");
+    }
+
+    if cfg!(feature = "daemon") {
+        features.push("daemon");
+    }
+
+    if cfg!(feature = "eventlog") {
+        features.push("eventlog");
+    }
+
+    if cfg!(feature = "flame") {
+        features.push("flame");
+    }
+
+    if cfg!(feature = "gelf") {
+        features.push("gelf");
+    }
+
+    if cfg!(feature = "health") {
+        features.push("health");
+    }
+
+    if cfg!(feature = "journald") {
+        features.push("journald");
+    }
+
+    if cfg!(feature = "log") {
+        features.push("log");
+    }
+
+    if cfg!(feature = "metrics") {
+        features.push("metrics");
+    }
+
+    if cfg!(feature = "otel") {
+        features.push("otel");
+    }
+
+    if cfg!(feature = "sentry") {
+        features.push("sentry");
+    }
+
+    if cfg!(feature = "shutdown") {
+        features.push("shutdown");
+    }
+
+    if cfg!(feature = "signals") {
+        features.push("signals");
+    }
+
+    if cfg!(feature = "smol") {
+        features.push("smol");
+    }
+
+    if cfg!(feature = "syslog") {
+        features.push("syslog");
+    }
+
+    if cfg!(feature = "systemd") {
+        features.push("systemd");
+    }
+
+    if cfg!(feature = "tokio") {
+        features.push("tokio");
+    }
+
+    if cfg!(feature = "tokio-console") {
+        features.push("tokio-console");
+    }
+
+    if cfg!(feature = "toml") {
+        features.push("toml");
+    }
+
+    if cfg!(feature = "tree") {
+        features.push("tree");
+    }
+
+    if cfg!(feature = "update-notifier") {
+        features.push("update-notifier");
+    }
+
+    if cfg!(feature = "vault") {
+        features.push("vault");
+    }
+
+    if cfg!(feature = "wasm") {
+        features.push("wasm");
+    }
+
+    if cfg!(feature = "watch") {
+        features.push("watch");
+    }
+
+    if cfg!(feature = "windows-service") {
+        features.push("windows-service");
+    }
+
+    if cfg!(feature = "yaml") {
+        features.push("yaml");
+    }
+
+    features
+}
+
+/// describe the supplied [`clap::Command`] (and this build of entrypoint) as JSON
+pub(crate) fn describe(command: &clap::Command) -> serde_json::Value {
+    let required_env_vars: Vec<String> = command
+        .get_arguments()
+        .filter(|arg| arg.is_required_set())
+        .filter_map(|arg| arg.get_env())
+        .map(|env| env.to_string_lossy().into_owned())
+        .collect();
+
+    let subcommands: Vec<&str> = command
+        .get_subcommands()
+        .map(clap::Command::get_name)
+        .collect();
+
+    serde_json::json!({
+        "name": command.get_name(),
+        "version": command.get_version(),
+        "features": features(),
+        "required_env_vars": required_env_vars,
+        "subcommands": subcommands,
+    })
+}
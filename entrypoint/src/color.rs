@@ -0,0 +1,47 @@
+//! builtin `--color` flag & ANSI auto-detection
+
+use std::io::IsTerminal;
+
+/// builtin arg id/long name
+pub(crate) const FLAG: &str = "color";
+
+/// [`clap::ColorChoice`] parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+static CHOICE: std::sync::Mutex<clap::ColorChoice> = std::sync::Mutex::new(clap::ColorChoice::Auto);
+
+/// the builtin `--color` arg
+pub(crate) fn arg() -> clap::Arg {
+    clap::Arg::new(FLAG)
+        .long(FLAG)
+        .value_name("WHEN")
+        .value_parser(clap::value_parser!(clap::ColorChoice))
+        .default_value("auto")
+        .global(true)
+        .help("When to use ANSI colors in log output")
+}
+
+/// record the [`clap::ColorChoice`] from parsed matches (if the builtin arg is present)
+pub(crate) fn set(matches: &clap::ArgMatches) {
+    if let Ok(Some(choice)) = matches.try_get_one::<clap::ColorChoice>(FLAG) {
+        *CHOICE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = *choice;
+    }
+}
+
+/// whether ANSI colors should be used, per the recorded [`clap::ColorChoice`]
+///
+/// `auto` disables colors if [`NO_COLOR`](https://no-color.org) is set or stdout isn't a terminal.
+pub(crate) fn ansi() -> bool {
+    let choice = *CHOICE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    match choice {
+        clap::ColorChoice::Always => true,
+        clap::ColorChoice::Never => false,
+        clap::ColorChoice::Auto => {
+            std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                && std::io::stdout().is_terminal()
+        }
+    }
+}
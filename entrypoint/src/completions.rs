@@ -0,0 +1,49 @@
+//! builtin `--completions <SHELL>` flag (i.e. shell completion scripts)
+
+/// builtin arg id/long name
+pub(crate) const FLAG: &str = "completions";
+
+/// the builtin `--completions <SHELL>` arg
+pub(crate) fn arg() -> clap::Arg {
+    clap::Arg::new(FLAG)
+        .long(FLAG)
+        .value_name("SHELL")
+        .value_parser(clap::value_parser!(clap_complete::Shell))
+        .global(true)
+        .help("Print shell completions, then exit")
+}
+
+/// the shell passed (i.e. `--completions <SHELL>` or `--completions=<SHELL>`), if any & valid
+///
+/// Checked before the user's [`clap::Parser`] runs, so completions work regardless of required args/subcommands.
+/// Anything after a `--` terminator is ignored.
+pub(crate) fn requested() -> Option<clap_complete::Shell> {
+    let long = format!("--{FLAG}");
+    let mut args = std::env::args_os()
+        .skip(1)
+        .take_while(|arg| arg != "--")
+        .map(|arg| arg.to_string_lossy().into_owned());
+
+    let shell = loop {
+        let arg = args.next()?;
+        if arg == long {
+            break args.next()?;
+        }
+        if let Some(shell) = arg
+            .strip_prefix(&long)
+            .and_then(|arg| arg.strip_prefix('='))
+        {
+            break shell.to_owned();
+        }
+    };
+
+    <clap_complete::Shell as clap::ValueEnum>::from_str(&shell, true).ok()
+}
+
+/// the completion script for `shell`
+pub(crate) fn generate(command: &mut clap::Command, shell: clap_complete::Shell) -> String {
+    let name = command.get_name().to_owned();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, command, name, &mut script);
+    String::from_utf8_lossy(&script).into_owned()
+}
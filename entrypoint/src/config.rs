@@ -0,0 +1,258 @@
+//! layered (i.e. structured) config files, merged w/ environment variables
+
+use crate::env_config;
+use serde::de::{self, Error as _, Visitor};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+/// a structured config file (refer to [`ConfigParserConfig::config_sources`](crate::ConfigParserConfig::config_sources))
+///
+/// The format is selected by extension: `.json`, `.toml` (w/ the `toml` [feature](crate#feature-flags)),
+/// or `.yaml`/`.yml` (w/ the `yaml` [feature](crate#feature-flags)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigFile {
+    /// processing fails if missing
+    Required(PathBuf),
+    /// skipped if missing (e.g. a developer overlay)
+    Optional(PathBuf),
+}
+
+impl ConfigFile {
+    /// path of the file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Required(path) | Self::Optional(path) => path,
+        }
+    }
+
+    /// whether processing fails if the file is missing
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        matches!(self, Self::Required(_))
+    }
+}
+
+impl From<PathBuf> for ConfigFile {
+    fn from(path: PathBuf) -> Self {
+        Self::Required(path)
+    }
+}
+
+/// a source of structured config (e.g. a [`ConfigFile`], or a remote config service)
+///
+/// Refer to [`ConfigParserConfig::config_sources`](crate::ConfigParserConfig::config_sources).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// struct Defaults;
+///
+/// impl ConfigSource for Defaults {
+///     fn name(&self) -> String {
+///         String::from("defaults")
+///     }
+///
+///     fn load(&self) -> anyhow::Result<Option<entrypoint::serde_json::Value>> {
+///         Ok(Some(entrypoint::serde_json::json!({ "database": { "pool": 8 } })))
+///     }
+/// }
+/// ```
+pub trait ConfigSource {
+    /// name of the source (e.g. a path), for logs & errors
+    fn name(&self) -> String;
+
+    /// the config tree; [`None`] if skipped (e.g. a missing optional file)
+    ///
+    /// # Errors
+    /// * the config can't be read/parsed
+    fn load(&self) -> anyhow::Result<Option<Value>>;
+}
+
+impl ConfigSource for ConfigFile {
+    fn name(&self) -> String {
+        self.path().display().to_string()
+    }
+
+    fn load(&self) -> anyhow::Result<Option<Value>> {
+        let path = self.path();
+        if !path.is_file() {
+            if self.is_required() {
+                anyhow::bail!("config file not found ({})", path.display());
+            }
+
+            tracing::info!("optional config file not found ({})", path.display());
+            return Ok(None);
+        }
+
+        parse(path, &std::fs::read_to_string(path)?).map(Some)
+    }
+}
+
+/// parse `content` per the `path` extension
+fn parse(path: &Path, content: &str) -> anyhow::Result<Value> {
+    match path.extension().and_then(std::ffi::OsStr::to_str) {
+        Some("json") => Ok(serde_json::from_str(content)?),
+        #[cfg(feature = "toml")]
+        Some("toml") => Ok(toml::from_str(content)?),
+        #[cfg(not(feature = "toml"))]
+        Some("toml") => anyhow::bail!("TOML config files require the `toml` feature"),
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => Ok(serde_yaml::from_str(content)?),
+        #[cfg(not(feature = "yaml"))]
+        Some("yaml" | "yml") => anyhow::bail!("YAML config files require the `yaml` feature"),
+        _ => anyhow::bail!("unsupported config file format ({})", path.display()),
+    }
+}
+
+/// merge `layer` into `base`; nested objects are merged, anything else is replaced
+pub(crate) fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+/// the environment as a config tree
+///
+/// Only variables w/ the `prefix` (if any) are included, and the prefix is removed.
+/// Keys are lowercase, and nested by the `separator` (e.g. `MYAPP_DATABASE__URL` -> `database.url`).
+pub(crate) fn env_layer(prefix: Option<&str>, separator: &str) -> Value {
+    let prefix = prefix.unwrap_or_default().to_ascii_lowercase();
+
+    let mut tree = Value::Object(Map::new());
+    for (key, value) in std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+    {
+        let key = key.to_ascii_lowercase();
+        let Some(key) = key.strip_prefix(&prefix).filter(|key| !key.is_empty()) else {
+            continue;
+        };
+
+        let leaf = key
+            .rsplit(separator)
+            .fold(Value::String(value), |node, part| {
+                Value::Object(Map::from_iter([(part.to_owned(), node)]))
+            });
+        merge(&mut tree, leaf);
+    }
+
+    tree
+}
+
+/// deserialize the merged config `tree` into `C`
+///
+/// String values (e.g. from the environment) are parsed per the field type, like [`crate::from_env`].
+///
+/// # Errors
+/// * a required field is missing
+/// * a value can't be parsed as its field type
+pub(crate) fn from_tree<C: de::DeserializeOwned>(tree: Value) -> anyhow::Result<C> {
+    Ok(C::deserialize(Layered(tree))?)
+}
+
+/// a merged config (sub)tree
+struct Layered(Value);
+
+impl de::IntoDeserializer<'_, de::value::Error> for Layered {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// `deserialize_*` methods that parse string values (refer to [`env_config::Value`])
+macro_rules! leaf {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0 {
+                    Value::String(value) => env_config::Value(value).$method(visitor),
+                    value => Self(value).deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Layered {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(value),
+            Value::Number(value) => value.deserialize_any(visitor).map_err(Self::Error::custom),
+            Value::String(value) => visitor.visit_string(value),
+            Value::Array(values) => visitor.visit_seq(de::value::SeqDeserializer::new(
+                values.into_iter().map(Self),
+            )),
+            Value::Object(values) => visitor.visit_map(de::value::MapDeserializer::new(
+                values.into_iter().map(|(key, value)| (key, Self(value))),
+            )),
+        }
+    }
+
+    leaf! {
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_seq,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Self(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(value) => {
+                env_config::Value(value).deserialize_enum(name, variants, visitor)
+            }
+            value => value
+                .deserialize_enum(name, variants, visitor)
+                .map_err(Self::Error::custom),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
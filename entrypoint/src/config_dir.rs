@@ -0,0 +1,83 @@
+//! platform-standard config directory discovery (e.g. `$XDG_CONFIG_HOME/<app>/`)
+
+use std::path::PathBuf;
+
+/// platform-standard config directory for the `app` (e.g. the [`clap::Command`] name)
+///
+/// * Linux (& other unix): `$XDG_CONFIG_HOME/<app>/`, or `~/.config/<app>/`
+/// * macOS: `~/Library/Application Support/<app>/`
+/// * Windows: `%APPDATA%\<app>\`
+///
+/// [`None`] if the base directory can't be determined (e.g. `HOME` isn't set, or on `wasm32`).
+/// The directory isn't created (or required to exist).
+///
+/// # Examples
+/// ```
+/// if let Some(dir) = entrypoint::config_dir("myapp") {
+///     println!("config files are in {}", dir.display());
+/// }
+/// ```
+#[must_use]
+pub fn config_dir(app: &str) -> Option<PathBuf> {
+    base().map(|dir| dir.join(app))
+}
+
+/// platform-standard cache directory for the `app`
+///
+/// * Linux (& other unix): `$XDG_CACHE_HOME/<app>/`, or `~/.cache/<app>/`
+/// * macOS: `~/Library/Caches/<app>/`
+/// * Windows: `%LOCALAPPDATA%\<app>\`
+///
+/// [`None`] if the base directory can't be determined; otherwise, the same as [`config_dir`].
+#[cfg(feature = "update-notifier")]
+pub(crate) fn cache_dir(app: &str) -> Option<PathBuf> {
+    cache_base().map(|dir| dir.join(app))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn base() -> Option<PathBuf> {
+    env_dir("XDG_CONFIG_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".config")))
+}
+
+#[cfg(target_os = "macos")]
+fn base() -> Option<PathBuf> {
+    env_dir("HOME").map(|home| home.join("Library").join("Application Support"))
+}
+
+#[cfg(windows)]
+fn base() -> Option<PathBuf> {
+    env_dir("APPDATA")
+}
+
+#[cfg(not(any(unix, windows)))]
+const fn base() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(all(feature = "update-notifier", unix, not(target_os = "macos")))]
+fn cache_base() -> Option<PathBuf> {
+    env_dir("XDG_CACHE_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".cache")))
+}
+
+#[cfg(all(feature = "update-notifier", target_os = "macos"))]
+fn cache_base() -> Option<PathBuf> {
+    env_dir("HOME").map(|home| home.join("Library").join("Caches"))
+}
+
+#[cfg(all(feature = "update-notifier", windows))]
+fn cache_base() -> Option<PathBuf> {
+    env_dir("LOCALAPPDATA")
+}
+
+#[cfg(all(feature = "update-notifier", not(any(unix, windows))))]
+const fn cache_base() -> Option<PathBuf> {
+    None
+}
+
+/// the `key` variable as a directory; relative paths are ignored (per the XDG spec)
+#[cfg(any(unix, windows))]
+fn env_dir(key: &str) -> Option<PathBuf> {
+    std::env::var_os(key)
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+}
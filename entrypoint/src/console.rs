@@ -0,0 +1,95 @@
+//! browser console log writer (for `wasm32` builds)
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// [`MakeWriter`] targeting the browser console (i.e. `console.error`, `console.warn`, etc.)
+///
+/// Each event is written via the console method matching its level, so browser devtools can filter them.
+/// On non-`wasm32` targets, events are written to stdout instead (e.g. for native tests of web builds).
+///
+/// This can be easily set with convenience [`macros`](crate::macros::LoggerDefault#attributes) (i.e. `#[log_writer(console)]`).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser)]
+/// # struct Args {}
+/// impl entrypoint::LoggerConfig for Args {
+///     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+///         entrypoint::ConsoleWriter
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConsoleWriter;
+
+impl<'a> MakeWriter<'a> for ConsoleWriter {
+    type Writer = ConsoleLine;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ConsoleLine::new(tracing::Level::INFO)
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        ConsoleLine::new(*meta.level())
+    }
+}
+
+/// a single buffered event; written to the console on drop
+#[derive(Debug)]
+pub struct ConsoleLine {
+    level: tracing::Level,
+    buffer: Vec<u8>,
+}
+
+impl ConsoleLine {
+    const fn new(level: tracing::Level) -> Self {
+        Self {
+            level,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl std::io::Write for ConsoleLine {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ConsoleLine {
+    fn drop(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        write(self.level, &String::from_utf8_lossy(&self.buffer));
+    }
+}
+
+/// write a formatted event via the console method for `level`
+#[cfg(target_arch = "wasm32")]
+fn write(level: tracing::Level, line: &str) {
+    use web_sys::console;
+
+    let line = line.trim_end_matches('\n').into();
+    match level {
+        tracing::Level::ERROR => console::error_1(&line),
+        tracing::Level::WARN => console::warn_1(&line),
+        tracing::Level::INFO => console::info_1(&line),
+        tracing::Level::DEBUG => console::debug_1(&line),
+        tracing::Level::TRACE => console::log_1(&line),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write(_level: tracing::Level, line: &str) {
+    use std::io::Write;
+
+    let _ = std::io::stdout().lock().write_all(line.as_bytes());
+}
@@ -0,0 +1,120 @@
+//! runtime context (i.e. the machinery built during setup) handed to the entrypoint function
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// timings recorded by the most recent [`Entrypoint::init`](crate::Entrypoint::init)
+static TIMINGS: std::sync::Mutex<Option<Timings>> = std::sync::Mutex::new(None);
+
+/// phase durations recorded during setup (refer to [`StartupTimings`])
+#[derive(Clone, Copy)]
+struct Timings {
+    started: Instant,
+    dotenv: Duration,
+    logging: Duration,
+}
+
+/// how long setup took
+///
+/// Refer to [`Context::timings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StartupTimings {
+    /// when setup started
+    pub started: Instant,
+    /// [`dotenv`](crate::DotEnvParserConfig) processing (incl. verification)
+    pub dotenv: Duration,
+    /// [logging](crate::LoggerConfig) initialization
+    pub logging: Duration,
+    /// all of setup (i.e. until the context was created, right before the entrypoint function runs)
+    pub setup: Duration,
+}
+
+/// the machinery [`Entrypoint::entrypoint`](crate::Entrypoint::entrypoint) builds during setup, for advanced applications
+///
+/// Received as the entrypoint function's second parameter w/ the `context` macro argument (refer to [`Entrypoint::entrypoint_with_context`](crate::Entrypoint::entrypoint_with_context)).
+///
+/// Not in the [`prelude`](crate::prelude) (i.e. to avoid clashing w/ [`anyhow::Context`]); use `entrypoint::Context`.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// # struct Args {}
+/// #[entrypoint::entrypoint(context)]
+/// fn main(args: Args, ctx: entrypoint::Context) -> anyhow::Result<()> {
+///     info!("setup took {:?}", ctx.timings.setup);
+///
+///     if let Some(origin) = ctx.env_origins.get("LOG_LEVEL") {
+///         info!("LOG_LEVEL was set by {origin}");
+///     }
+///
+///     if let Some(logger) = &ctx.logger {
+///         logger.set_level(LevelFilter::DEBUG)?;
+///     }
+/// #   Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Context {
+    /// observe (or wait for) a shutdown request (requires the `shutdown` [feature](crate#feature-flags))
+    #[cfg(feature = "shutdown")]
+    pub shutdown: crate::ShutdownToken,
+    /// cancelled once a shutdown is requested (requires the `tokio` [feature](crate#feature-flags);
+    /// refer to [`cancellation_token`](crate::cancellation_token))
+    #[cfg(feature = "tokio")]
+    pub cancellation: tokio_util::sync::CancellationToken,
+    /// runtime level/filter control (refer to [`logger_handle`](crate::logger_handle))
+    pub logger: Option<crate::LoggerHandle>,
+    /// origin of each (recorded) environment variable, by key (refer to [`env_origins`](crate::env_origins))
+    pub env_origins: BTreeMap<String, crate::EnvOrigin>,
+    /// how long setup took
+    pub timings: StartupTimings,
+}
+
+impl Context {
+    /// the context as of now (e.g. for applications using [`Entrypoint::init`](crate::Entrypoint::init))
+    ///
+    /// Timings are zero if [`Entrypoint::init`](crate::Entrypoint::init) hasn't run.
+    #[must_use]
+    pub fn current() -> Self {
+        let timings = timings().unwrap_or_else(|| Timings {
+            started: Instant::now(),
+            dotenv: Duration::ZERO,
+            logging: Duration::ZERO,
+        });
+
+        Self {
+            #[cfg(feature = "shutdown")]
+            shutdown: crate::ShutdownToken::default(),
+            #[cfg(feature = "tokio")]
+            cancellation: crate::cancellation_token(),
+            logger: crate::logger_handle(),
+            env_origins: crate::env_origins(),
+            timings: StartupTimings {
+                started: timings.started,
+                dotenv: timings.dotenv,
+                logging: timings.logging,
+                setup: timings.started.elapsed(),
+            },
+        }
+    }
+}
+
+fn timings() -> Option<Timings> {
+    *TIMINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// record the setup phase durations (i.e. setup `started`, then `dotenv` processing, then `logging` initialization)
+pub(crate) fn record(started: Instant, dotenv: Duration, logging: Duration) {
+    *TIMINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Timings {
+        started,
+        dotenv,
+        logging,
+    });
+}
@@ -0,0 +1,90 @@
+//! crash report files for panics (i.e. `human-panic` style)
+
+use std::io::Write;
+
+/// what to put in (& say about) a crash report
+pub(crate) struct CrashReport {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) dir: std::path::PathBuf,
+    pub(crate) contact: Option<String>,
+}
+
+/// install (once) a panic hook writing a crash report file & telling the user where to send it
+///
+/// The replaced hook (e.g. panics logged through the subscriber) runs first.
+pub(crate) fn install(report: CrashReport) {
+    static INSTALLED: std::sync::Once = std::sync::Once::new();
+
+    INSTALLED.call_once(move || {
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+
+            let mut stderr = std::io::stderr().lock();
+            match report.write(info) {
+                Ok(file) => {
+                    let _ = writeln!(
+                        stderr,
+                        "\nWell, this is embarrassing.\n\n\
+                         {name} had a problem and crashed. A crash report was written to:\n  {file}\n",
+                        name = report.name,
+                        file = file.display(),
+                    );
+                    if let Some(contact) = &report.contact {
+                        let _ = writeln!(
+                            stderr,
+                            "To help us fix it, please send the report (subject: \"{} Crash Report\") to:\n  {contact}\n",
+                            report.name,
+                        );
+                    }
+                }
+                Err(e) => {
+                    let _ = writeln!(stderr, "\n{} crashed; failed to write a crash report: {e}", report.name);
+                }
+            }
+        }));
+    });
+}
+
+impl CrashReport {
+    /// write the report for the supplied panic; returns the file written
+    fn write(&self, info: &std::panic::PanicHookInfo<'_>) -> std::io::Result<std::path::PathBuf> {
+        let thread = std::thread::current();
+        let timestamp = chrono::Utc::now();
+
+        std::fs::create_dir_all(&self.dir)?;
+        let file = self.dir.join(format!(
+            "report-{}-{}-{}.toml",
+            self.name,
+            timestamp.format("%Y%m%dT%H%M%SZ"),
+            std::process::id()
+        ));
+
+        std::fs::write(
+            &file,
+            format!(
+                "name = {:?}\n\
+                 version = {:?}\n\
+                 operating_system = \"{} ({})\"\n\
+                 timestamp = {:?}\n\
+                 thread = {:?}\n\
+                 message = {:?}\n\
+                 location = {:?}\n\
+                 backtrace = '''\n{}'''\n",
+                self.name,
+                self.version,
+                std::env::consts::OS,
+                std::env::consts::ARCH,
+                timestamp.to_rfc3339(),
+                thread.name().unwrap_or("<unnamed>"),
+                crate::panic::message(info),
+                crate::panic::location(info),
+                std::backtrace::Backtrace::force_capture(),
+            ),
+        )?;
+
+        Ok(file)
+    }
+}
@@ -0,0 +1,83 @@
+//! daemonization (i.e. detaching into the background) for unix services
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// builtin arg id/long name
+pub(crate) const FLAG: &str = "daemon";
+
+/// whether the flag was passed, as parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// the builtin `--daemon` arg
+pub(crate) fn arg() -> clap::Arg {
+    clap::Arg::new(FLAG)
+        .long(FLAG)
+        .action(clap::ArgAction::SetTrue)
+        .global(true)
+        .help("Run in the background (i.e. as a daemon)")
+}
+
+/// record whether the flag was passed from parsed matches (if the builtin arg is present)
+pub(crate) fn set(matches: &clap::ArgMatches) {
+    if let Ok(Some(requested)) = matches.try_get_one::<bool>(FLAG) {
+        REQUESTED.store(*requested, Ordering::SeqCst);
+    }
+}
+
+/// whether the flag was passed
+pub(crate) fn requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// detach from the terminal (i.e. double-fork & `setsid`); the original process exits (w/ success)
+///
+/// The working directory is kept, the pid is written to `pid_file` (if any), stdin is `/dev/null`,
+/// and stdout/stderr are appended to `output` (or `/dev/null`, if none).
+///
+/// # Errors
+/// * the `output` file can't be opened
+/// * failure forking/detaching, or writing the `pid_file`
+#[cfg(unix)]
+pub(crate) fn daemonize(
+    pid_file: Option<&std::path::Path>,
+    output: Option<&std::path::Path>,
+) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    let mut daemon = daemonize::Daemonize::new().working_directory(
+        std::env::current_dir().context("failed to read the current working directory")?,
+    );
+
+    if let Some(pid_file) = pid_file {
+        daemon = daemon.pid_file(pid_file);
+    }
+
+    if let Some(output) = output {
+        if let Some(directory) = output.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(directory).with_context(|| {
+                format!("failed to create log directory {}", directory.display())
+            })?;
+        }
+
+        let open_error = || format!("failed to open daemon output {}", output.display());
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(output)
+            .with_context(open_error)?;
+        daemon = daemon
+            .stdout(file.try_clone().with_context(open_error)?)
+            .stderr(file);
+    }
+
+    daemon.start().context("failed to daemonize")
+}
+
+/// daemonization isn't available on this platform
+#[cfg(not(unix))]
+pub(crate) fn daemonize(
+    _pid_file: Option<&std::path::Path>,
+    _output: Option<&std::path::Path>,
+) -> anyhow::Result<()> {
+    anyhow::bail!("daemon mode requires unix")
+}
@@ -0,0 +1,39 @@
+//! subcommand dispatch (i.e. routing each clap subcommand to its own handler function)
+
+/// route a (clap) subcommand to its handler function, w/ the shared args `A`
+///
+/// Typically derived for a [`clap::Subcommand`] enum (refer to the [`Dispatch`](crate::macros::Dispatch) derive macro),
+/// then run by [`Entrypoint::entrypoint_dispatch`](crate::Entrypoint::entrypoint_dispatch) once setup completes.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// struct Args {
+///     #[command(subcommand)]
+///     command: Command,
+/// }
+///
+/// #[derive(clap::Subcommand, Clone)]
+/// enum Command {
+///     Serve { port: u16 },
+///     Migrate,
+/// }
+///
+/// impl Dispatch<Args> for Command {
+///     fn dispatch(&self, args: &Args) -> anyhow::Result<()> {
+///         match self {
+///             Self::Serve { port } => info!("serving on {port}"),
+///             Self::Migrate => info!("migrating"),
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Dispatch<A> {
+    /// run the handler function of this subcommand
+    ///
+    /// # Errors
+    /// * the handler function's error
+    fn dispatch(&self, args: &A) -> anyhow::Result<()>;
+}
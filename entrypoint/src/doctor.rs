@@ -0,0 +1,183 @@
+//! builtin `--doctor` diagnostics (i.e. dotenv files, required variables, log destination & filter)
+
+use crate::DotEnvParser;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// builtin arg id/long name
+pub(crate) const FLAG: &str = "doctor";
+
+/// whether the flag was passed, as parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// the builtin `--doctor` arg
+pub(crate) fn arg() -> clap::Arg {
+    clap::Arg::new(FLAG)
+        .long(FLAG)
+        .action(clap::ArgAction::SetTrue)
+        .global(true)
+        .help("Check the configuration (dotenv files, required variables, log destination & filter), then exit")
+}
+
+/// record whether the flag was passed from parsed matches (if the builtin arg is present)
+pub(crate) fn set(matches: &clap::ArgMatches) {
+    if let Ok(Some(requested)) = matches.try_get_one::<bool>(FLAG) {
+        REQUESTED.store(*requested, Ordering::SeqCst);
+    }
+}
+
+/// whether the flag was passed
+pub(crate) fn requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// diagnostics report, built a check at a time
+#[derive(Default)]
+pub(crate) struct Report {
+    text: String,
+    problems: usize,
+}
+
+impl Report {
+    /// start a section
+    pub(crate) fn section(&mut self, title: &str) {
+        let _ = writeln!(self.text, "{title}:");
+    }
+
+    /// a passed check
+    pub(crate) fn ok(&mut self, detail: impl std::fmt::Display) {
+        let _ = writeln!(self.text, "  ok    {detail}");
+    }
+
+    /// a failed check
+    pub(crate) fn fail(&mut self, detail: impl std::fmt::Display) {
+        self.problems += 1;
+        let _ = writeln!(self.text, "  FAIL  {detail}");
+    }
+
+    /// an informational line
+    pub(crate) fn info(&mut self, detail: impl std::fmt::Display) {
+        let _ = writeln!(self.text, "        {detail}");
+    }
+
+    /// print the report (& a summary), then exit w/ failure if any check failed
+    pub(crate) fn exit(mut self) -> ! {
+        match self.problems {
+            0 => self.text.push_str("\nno problems found\n"),
+            1 => self.text.push_str("\n1 problem found\n"),
+            problems => {
+                let _ = write!(self.text, "\n{problems} problems found\n");
+            }
+        }
+
+        print!("{}", self.text);
+        std::process::exit(i32::from(self.problems > 0));
+    }
+}
+
+/// whether the log `file` (or its directory, to be created) is writable
+pub(crate) fn writable(file: &std::path::Path) -> std::io::Result<()> {
+    if file.exists() {
+        return std::fs::OpenOptions::new()
+            .append(true)
+            .open(file)
+            .map(drop);
+    }
+
+    // the nearest existing directory (i.e. missing directories are created at startup)
+    let directory = file
+        .ancestors()
+        .skip(1)
+        .map(|dir| {
+            if dir.as_os_str().is_empty() {
+                std::path::Path::new(".")
+            } else {
+                dir
+            }
+        })
+        .find(|dir| dir.is_dir())
+        .unwrap_or_else(|| std::path::Path::new("."));
+
+    let probe = directory.join(format!(".entrypoint-doctor-{}", std::process::id()));
+    std::fs::File::create(&probe)?;
+    std::fs::remove_file(probe)
+}
+
+/// check & report the configuration, given the result of dotenv processing, then exit
+pub(crate) fn run<T: crate::Entrypoint>(processed: anyhow::Result<T>) -> ! {
+    let mut report = Report::default();
+
+    let (args, mut error) = match processed {
+        Ok(args) => (args, None),
+        Err(e) => (T::parse_args(), Some(e)), // i.e. w/o (all) dotenv variables
+    };
+
+    // first, as isolated variables are only loaded on demand
+    let isolated = if args.dotenv_isolated() {
+        args.dotenv_env_map().map_err(|e| error = Some(e)).ok()
+    } else {
+        None
+    };
+
+    report.section("dotenv files (in processing order)");
+    let files = crate::dotenv::loaded();
+    if files.is_empty() && error.is_none() {
+        report.info("none found");
+    }
+    for (path, loaded) in files {
+        if loaded {
+            report.ok(path.display());
+        } else {
+            report.fail(format_args!("{} (invalid)", path.display()));
+        }
+    }
+    if let Some(e) = error {
+        report.fail(format_args!("{e:#}"));
+    }
+
+    report.section("required environment variables");
+    let required = args.required_env_vars();
+    if required.is_empty() {
+        report.info("none");
+    }
+    for var in required {
+        let set = isolated.as_ref().map_or_else(
+            || std::env::var_os(&var).is_some(),
+            |env| env.contains_key(&var),
+        );
+        if set {
+            report.ok(var);
+        } else {
+            report.fail(format_args!("{var} (missing)"));
+        }
+    }
+
+    report.section("log destination");
+    match args.default_log_file() {
+        Some(file) => {
+            let file = crate::writer::expand_home(&file);
+            match writable(&file) {
+                Ok(()) => report.ok(format_args!("{} (writable)", file.display())),
+                Err(e) => report.fail(format_args!("{} (not writable: {e})", file.display())),
+            }
+            if args.default_log_tee() {
+                report.info("also the log writer (i.e. tee)");
+            }
+        }
+        None => report.info("the log writer (i.e. no log file)"),
+    }
+
+    report.section("log filter");
+    let filter = args.default_env_filter().unwrap_or_else(|| {
+        tracing_subscriber::EnvFilter::default().add_directive(args.default_log_level().into())
+    });
+    report.info(filter);
+    if let Some(level) = args.default_log_file_level() {
+        report.info(format_args!("log file level: {level}"));
+    }
+    if let Some(level) = args.default_log_writer_level() {
+        report.info(format_args!("log writer level: {level}"));
+    }
+
+    report.exit()
+}
@@ -0,0 +1,424 @@
+//! dotenv file discovery & processing
+
+use crate::provenance::EnvOrigin;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// dotenv files processed (& whether successfully), in order, as of the last merge
+static LOADED: std::sync::Mutex<Vec<(PathBuf, bool)>> = std::sync::Mutex::new(Vec::new());
+
+fn loaded_files() -> std::sync::MutexGuard<'static, Vec<(PathBuf, bool)>> {
+    LOADED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// forget the processed files, before merging
+pub(crate) fn init() {
+    loaded_files().clear();
+}
+
+/// dotenv files processed (& whether successfully), in order (refer to [`init`])
+pub(crate) fn loaded() -> Vec<(PathBuf, bool)> {
+    loaded_files().clone()
+}
+
+/// an additional dotenv file (refer to [`DotEnvParserConfig::dotenv_files`](crate::DotEnvParserConfig::dotenv_files))
+///
+/// The path may contain `*`/`?` wildcards (e.g. `conf/*.env`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DotEnvFile {
+    /// processing fails if missing (or a pattern has no match)
+    Required(PathBuf),
+    /// skipped if missing (e.g. a developer overlay)
+    Optional(PathBuf),
+}
+
+impl DotEnvFile {
+    /// path (or wildcard pattern) of the file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Required(path) | Self::Optional(path) => path,
+        }
+    }
+
+    /// whether processing fails if the file is missing
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        matches!(self, Self::Required(_))
+    }
+}
+
+impl From<PathBuf> for DotEnvFile {
+    fn from(path: PathBuf) -> Self {
+        Self::Required(path)
+    }
+}
+
+/// directories to search for relative paths, in order (i.e. the current directory, then its parents)
+///
+/// Parents are only searched when `search_parents`, up to & including `boundary`.
+fn search_dirs(search_parents: bool, boundary: Option<&Path>) -> Vec<PathBuf> {
+    let boundary = boundary.map(|boundary| {
+        boundary
+            .canonicalize()
+            .unwrap_or_else(|_| boundary.to_path_buf())
+    });
+
+    let Ok(mut dir) = std::env::current_dir() else {
+        return Vec::new();
+    };
+
+    let mut dirs = vec![dir.clone()];
+    while search_parents && boundary.as_deref() != Some(dir.as_path()) && dir.pop() {
+        dirs.push(dir.clone());
+    }
+    dirs
+}
+
+/// locate `file` relative to the current directory
+///
+/// If missing, parent directories are searched (when `search_parents`), up to & including `boundary`.
+/// Absolute paths are used as-is.
+pub(crate) fn find(file: &Path, search_parents: bool, boundary: Option<&Path>) -> Option<PathBuf> {
+    if file.is_absolute() {
+        return file.is_file().then(|| file.to_path_buf());
+    }
+
+    search_dirs(search_parents, boundary)
+        .into_iter()
+        .map(|dir| dir.join(file))
+        .find(|candidate| candidate.is_file())
+}
+
+/// locate the file(s) matching `file`, which may contain `*`/`?` wildcards (e.g. `conf/*.env`)
+///
+/// Matches are sorted, for a deterministic processing order.
+/// Relative patterns are expanded in the first directory (per [`find`]) w/ any match.
+pub(crate) fn find_all(file: &Path, search_parents: bool, boundary: Option<&Path>) -> Vec<PathBuf> {
+    if !is_glob(file) {
+        return find(file, search_parents, boundary).into_iter().collect();
+    }
+
+    if file.is_absolute() {
+        return expand(Path::new(""), file);
+    }
+
+    search_dirs(search_parents, boundary)
+        .into_iter()
+        .map(|dir| expand(&dir, file))
+        .find(|files| !files.is_empty())
+        .unwrap_or_default()
+}
+
+/// whether `file` contains wildcards
+fn is_glob(file: &Path) -> bool {
+    file.to_string_lossy().contains(['*', '?'])
+}
+
+/// files matching `pattern` (relative to `dir`); sorted
+fn expand(dir: &Path, pattern: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![dir.to_path_buf()];
+
+    for component in pattern.components() {
+        paths = match component {
+            Component::Normal(name) if is_glob(name.as_ref()) => {
+                let name = name.to_string_lossy();
+                paths
+                    .iter()
+                    .filter_map(|path| std::fs::read_dir(path).ok())
+                    .flatten()
+                    .filter_map(Result::ok)
+                    .filter(|entry| {
+                        entry
+                            .file_name()
+                            .to_str()
+                            .is_some_and(|entry| matches(&name, entry))
+                    })
+                    .map(|entry| entry.path())
+                    .collect()
+            }
+            component => paths.into_iter().map(|path| path.join(component)).collect(),
+        };
+    }
+
+    paths.retain(|path| path.is_file());
+    paths.sort();
+    paths
+}
+
+/// whether `name` matches the `*`/`?` wildcard `pattern`
+///
+/// Like a shell, wildcards don't match a leading `.` (i.e. hidden files).
+fn matches(pattern: &str, name: &str) -> bool {
+    if name.starts_with('.') && !pattern.starts_with('.') {
+        return false;
+    }
+
+    wildcard(pattern, name)
+}
+
+/// see [`matches`]
+fn wildcard(pattern: &str, name: &str) -> bool {
+    let mut chars = pattern.chars();
+    match chars.next() {
+        None => name.is_empty(),
+        Some('*') => name
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain([name.len()])
+            .any(|index| wildcard(chars.as_str(), &name[index..])),
+        Some('?') => {
+            let mut name = name.chars();
+            name.next().is_some() && wildcard(chars.as_str(), name.as_str())
+        }
+        Some(c) => name
+            .strip_prefix(c)
+            .is_some_and(|name| wildcard(chars.as_str(), name)),
+    }
+}
+
+/// verify every `$VAR`/`${VAR}` reference in the dotenv file at `path` is defined
+///
+/// References resolve against the environment and the preceding lines of the file.
+///
+/// # Errors
+/// * the file can't be read
+/// * any reference is undefined; the error lists all of them
+pub(crate) fn verify_references(path: &Path) -> anyhow::Result<()> {
+    verify_content(&path.display().to_string(), &std::fs::read_to_string(path)?)
+}
+
+/// see [`verify_references`]; `source` names the `content` in errors
+///
+/// # Errors
+/// * any reference is undefined; the error lists all of them
+pub(crate) fn verify_content(source: &str, content: &str) -> anyhow::Result<()> {
+    let mut defined = std::collections::HashSet::new();
+    let mut undefined = Vec::new();
+    for line in content.lines() {
+        let line = line.trim_start();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        for name in references(value.trim_start()) {
+            if !defined.contains(&name)
+                && std::env::var_os(&name).is_none()
+                && !undefined.contains(&name)
+            {
+                undefined.push(name);
+            }
+        }
+        defined.insert(key.trim().to_owned());
+    }
+
+    if !undefined.is_empty() {
+        tracing::error!("undefined variable(s) referenced by {source}");
+        anyhow::bail!(
+            "undefined variable(s) referenced by {source}: {}",
+            undefined.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// names of the variables referenced by a dotenv `value`
+///
+/// Mirrors [`dotenvy`] substitution (e.g. none within single quotes, `$VAR` names are alphanumeric).
+fn references(value: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let (mut escaped, mut strong_quote, mut weak_quote) = (false, false, false);
+
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if escaped {
+            escaped = false;
+        } else if strong_quote {
+            strong_quote = c != '\'';
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            weak_quote = !weak_quote;
+        } else if c == '\'' && !weak_quote {
+            strong_quote = true;
+        } else if (c == ' ' || c == '\t') && !weak_quote {
+            break; // i.e. a trailing comment
+        } else if c == '$' {
+            let name: String = if chars.next_if_eq(&'{').is_some() {
+                chars.by_ref().take_while(|c| *c != '}').collect()
+            } else {
+                std::iter::from_fn(|| chars.next_if(|c| c.is_alphanumeric())).collect()
+            };
+
+            if !name.is_empty() {
+                names.push(name);
+            }
+        }
+    }
+
+    names
+}
+
+/// how (& where) loaded variables are merged
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct Merge {
+    /// whether existing variables are replaced
+    pub(crate) can_override: bool,
+    /// whether variables defined in the process environment (before any dotenv processing) are never replaced
+    pub(crate) protect: bool,
+    /// only variables w/ this key prefix are merged
+    pub(crate) prefix: Option<String>,
+    /// whether the `prefix` is removed from merged keys
+    pub(crate) strip: bool,
+    /// whether merged keys are [normalized](crate::dotenv_audit::normalize)
+    pub(crate) normalize: bool,
+    /// isolated variables (refer to [`EnvMap`](crate::EnvMap)); the process environment is used if [`None`]
+    pub(crate) isolated: Option<RefCell<BTreeMap<String, String>>>,
+}
+
+impl Merge {
+    /// whether `key` is already defined
+    fn contains(&self, key: &str) -> bool {
+        self.isolated.as_ref().map_or_else(
+            || std::env::var_os(key).is_some(),
+            |isolated| isolated.borrow().contains_key(key),
+        )
+    }
+
+    /// define `key`, unless already defined (w/o `can_override`) or [original](crate::precedence::is_original) (w/ `protect`)
+    pub(crate) fn set(&self, key: &str, value: String, can_override: bool, protect: bool) {
+        let key = if self.normalize {
+            std::borrow::Cow::Owned(crate::dotenv_audit::normalize(key))
+        } else {
+            std::borrow::Cow::Borrowed(key)
+        };
+        if key.is_empty()
+            || (!can_override && self.contains(&key))
+            || (protect && crate::precedence::is_original(&key, self.isolated.is_some()))
+        {
+            return;
+        }
+
+        match &self.isolated {
+            Some(isolated) => {
+                isolated.borrow_mut().insert(key.into_owned(), value);
+            }
+            None => std::env::set_var(key.as_ref(), value),
+        }
+    }
+
+    /// whether variables can be merged by [`dotenvy`] directly (i.e. w/o filtering or transforming keys)
+    const fn is_direct(&self, protect: bool) -> bool {
+        self.isolated.is_none() && self.prefix.is_none() && !self.normalize && !protect
+    }
+
+    /// merge the dotenv file at `path`
+    ///
+    /// # Errors
+    /// * the file can't be read/parsed
+    pub(crate) fn load(&self, path: &Path) -> Result<(), dotenvy::Error> {
+        self.load_file(path, self.can_override, self.protect)
+    }
+
+    /// merge the (more specific) dotenv file at `path` over the files merged before it (e.g. `.env.{environment}` over `.env`)
+    ///
+    /// Variables defined in the process environment (before any dotenv processing) are still preserved,
+    /// unless dotenv files take precedence over them (refer to [`PrecedencePolicy`](crate::PrecedencePolicy)).
+    ///
+    /// # Errors
+    /// * the file can't be read/parsed
+    pub(crate) fn overlay(&self, path: &Path) -> Result<(), dotenvy::Error> {
+        self.load_file(path, true, self.protect || !self.can_override)
+    }
+
+    fn load_file(
+        &self,
+        path: &Path,
+        can_override: bool,
+        protect: bool,
+    ) -> Result<(), dotenvy::Error> {
+        let (res, function) =
+            crate::provenance::track(&EnvOrigin::File(path.to_path_buf()), || {
+                match (self.is_direct(protect), can_override) {
+                    (true, true) => (dotenvy::from_path_override(path), "from_path_override"),
+                    (true, false) => (dotenvy::from_path(path), "from_path"),
+                    (false, _) => (
+                        dotenvy::from_path_iter(path)
+                            .and_then(|vars| self.import(vars, can_override, protect)),
+                        "from_path_iter",
+                    ),
+                }
+            });
+
+        #[cfg(feature = "watch")]
+        crate::watch::track(path);
+        loaded_files().push((path.to_path_buf(), res.is_ok()));
+
+        if let Ok(vars) = dotenvy::from_path_iter(path) {
+            self.audit(vars);
+        }
+
+        res.inspect(|()| tracing::info!("dotenv::{function}({})", path.display()))
+            .inspect_err(|_| tracing::error!("dotenv::{function}({})", path.display()))
+    }
+
+    /// merge dotenv content (e.g. [`DotEnvParserConfig::builtin_dotenv`](crate::DotEnvParserConfig::builtin_dotenv))
+    ///
+    /// # Errors
+    /// * the content can't be read/parsed
+    pub(crate) fn read<R: std::io::Read>(
+        &self,
+        reader: R,
+        can_override: bool,
+    ) -> Result<(), dotenvy::Error> {
+        match (self.is_direct(self.protect), can_override) {
+            (true, true) => dotenvy::from_read_override(reader),
+            (true, false) => dotenvy::from_read(reader),
+            (false, _) => self.import(dotenvy::from_read_iter(reader), can_override, self.protect),
+        }
+    }
+
+    /// record the (merged) keys of parsed dotenv `vars` (refer to [`DotEnvParserConfig::dotenv_audit`](crate::DotEnvParserConfig::dotenv_audit))
+    pub(crate) fn audit<R: std::io::Read>(&self, vars: dotenvy::Iter<R>) {
+        crate::dotenv_audit::track(
+            vars.filter_map(Result::ok)
+                .filter_map(|(key, _)| self.key(&key).map(str::to_owned)),
+        );
+    }
+
+    /// merged key for `key` (per the `prefix`, if any); [`None`] if skipped
+    fn key<'k>(&self, key: &'k str) -> Option<&'k str> {
+        self.prefix.as_ref().map_or(Some(key), |prefix| {
+            key.strip_prefix(prefix.as_str())
+                .map(|stripped| if self.strip { stripped } else { key })
+        })
+    }
+
+    /// merge parsed dotenv `vars` (w/ the `prefix`, if any)
+    ///
+    /// # Errors
+    /// * the content can't be read/parsed
+    fn import<R: std::io::Read>(
+        &self,
+        vars: dotenvy::Iter<R>,
+        can_override: bool,
+        protect: bool,
+    ) -> Result<(), dotenvy::Error> {
+        for var in vars {
+            let (key, value) = var?;
+            if let Some(key) = self.key(&key) {
+                self.set(key, value, can_override, protect);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+//! audit of the variables loaded from dotenv files (i.e. unknown keys, such as typos, & case collisions)
+
+use std::collections::BTreeSet;
+
+/// (original, i.e. not normalized) keys loaded from dotenv files, as of the last [`DotEnvParser::process_dotenv_files`](crate::DotEnvParser::process_dotenv_files)
+static LOADED: std::sync::Mutex<BTreeSet<String>> = std::sync::Mutex::new(BTreeSet::new());
+
+/// (original) keys loaded from [`EnvSource`](crate::EnvSource)s; only checked for collisions
+static SOURCED: std::sync::Mutex<BTreeSet<String>> = std::sync::Mutex::new(BTreeSet::new());
+
+/// how dotenv audit findings are reported
+///
+/// Refer to [`DotEnvParserConfig::dotenv_audit`](crate::DotEnvParserConfig::dotenv_audit)
+/// & [`DotEnvParserConfig::dotenv_key_collisions`](crate::DotEnvParserConfig::dotenv_key_collisions).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DotEnvAudit {
+    /// no audit
+    #[default]
+    Off,
+    /// log a warning listing the findings
+    Warn,
+    /// fail w/ an error listing the findings
+    Deny,
+}
+
+fn loaded() -> std::sync::MutexGuard<'static, BTreeSet<String>> {
+    LOADED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn sourced() -> std::sync::MutexGuard<'static, BTreeSet<String>> {
+    SOURCED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// forget the recorded keys, before processing
+pub(crate) fn init() {
+    loaded().clear();
+    sourced().clear();
+}
+
+/// record `keys` as loaded from a dotenv file
+pub(crate) fn track(keys: impl IntoIterator<Item = String>) {
+    loaded().extend(keys);
+}
+
+/// record `keys` as loaded from an [`EnvSource`](crate::EnvSource)
+pub(crate) fn track_source(keys: impl IntoIterator<Item = String>) {
+    sourced().extend(keys);
+}
+
+/// normalized `key` (i.e. uppercase, w/ `-` & `.` replaced by `_`)
+///
+/// Refer to [`DotEnvParserConfig::dotenv_normalize_keys`](crate::DotEnvParserConfig::dotenv_normalize_keys).
+pub(crate) fn normalize(key: &str) -> String {
+    key.chars()
+        .map(|c| match c {
+            '-' | '.' => '_',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+/// recorded keys (`normalized`, if enabled) missing from `known`; sorted
+pub(crate) fn unknown(known: &BTreeSet<String>, normalized: bool) -> Vec<String> {
+    loaded()
+        .iter()
+        .map(|key| {
+            if normalized {
+                normalize(key)
+            } else {
+                key.clone()
+            }
+        })
+        .filter(|key| !known.contains(key))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// recorded keys (incl. [`EnvSource`](crate::EnvSource)s') that differ, but [normalize](normalize) identically
+/// (e.g. `db-url`/`DB_URL`); sorted
+pub(crate) fn collisions() -> Vec<String> {
+    let mut keys = loaded().clone();
+    keys.extend(sourced().iter().cloned());
+
+    let mut groups = std::collections::BTreeMap::<String, Vec<String>>::new();
+    for key in keys {
+        groups.entry(normalize(&key)).or_default().push(key);
+    }
+
+    groups
+        .into_values()
+        .filter(|keys| keys.len() > 1)
+        .map(|keys| keys.join("/"))
+        .collect()
+}
+
+/// report the `findings` (if any) per `audit`
+///
+/// # Errors
+/// * any findings w/ [`DotEnvAudit::Deny`]; the error lists all of them
+pub(crate) fn report(audit: DotEnvAudit, what: &str, findings: &[String]) -> anyhow::Result<()> {
+    if findings.is_empty() {
+        return Ok(());
+    }
+
+    let findings = findings.join(", ");
+    match audit {
+        DotEnvAudit::Off => {}
+        DotEnvAudit::Warn => tracing::warn!("{what}: {findings}"),
+        DotEnvAudit::Deny => {
+            tracing::error!("{what}: {findings}");
+            anyhow::bail!("{what}: {findings}");
+        }
+    }
+
+    Ok(())
+}
+
+/// `env =` bindings of the `command`'s arguments (& subcommands')
+pub(crate) fn bindings(command: &clap::Command) -> BTreeSet<String> {
+    command
+        .get_arguments()
+        .filter_map(|arg| arg.get_env())
+        .map(|env| env.to_string_lossy().into_owned())
+        .chain(command.get_subcommands().flat_map(bindings))
+        .collect()
+}
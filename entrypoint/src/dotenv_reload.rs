@@ -0,0 +1,89 @@
+//! re-processing of dotenv files (i.e. hot-reload)
+
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::OsString;
+
+/// keys defined before any dotenv processing (i.e. never removed on reload)
+static ORIGINAL: std::sync::OnceLock<HashSet<OsString>> = std::sync::OnceLock::new();
+
+/// keys currently defined by dotenv processing; [`None`] until the first reload
+static MANAGED: std::sync::Mutex<Option<HashSet<String>>> = std::sync::Mutex::new(None);
+
+/// snapshot the original environment keys, if not already done
+pub(crate) fn init() {
+    ORIGINAL.get_or_init(|| std::env::vars_os().map(|(key, _)| key).collect());
+}
+
+/// snapshot the keys defined by dotenv processing (i.e. since [`init`]), if not already done
+///
+/// Call once setup completes, so keys set later by the application aren't removed on reload.
+pub(crate) fn start() {
+    MANAGED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get_or_insert_with(managed);
+}
+
+/// keys defined since [`init`] (i.e. by dotenv processing)
+fn managed() -> HashSet<String> {
+    ORIGINAL.get().map_or_else(HashSet::new, |original| {
+        std::env::vars_os()
+            .filter(|(key, _)| !original.contains(key))
+            .filter_map(|(key, _)| key.into_string().ok())
+            .collect()
+    })
+}
+
+/// re-process the dotenv files from scratch, then notify [`DotEnvParserConfig::dotenv_on_reload`] of the changed keys
+///
+/// The managed keys (i.e. defined by previous dotenv processing) are removed first,
+/// so edited/deleted values don't linger; they're updated to reflect this run.
+/// Concurrent reloads (e.g. file watcher & signal) are serialized.
+///
+/// # Errors
+/// * failure parsing args or processing dotenv file(s)
+pub(crate) fn reload<T: crate::Entrypoint>() -> anyhow::Result<()> {
+    let mut guard = MANAGED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let managed = guard.get_or_insert_with(managed);
+
+    let before = snapshot();
+    for key in managed.iter() {
+        std::env::remove_var(key);
+    }
+
+    let command = crate::command::<T>();
+    let args = T::from_arg_matches_mut(&mut command.try_get_matches()?)?.process_dotenv_files()?;
+
+    let after = snapshot();
+    *managed = after
+        .keys()
+        .filter(|key| managed.contains(*key) || !before.contains_key(*key))
+        .cloned()
+        .collect();
+    drop(guard);
+
+    let mut changed: Vec<String> = before
+        .iter()
+        .filter(|(key, value)| after.get(*key) != Some(value))
+        .chain(after.iter().filter(|(key, _)| !before.contains_key(*key)))
+        .map(|(key, _)| key.clone())
+        .collect();
+    changed.sort();
+
+    if !changed.is_empty() {
+        info!(changed = ?changed, "dotenv file(s) reloaded");
+        args.dotenv_on_reload(&changed);
+    }
+
+    Ok(())
+}
+
+/// the current environment (as UTF-8 strings)
+fn snapshot() -> BTreeMap<String, String> {
+    std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .collect()
+}
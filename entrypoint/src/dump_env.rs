@@ -0,0 +1,54 @@
+//! builtin `--dump-env` diagnostic flag
+
+use std::fmt::Write;
+
+/// builtin arg id/long name
+pub(crate) const FLAG: &str = "dump-env";
+
+/// whether to exit after dumping, as parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+///
+/// [`None`] if the flag wasn't passed.
+static REQUESTED: std::sync::Mutex<Option<bool>> = std::sync::Mutex::new(None);
+
+/// the builtin (hidden) `--dump-env[=exit|continue]` arg
+pub(crate) fn arg() -> clap::Arg {
+    clap::Arg::new(FLAG)
+        .long(FLAG)
+        .value_name("THEN")
+        .value_parser(["exit", "continue"])
+        .num_args(0..=1)
+        .require_equals(true)
+        .default_missing_value("exit")
+        .global(true)
+        .hide(true)
+        .help("Print the resolved environment (w/ sensitive values masked), then exit or continue")
+}
+
+/// record whether the flag was passed from parsed matches (if the builtin arg is present)
+pub(crate) fn set(matches: &clap::ArgMatches) {
+    if let Ok(then) = matches.try_get_one::<String>(FLAG) {
+        *REQUESTED
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = then.map(|then| then == "exit");
+    }
+}
+
+/// [`Some`] (whether to exit after dumping) if the flag was passed
+pub(crate) fn requested() -> Option<bool> {
+    *REQUESTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// `KEY=value` lines for the current environment; sorted, w/ sensitive values masked
+pub(crate) fn describe(patterns: &[String]) -> String {
+    let mut vars: Vec<(String, String)> = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .collect();
+    vars.sort();
+
+    vars.iter().fold(String::new(), |mut dump, (key, value)| {
+        let _ = writeln!(dump, "{key}={}", crate::redact::mask(key, value, patterns));
+        dump
+    })
+}
@@ -0,0 +1,136 @@
+//! typed (i.e. [`serde`]) extraction of environment variables
+
+use serde::de::{self, Error as _, IntoDeserializer, Visitor};
+
+/// deserialize the environment into `C` (e.g. a [`serde::Deserialize`] config struct)
+///
+/// Keys are matched case-insensitively against (lowercase) field names; use `#[serde(rename)]` if needed.
+/// Only variables w/ the `prefix` (if any) are considered, and the prefix is removed before matching
+/// (e.g. `MYAPP_PORT` -> `port`).
+///
+/// Values are parsed per the field type (e.g. `u16`, `bool`); `Option` fields are [`None`] when unset.
+/// Sequence fields (e.g. `Vec<String>`) are comma separated.
+///
+/// # Errors
+/// * a (non-[`Option`]/`#[serde(default)]`) field isn't set
+/// * a value can't be parsed as its field type
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     port: u16,
+///     hosts: Vec<String>,
+///     debug: Option<bool>,
+/// }
+///
+/// std::env::set_var("MYAPP_PORT", "8080");
+/// std::env::set_var("MYAPP_HOSTS", "a.example.com,b.example.com");
+///
+/// let config: Config = entrypoint::from_env(Some("MYAPP_"))?;
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.hosts, ["a.example.com", "b.example.com"]);
+/// assert_eq!(config.debug, None);
+/// # Ok::<(), entrypoint::anyhow::Error>(())
+/// ```
+pub fn from_env<C: de::DeserializeOwned>(prefix: Option<&str>) -> anyhow::Result<C> {
+    let prefix = prefix.unwrap_or_default().to_ascii_lowercase();
+
+    let vars = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .filter_map(|(key, value)| {
+            Some((
+                key.to_ascii_lowercase().strip_prefix(&prefix)?.to_owned(),
+                Value(value),
+            ))
+        });
+
+    Ok(C::deserialize(de::value::MapDeserializer::new(vars))?)
+}
+
+/// a single (string) environment variable value
+pub(crate) struct Value(pub(crate) String);
+
+impl IntoDeserializer<'_, de::value::Error> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// `deserialize_*` methods that parse the value via [`std::str::FromStr`]
+macro_rules! parse {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = self.0.trim();
+                visitor.$visit(
+                    value
+                        .parse()
+                        .map_err(|e| Self::Error::custom(format!("invalid value {value:?} ({e})")))?,
+                )
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0)
+    }
+
+    parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let values = self
+            .0
+            .split(',')
+            .map(|value| Self(value.trim().to_owned()))
+            .filter(|value| !value.0.is_empty());
+
+        visitor.visit_seq(de::value::SeqDeserializer::new(values))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,86 @@
+//! isolated (i.e. not in the process environment) variables
+
+use std::collections::BTreeMap;
+
+/// environment variables merged w/o mutating the process environment
+///
+/// Refer to [`DotEnvParserConfig::dotenv_isolated`](crate::DotEnvParserConfig::dotenv_isolated)
+/// & [`DotEnvParser::dotenv_env_map`](crate::DotEnvParser::dotenv_env_map).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// let env = EnvMap::from(std::collections::BTreeMap::from([(
+///     String::from("PORT"),
+///     String::from("8080"),
+/// )]));
+///
+/// assert_eq!(env.get("PORT"), Some("8080"));
+/// assert_eq!(env.get("HOST"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnvMap(BTreeMap<String, String>);
+
+impl EnvMap {
+    /// value of the `key` variable, if defined
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// whether the `key` variable is defined
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// `(key, value)` pairs, sorted by key
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// number of defined variables
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// whether no variables are defined
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// [`Command`](std::process::Command) for `program`, w/ exactly these variables (i.e. nothing else is inherited)
+    ///
+    /// Refer to [`DotEnvParser::dotenv_child_env`](crate::DotEnvParser::dotenv_child_env).
+    #[must_use]
+    pub fn command(&self, program: impl AsRef<std::ffi::OsStr>) -> std::process::Command {
+        let mut command = std::process::Command::new(program);
+        command.env_clear().envs(self.iter());
+        command
+    }
+}
+
+impl From<BTreeMap<String, String>> for EnvMap {
+    fn from(vars: BTreeMap<String, String>) -> Self {
+        Self(vars)
+    }
+}
+
+impl From<EnvMap> for BTreeMap<String, String> {
+    fn from(env: EnvMap) -> Self {
+        env.0
+    }
+}
+
+impl IntoIterator for EnvMap {
+    type Item = (String, String);
+    type IntoIter = std::collections::btree_map::IntoIter<String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,128 @@
+//! declarative environment variable validation
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// a single value check; returns a description of the problem on failure
+type Check = Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// validation rule(s) for an environment variable's value (e.g. `PORT` must parse as a [`u16`])
+///
+/// Rules are declared via [`DotEnvParserConfig::env_schema`](crate::DotEnvParserConfig::env_schema)
+/// and verified after dotenv processing. Unset variables are skipped (refer to
+/// [`DotEnvParserConfig::required_env_vars`](crate::DotEnvParserConfig::required_env_vars)).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// let rules = [
+///     EnvRule::new("PORT").parse::<u16>(),
+///     EnvRule::new("WORKERS").range(1..=64),
+///     EnvRule::new("APP_ENV").one_of(["dev", "staging", "prod"]),
+/// ];
+///
+/// assert!(rules[0].validate("8080").is_ok());
+/// assert!(rules[1].validate("128").is_err());
+/// assert!(rules[2].validate("qa").is_err());
+/// ```
+pub struct EnvRule {
+    key: String,
+    checks: Vec<Check>,
+}
+
+impl EnvRule {
+    /// rule for the `key` variable; add checks w/ the other methods
+    #[must_use]
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// variable name
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// value must parse as `T` (via [`FromStr`])
+    #[must_use]
+    pub fn parse<T>(self) -> Self
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.check(|value| {
+            value
+                .parse::<T>()
+                .map(|_| ())
+                .map_err(|e| format!("expected {} ({e})", std::any::type_name::<T>()))
+        })
+    }
+
+    /// value must parse as `T`, within `range` (e.g. `1..=64`)
+    #[must_use]
+    pub fn range<T, R>(self, range: R) -> Self
+    where
+        T: FromStr + PartialOrd + Debug,
+        T::Err: Display,
+        R: std::ops::RangeBounds<T> + Debug + Send + Sync + 'static,
+    {
+        self.check(move |value| {
+            let parsed = value
+                .parse::<T>()
+                .map_err(|e| format!("expected {} ({e})", std::any::type_name::<T>()))?;
+
+            if range.contains(&parsed) {
+                Ok(())
+            } else {
+                Err(format!("expected a value within {range:?}"))
+            }
+        })
+    }
+
+    /// value must be one of `values` (case-sensitive)
+    #[must_use]
+    pub fn one_of<I, S>(self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let values: Vec<String> = values.into_iter().map(Into::into).collect();
+        self.check(move |value| {
+            if values.iter().any(|allowed| allowed == value) {
+                Ok(())
+            } else {
+                Err(format!("expected one of {}", values.join(", ")))
+            }
+        })
+    }
+
+    /// value must satisfy a custom `check`, which describes the problem on failure
+    #[must_use]
+    pub fn check<F>(mut self, check: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    /// run every check against `value`
+    ///
+    /// # Errors
+    /// * the first failed check's description
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        self.checks.iter().try_for_each(|check| check(value))
+    }
+}
+
+impl Debug for EnvRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnvRule")
+            .field("key", &self.key)
+            .field("checks", &self.checks.len())
+            .finish()
+    }
+}
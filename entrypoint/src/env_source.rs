@@ -0,0 +1,148 @@
+//! pluggable (non-file) environment variable sources
+
+/// a source of environment variables (e.g. an HTTP endpoint, AWS SSM, mounted secrets)
+///
+/// Sources are registered via [`DotEnvParserConfig::env_sources`](crate::DotEnvParserConfig::env_sources)
+/// and participate in the same ordered, [override](crate::DotEnvParserConfig::dotenv_can_override) aware
+/// merge as the dotenv files (i.e. the builtin source).
+///
+/// Mounted secret directories are supported out of the box (refer to [`SecretDir`]).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// struct Mounted(std::path::PathBuf);
+///
+/// impl EnvSource for Mounted {
+///     fn name(&self) -> String {
+///         format!("mounted secrets ({})", self.0.display())
+///     }
+///
+///     fn vars(&self) -> anyhow::Result<Vec<(String, String)>> {
+///         let mut vars = Vec::new();
+///         for entry in std::fs::read_dir(&self.0)? {
+///             let entry = entry?;
+///             if let Some(key) = entry.file_name().to_str() {
+///                 vars.push((key.to_owned(), std::fs::read_to_string(entry.path())?));
+///             }
+///         }
+///         Ok(vars)
+///     }
+/// }
+/// ```
+pub trait EnvSource {
+    /// name used in log/error output
+    fn name(&self) -> String;
+
+    /// fetch the variables to populate into the environment
+    ///
+    /// # Errors
+    /// * the source is unavailable/invalid
+    fn vars(&self) -> anyhow::Result<Vec<(String, String)>>;
+}
+
+/// merge the variables from `source`
+///
+/// Existing variables are only replaced per the [`Merge`](crate::dotenv::Merge) `can_override`.
+///
+/// # Errors
+/// * the source failed to supply its variables
+pub(crate) fn merge(source: &dyn EnvSource, merge: &crate::dotenv::Merge) -> anyhow::Result<()> {
+    let name = source.name();
+    let vars = source
+        .vars()
+        .inspect(|_| tracing::info!("env_source({name})"))
+        .inspect_err(|_| tracing::error!("env_source({name})"))?;
+
+    crate::dotenv_audit::track_source(vars.iter().map(|(key, _)| key.clone()));
+    crate::provenance::track(&crate::EnvOrigin::Source(name), || {
+        for (key, value) in vars {
+            merge.set(&key, value, merge.can_override, merge.protect);
+        }
+    });
+
+    Ok(())
+}
+
+/// an [`EnvSource`] reading every file in a directory as `KEY=file-contents`
+///
+/// This is the layout of Kubernetes secret/configmap volumes (and Docker secrets, i.e. `/run/secrets`).
+/// Hidden entries (e.g. the `..data` links Kubernetes maintains) & subdirectories are skipped.
+/// A single trailing newline is removed from each value.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser)]
+/// # struct Args {}
+/// impl entrypoint::DotEnvParserConfig for Args {
+///     fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+///         vec![
+///             Box::new(SecretDir::new("/etc/secrets")),
+///             Box::new(SecretDir::new("/etc/config").optional()),
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretDir {
+    path: std::path::PathBuf,
+    required: bool,
+}
+
+impl SecretDir {
+    /// source for the directory at `path`; it's an error if missing (refer to [`SecretDir::optional`])
+    #[must_use]
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            required: true,
+        }
+    }
+
+    /// skip the directory if missing (e.g. when running outside the cluster)
+    #[must_use]
+    pub fn optional(self) -> Self {
+        Self {
+            required: false,
+            ..self
+        }
+    }
+}
+
+impl EnvSource for SecretDir {
+    fn name(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn vars(&self) -> anyhow::Result<Vec<(String, String)>> {
+        if !self.required && !self.path.is_dir() {
+            tracing::info!(
+                "optional secret directory not found ({})",
+                self.path.display()
+            );
+            return Ok(Vec::new());
+        }
+
+        let mut vars = Vec::new();
+        for entry in std::fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            let Some(key) = path.file_name().and_then(std::ffi::OsStr::to_str) else {
+                continue;
+            };
+            if key.starts_with('.') || !path.is_file() {
+                continue;
+            }
+
+            let value = std::fs::read_to_string(&path)
+                .map_err(|e| anyhow::anyhow!("failed to read {} ({e})", path.display()))?;
+            let value = value.strip_suffix('\n').map_or(value.as_str(), |value| {
+                value.strip_suffix('\r').unwrap_or(value)
+            });
+            vars.push((key.to_owned(), value.to_owned()));
+        }
+
+        vars.sort();
+        Ok(vars)
+    }
+}
@@ -0,0 +1,39 @@
+//! [`tracing_flame`] profiling output
+
+use std::{fs::File, io::BufWriter};
+use tracing_flame::{FlameLayer, FlushGuard};
+
+/// flush guard for [`layer`]; flushed by [`flush`]
+static GUARD: std::sync::Mutex<Option<FlushGuard<BufWriter<File>>>> = std::sync::Mutex::new(None);
+
+/// [`FlameLayer`] writing folded stack samples to the supplied file
+///
+/// # Panics
+/// * the file can't be created
+pub(crate) fn layer<S>(file: &std::path::Path) -> FlameLayer<S, BufWriter<File>>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    if let Some(dir) = file.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        let _ = std::fs::create_dir_all(dir);
+    }
+
+    let (layer, guard) = FlameLayer::with_file(file)
+        .unwrap_or_else(|e| panic!("failed to create flame file {}: {e}", file.display()));
+
+    *GUARD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(guard);
+
+    layer
+}
+
+/// flush & close the flame file (if any)
+pub(crate) fn flush() {
+    let guard = GUARD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+
+    drop(guard);
+}
@@ -0,0 +1,53 @@
+//! flushing of buffered/network log output
+
+/// flushes buffered and network log output when dropped
+///
+/// On drop:
+/// * [non-blocking](crate::LoggerConfig::default_log_non_blocking) writers are flushed
+/// * the `flame` file is flushed (w/ the `flame` [feature](crate#feature-flags))
+/// * the OpenTelemetry tracer provider is shut down (w/ the `otel` [feature](crate#feature-flags))
+/// * the Sentry client is flushed (w/ the `sentry` [feature](crate#feature-flags))
+///
+/// [`Entrypoint::entrypoint`](crate::Entrypoint::entrypoint) holds one across the entrypoint function,
+/// so tail output isn't lost when the function returns (or panics).
+/// Hold one yourself when calling [`Logger::log_init`](crate::Logger::log_init) directly (e.g. from tests/benchmarks).
+///
+/// Output logged after the guard is dropped may be lost.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// # struct Args {}
+/// fn main() -> anyhow::Result<()> {
+///     let _flush = entrypoint::FlushGuard::new();
+///     let args = Args::parse().log_init(None)?;
+///
+///     info!("flushed before main() returns");
+///     Ok(())
+/// }
+/// ```
+#[must_use = "output is flushed when the guard is dropped"]
+#[derive(Debug, Default)]
+pub struct FlushGuard {
+    _private: (),
+}
+
+impl FlushGuard {
+    /// new guard; flushes when dropped
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Drop for FlushGuard {
+    fn drop(&mut self) {
+        crate::writer::flush_non_blocking();
+        #[cfg(feature = "flame")]
+        crate::flame::flush();
+        #[cfg(feature = "otel")]
+        crate::otel::shutdown();
+        #[cfg(feature = "sentry")]
+        crate::sentry::shutdown();
+    }
+}
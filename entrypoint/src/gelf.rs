@@ -0,0 +1,197 @@
+//! [GELF](https://go2docs.graylog.org/current/getting_in_log_data/gelf.html) (Graylog) UDP output
+
+use tracing::field::{Field, Visit};
+use tracing_subscriber::{layer::Context, registry::LookupSpan};
+
+/// env var w/ the Graylog host; GELF output is disabled unless set
+pub(crate) const HOST_VAR: &str = "GELF_HOST";
+
+/// env var w/ the Graylog GELF UDP port; defaults to `12201`
+pub(crate) const PORT_VAR: &str = "GELF_PORT";
+
+/// max datagram size (incl. chunk header)
+const CHUNK_SIZE: usize = 8192;
+
+/// chunked GELF magic bytes
+const CHUNK_MAGIC: [u8; 2] = [0x1e, 0x0f];
+
+/// chunk header size (magic + message id + sequence number + sequence count)
+const CHUNK_HEADER: usize = 12;
+
+/// max chunks per message
+const MAX_CHUNKS: usize = 128;
+
+/// [`tracing_subscriber::Layer`] sending one GELF 1.1 message per event
+pub(crate) struct GelfLayer {
+    socket: std::net::UdpSocket,
+    host: String,
+    app_name: String,
+    messages: std::sync::atomic::AtomicU64,
+}
+
+/// GELF layer sending to [`HOST_VAR`]:[`PORT_VAR`]
+///
+/// The message source (i.e. `host` field) is the host name (if known), otherwise `app_name`.
+/// Returns [`None`] if [`HOST_VAR`] isn't set.
+pub(crate) fn layer(app_name: &str) -> Option<GelfLayer> {
+    let server = std::env::var(HOST_VAR)
+        .ok()
+        .filter(|host| !host.is_empty())?;
+    let port = std::env::var(PORT_VAR)
+        .ok()
+        .map_or(Ok(12201), |port| port.parse::<u16>());
+
+    let socket = port
+        .map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{PORT_VAR}: {e}"))
+        })
+        .and_then(|port| {
+            let socket = std::net::UdpSocket::bind(("0.0.0.0", 0))?;
+            socket.connect((server.as_str(), port))?;
+            Ok(socket)
+        });
+
+    match socket {
+        Ok(socket) => Some(GelfLayer {
+            socket,
+            host: crate::app::hostname().unwrap_or_else(|| app_name.to_owned()),
+            app_name: app_name.to_owned(),
+            messages: std::sync::atomic::AtomicU64::new(0),
+        }),
+        Err(e) => {
+            eprintln!("GELF output unavailable ({e})");
+            None
+        }
+    }
+}
+
+/// syslog severity code for a [`tracing::Level`]
+const fn severity(level: tracing::Level) -> u8 {
+    match level {
+        tracing::Level::ERROR => 3,
+        tracing::Level::WARN => 4,
+        tracing::Level::INFO => 6,
+        tracing::Level::DEBUG | tracing::Level::TRACE => 7,
+    }
+}
+
+/// event field [`Visit`]or collecting GELF additional fields
+struct Visitor<'a>(&'a mut serde_json::Map<String, serde_json::Value>);
+
+impl Visitor<'_> {
+    fn record(&mut self, field: &Field, value: serde_json::Value) {
+        let key = match field.name() {
+            "message" => String::from("short_message"),
+            // `_id` is reserved by GELF
+            "id" => String::from("_id_"),
+            name => format!("_{}", name.strip_prefix("r#").unwrap_or(name)),
+        };
+
+        self.0.insert(key, value);
+    }
+}
+
+impl Visit for Visitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.record(field, format!("{value:?}").into());
+    }
+}
+
+impl GelfLayer {
+    /// send the message, chunked if necessary
+    fn send(&self, message: &[u8]) -> std::io::Result<()> {
+        if message.len() <= CHUNK_SIZE {
+            return self.socket.send(message).map(|_| ());
+        }
+
+        let chunks = message.chunks(CHUNK_SIZE - CHUNK_HEADER);
+        let count = chunks.len();
+        if count > MAX_CHUNKS {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "GELF message too large",
+            ));
+        }
+
+        let id = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |now| {
+                now.as_secs().rotate_left(32) ^ u64::from(now.subsec_nanos())
+            })
+            ^ u64::from(std::process::id()).rotate_left(32)
+            ^ self
+                .messages
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        for (sequence, chunk) in chunks.enumerate() {
+            let mut datagram = Vec::with_capacity(CHUNK_HEADER + chunk.len());
+            datagram.extend_from_slice(&CHUNK_MAGIC);
+            datagram.extend_from_slice(&id.to_be_bytes());
+            // both < MAX_CHUNKS
+            datagram.push(u8::try_from(sequence).unwrap_or(u8::MAX));
+            datagram.push(u8::try_from(count).unwrap_or(u8::MAX));
+            datagram.extend_from_slice(chunk);
+            self.socket.send(&datagram)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> tracing_subscriber::Layer<S> for GelfLayer
+where
+    S: tracing::Subscriber + for<'span> LookupSpan<'span>,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |now| now.as_secs_f64());
+
+        let mut message = serde_json::Map::new();
+        message.insert("version".into(), "1.1".into());
+        message.insert("host".into(), self.host.clone().into());
+        message.insert("timestamp".into(), timestamp.into());
+        message.insert("level".into(), severity(*meta.level()).into());
+        message.insert("_app".into(), self.app_name.clone().into());
+        message.insert("_target".into(), meta.target().into());
+
+        if let Some(file) = meta.file() {
+            message.insert("_file".into(), file.into());
+        }
+        if let Some(line) = meta.line() {
+            message.insert("_line".into(), line.into());
+        }
+        if let Some(span) = ctx.event_span(event) {
+            message.insert("_span".into(), span.name().into());
+        }
+
+        event.record(&mut Visitor(&mut message));
+        message
+            .entry("short_message")
+            .or_insert_with(|| meta.name().into());
+
+        // best effort; there's nowhere to report failures
+        let _ = self.send(serde_json::Value::Object(message).to_string().as_bytes());
+    }
+}
@@ -0,0 +1,124 @@
+//! runtime reload of the default layer filters
+
+use tracing_subscriber::{filter::LevelFilter, reload, EnvFilter, Registry};
+
+/// filter handles created (by [`crate::filtered`]) since the last [`clear`]
+static PENDING: std::sync::Mutex<Vec<reload::Handle<EnvFilter, Registry>>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// handle to the filters of the installed default layer
+static HANDLE: std::sync::Mutex<Option<LoggerHandle>> = std::sync::Mutex::new(None);
+
+/// runtime control of the default layer's level/filter
+///
+/// Obtained via [`logger_handle`]. Changes apply to every filtered layer
+/// built by [`LoggerConfig::default_log_layer`](crate::LoggerConfig::default_log_layer).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// # struct Args {}
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> anyhow::Result<()> {
+///     let handle = entrypoint::logger_handle().expect("logging initialized");
+///
+///     handle.set_level(LevelFilter::TRACE)?;
+///     assert!(enabled!(entrypoint::Level::TRACE));
+///
+///     handle.set_filter("warn")?;
+///     assert!(!enabled!(entrypoint::Level::INFO));
+/// #   Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoggerHandle {
+    handles: Vec<reload::Handle<EnvFilter, Registry>>,
+}
+
+impl LoggerHandle {
+    /// replace the filter(s) w/ the supplied [`LevelFilter`]
+    ///
+    /// # Errors
+    /// * the logging subscriber is no longer installed
+    pub fn set_level(&self, level: LevelFilter) -> anyhow::Result<()> {
+        self.reload(|| EnvFilter::default().add_directive(level.into()))
+    }
+
+    /// replace the filter(s) w/ the supplied [`EnvFilter`] directives (e.g. `info,hyper=warn`)
+    ///
+    /// # Errors
+    /// * `directives` are malformed
+    /// * the logging subscriber is no longer installed
+    pub fn set_filter(&self, directives: &str) -> anyhow::Result<()> {
+        EnvFilter::try_new(directives)?;
+        self.reload(|| EnvFilter::new(directives))
+    }
+
+    /// the most verbose level currently enabled by the filter(s)
+    #[must_use]
+    pub fn level(&self) -> Option<LevelFilter> {
+        self.handles
+            .iter()
+            .filter_map(|handle| {
+                handle
+                    .with_current(EnvFilter::max_level_hint)
+                    .ok()
+                    .flatten()
+            })
+            .max()
+    }
+
+    /// replace each filter w/ a fresh one from `filter`
+    fn reload(&self, filter: impl Fn() -> EnvFilter) -> anyhow::Result<()> {
+        for handle in &self.handles {
+            handle.reload(filter())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// handle to the default layer's filter(s), for runtime level/filter changes
+///
+/// Returns [`None`] until [`Logger::log_init`](crate::Logger::log_init) installs
+/// the [`LoggerConfig::default_log_layer`](crate::LoggerConfig::default_log_layer)
+/// (i.e. always [`None`] when [`LoggerConfig::bypass_log_init`](crate::LoggerConfig::bypass_log_init) is used w/ custom layers).
+#[must_use]
+pub fn logger_handle() -> Option<LoggerHandle> {
+    HANDLE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// record a filter handle for the layer being built
+pub(crate) fn push(handle: reload::Handle<EnvFilter, Registry>) {
+    PENDING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(handle);
+}
+
+/// forget handles from any previously built (e.g. temporary) layers
+pub(crate) fn clear() {
+    PENDING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+}
+
+/// publish the handles of the installed layer via [`logger_handle`]
+pub(crate) fn install() {
+    let handles = std::mem::take(
+        &mut *PENDING
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+
+    if !handles.is_empty() {
+        *HANDLE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(LoggerHandle { handles });
+    }
+}
@@ -0,0 +1,140 @@
+//! health/liveness (`/healthz`) & readiness (`/readyz`) HTTP probes (e.g. for kubernetes)
+
+use std::io::{BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// env var w/ the probe listen address (e.g. `0.0.0.0:8080`); the probes are disabled unless set
+pub(crate) const ADDR_VAR: &str = "HEALTH_ADDR";
+
+/// how long to wait for a probe request
+const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// whether setup completed & the entrypoint function is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// app reported liveness (refer to [`set_healthy`])
+static HEALTHY: AtomicBool = AtomicBool::new(true);
+
+/// app reported readiness (refer to [`set_ready`])
+static READY: AtomicBool = AtomicBool::new(true);
+
+/// report whether the app is healthy (i.e. `/healthz`); unhealthy apps are also not ready
+///
+/// Kubernetes restarts the container when the liveness probe fails.
+///
+/// Defaults to healthy.
+///
+/// # Examples
+/// ```
+/// # fn connected() -> bool { true }
+/// entrypoint::set_healthy(connected());
+/// ```
+pub fn set_healthy(healthy: bool) {
+    HEALTHY.store(healthy, Ordering::SeqCst);
+}
+
+/// report whether the app is ready for traffic (i.e. `/readyz`), once setup completed
+///
+/// Kubernetes stops routing traffic (but doesn't restart the container) when the readiness probe fails.
+///
+/// Defaults to ready.
+///
+/// # Examples
+/// ```
+/// entrypoint::set_ready(false); // e.g. warming a cache
+/// // ...
+/// entrypoint::set_ready(true);
+/// ```
+pub fn set_ready(ready: bool) {
+    READY.store(ready, Ordering::SeqCst);
+}
+
+/// whether `/healthz` is OK
+fn healthy() -> bool {
+    HEALTHY.load(Ordering::SeqCst)
+}
+
+/// whether `/readyz` is OK
+fn ready() -> bool {
+    RUNNING.load(Ordering::SeqCst) && READY.load(Ordering::SeqCst) && healthy() && !stopping()
+}
+
+/// whether a shutdown was requested (i.e. not ready)
+#[cfg(feature = "shutdown")]
+fn stopping() -> bool {
+    crate::shutdown_requested()
+}
+
+#[cfg(not(feature = "shutdown"))]
+const fn stopping() -> bool {
+    false
+}
+
+/// serve the probes on [`ADDR_VAR`] (in a new thread)
+pub(crate) fn install() {
+    let Some(addr) = std::env::var(ADDR_VAR).ok().filter(|addr| !addr.is_empty()) else {
+        return;
+    };
+
+    let listener = match std::net::TcpListener::bind(&addr) {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::warn!("health probes unavailable ({ADDR_VAR}={addr}: {e})");
+            return;
+        }
+    };
+
+    let spawned = std::thread::Builder::new()
+        .name(String::from("entrypoint-health"))
+        .spawn(move || {
+            for stream in listener.incoming() {
+                if let Err(e) = stream.and_then(respond) {
+                    tracing::debug!("health probe failed: {e}");
+                }
+            }
+        });
+
+    match spawned {
+        Ok(_) => tracing::info!("serving health probes on {addr}"),
+        Err(e) => tracing::warn!("failed to spawn health probe thread: {e}"),
+    }
+}
+
+/// record that setup completed (i.e. `/readyz` is OK, unless the app [reports otherwise](set_ready))
+pub(crate) fn running() {
+    RUNNING.store(true, Ordering::SeqCst);
+}
+
+/// record that the entrypoint function returned (i.e. `/readyz` fails)
+pub(crate) fn stopped() {
+    RUNNING.store(false, Ordering::SeqCst);
+}
+
+/// read the request (i.e. the request line & headers) & respond w/ the probe status
+fn respond(mut stream: std::net::TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+
+    let mut reader = std::io::BufReader::new(&stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+    let (status, body) = match path.split('?').next().unwrap_or_default() {
+        "/healthz" if healthy() => ("200 OK", "ok"),
+        "/healthz" => ("503 Service Unavailable", "unhealthy"),
+        "/readyz" if ready() => ("200 OK", "ok"),
+        "/readyz" => ("503 Service Unavailable", "not ready"),
+        _ => ("404 Not Found", "not found"),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}\n",
+        body.len() + 1
+    )?;
+    stream.flush()
+}
@@ -0,0 +1,133 @@
+//! JSON log output customization
+
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::{
+    format::{DefaultFields, FormatEvent, FormatFields, JsonFields, Writer},
+    FmtContext, FormattedFields,
+};
+use tracing_subscriber::registry::LookupSpan;
+
+/// [`FormatFields`] recording span fields as JSON (for the `json` format) or text (otherwise)
+///
+/// The `json` format expects span fields to be recorded as JSON objects.
+pub(crate) enum Fields {
+    Default(DefaultFields),
+    Json(JsonFields),
+}
+
+impl Fields {
+    /// JSON fields if `json`, otherwise [`DefaultFields`]
+    pub(crate) fn new(json: bool) -> Self {
+        if json {
+            Self::Json(JsonFields::new())
+        } else {
+            Self::Default(DefaultFields::new())
+        }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for Fields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Default(format) => format.format_fields(writer, fields),
+            Self::Json(format) => format.format_fields(writer, fields),
+        }
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &tracing::span::Record<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Default(_) => {
+                if !current.fields.is_empty() {
+                    current.fields.push(' ');
+                }
+                self.format_fields(current.as_writer(), fields)
+            }
+            Self::Json(format) => {
+                let mut added = String::new();
+                format.format_fields(Writer::new(&mut added), fields)?;
+
+                let merged = match (
+                    serde_json::from_str(&current.fields),
+                    serde_json::from_str(&added),
+                ) {
+                    (
+                        Ok(serde_json::Value::Object(mut existing)),
+                        Ok(serde_json::Value::Object(added)),
+                    ) => {
+                        existing.extend(added);
+                        serde_json::Value::Object(existing).to_string()
+                    }
+                    _ => added,
+                };
+
+                current.fields = merged;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// [`FormatEvent`] renaming the keys of JSON formatted events
+///
+/// Keys are renamed in the event object and its nested `fields` object (if any).
+pub(crate) struct Renamed<F> {
+    format: F,
+    keys: Vec<(String, String)>,
+}
+
+impl<F> Renamed<F> {
+    /// rename `(from, to)` keys of events formatted by `format`
+    pub(crate) const fn new(format: F, keys: Vec<(String, String)>) -> Self {
+        Self { format, keys }
+    }
+
+    /// rename matching keys of the supplied object
+    fn rename(&self, object: &mut serde_json::Map<String, serde_json::Value>) {
+        for (from, to) in &self.keys {
+            if let Some(value) = object.remove(from) {
+                object.insert(to.clone(), value);
+            }
+        }
+    }
+}
+
+impl<S, N, F> FormatEvent<S, N> for Renamed<F>
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+    F: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> std::fmt::Result {
+        if self.keys.is_empty() {
+            return self.format.format_event(ctx, writer, event);
+        }
+
+        let mut line = String::new();
+        self.format
+            .format_event(ctx, Writer::new(&mut line), event)?;
+
+        let Ok(serde_json::Value::Object(mut object)) = serde_json::from_str(&line) else {
+            return writer.write_str(&line);
+        };
+
+        if let Some(serde_json::Value::Object(fields)) = object.get_mut("fields") {
+            self.rename(fields);
+        }
+        self.rename(&mut object);
+
+        writeln!(writer, "{}", serde_json::Value::Object(object))
+    }
+}
@@ -22,7 +22,7 @@
 //! ```
 //! use entrypoint::prelude::*;
 //!
-//! #[derive(clap::Parser, DotEnvDefault, LoggerDefault, Debug)]
+//! #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug)]
 //! #[log_format(pretty)]
 //! #[log_level(entrypoint::LevelFilter::DEBUG)]
 //! #[log_writer(std::io::stdout)]
@@ -45,27 +45,156 @@
 //! ```
 //!
 //! # Feature Flags
-//! Name       | Description                     | Default?
-//! -----------|---------------------------------|---------
-//! [`macros`] | Enables optional utility macros | Yes
+//! Name              | Description                                               | Default?
+//! ------------------|-----------------------------------------------------------|---------
+//! [`macros`]        | Enables optional utility macros                           | Yes
+//! `log`             | Forward `log` crate records to the subscriber             | Yes
+//! `async-std`       | Run async entrypoint functions on async-std               | No
+//! `bunyan`          | Enables the bunyan JSON log format                        | No
+//! `crash-report`    | Write a crash report file on panic (in release builds)    | No
+//! `daemon`          | Detach into the background w/ `--daemon` (unix only)      | No
+//! `eventlog`        | Enables the Windows Event Log as a log writer             | No
+//! `flame`           | Record spans for flamegraph profiling                     | No
+//! `gelf`            | Send logs to Graylog (GELF) when `GELF_HOST` is set       | No
+//! `health`          | Serve `/healthz` & `/readyz` when `HEALTH_ADDR` is set    | No
+//! `journald`        | Enables the systemd journal as a log writer               | No
+//! `metrics`         | Serve Prometheus metrics when `METRICS_ADDR` is set       | No
+//! `otel`            | Export spans via OpenTelemetry (OTLP) when configured     | No
+//! `sentry`          | Report errors to Sentry when `SENTRY_DSN` is set          | No
+//! `shutdown`        | Graceful shutdown on ctrl-c/`SIGTERM`                     | No
+//! `signals`         | Map signals to actions (see `SignalConfig`)               | No
+//! `smol`            | Run async entrypoint functions on smol                    | No
+//! `syslog`          | Enables RFC 5424 syslog as a log writer                   | No
+//! `systemd`         | Notify systemd of readiness (`Type=notify`) & watchdog    | No
+//! `tokio`           | Run async entrypoint functions on tokio (+ `shutdown`)    | No
+//! `tokio-console`   | Serve tokio-console when `TOKIO_CONSOLE` is set           | No
+//! `toml`            | Enables TOML config files (see `ConfigParserConfig`)      | No
+//! `tree`            | Enables the span tree log format (for development)        | No
+//! `update-notifier` | Log a notice when a newer release is available            | No
+//! `vault`           | Decrypt `.env.vault` files when `DOTENV_KEY` is set       | No
+//! `wasm`            | Enables the browser console as a log writer               | No
+//! `watch`           | Reload dotenv files when they change                      | No
+//! `windows-service` | Run as a Windows service when started by the SCM          | No
+//! `yaml`            | Enables YAML config files (see `ConfigParserConfig`)      | No
+//!
+//! The `tokio` feature enables `shutdown` (i.e. so the `cancellation_token()` is cancelled on ctrl-c/`SIGTERM`), which installs
+//! signal handlers by default. Opt out w/ `EntrypointConfig::shutdown_signals` (e.g. if the application handles signals itself).
 //!
 
 pub extern crate anyhow;
 pub extern crate clap;
+pub extern crate clap_complete;
+pub extern crate serde_json;
 pub extern crate tracing;
+pub extern crate tracing_appender;
+pub extern crate tracing_error;
 pub extern crate tracing_subscriber;
 
 #[cfg(feature = "macros")]
 pub extern crate entrypoint_macros;
 
+#[cfg(feature = "metrics")]
+pub extern crate metrics;
+
+#[cfg(feature = "tokio")]
+pub extern crate tokio;
+
+#[cfg(feature = "tokio")]
+pub extern crate tokio_util;
+
+#[cfg(feature = "async-std")]
+pub extern crate async_std;
+
+#[cfg(feature = "smol")]
+pub extern crate smol;
+
+#[cfg(all(test, not(feature = "tokio")))]
+use tokio as _; // integration tests only
+
+mod app;
+mod build_info;
+mod capabilities;
+mod color;
+mod completions;
+mod config;
+mod config_dir;
+#[cfg(feature = "wasm")]
+mod console;
+mod context;
+#[cfg(feature = "crash-report")]
+mod crash_report;
+#[cfg(feature = "daemon")]
+mod daemon;
+mod dispatch;
+mod doctor;
+mod dotenv;
+mod dotenv_audit;
+#[cfg(any(feature = "signals", feature = "watch"))]
+mod dotenv_reload;
+mod dump_env;
+mod env_config;
+mod env_map;
+mod env_schema;
+mod env_source;
+#[cfg(feature = "flame")]
+mod flame;
+mod flush;
+#[cfg(feature = "gelf")]
+mod gelf;
+mod handle;
+#[cfg(feature = "health")]
+mod health;
+mod json;
+mod logfmt;
+#[cfg(feature = "otel")]
+mod otel;
+mod panic;
+mod precedence;
+#[cfg(feature = "metrics")]
+mod prometheus;
+mod provenance;
+mod rate_limit;
+mod redact;
+mod retry;
+mod ring_buffer;
+#[cfg(feature = "sentry")]
+mod sentry;
+#[cfg(feature = "windows-service")]
+mod service;
+#[cfg(feature = "shutdown")]
+mod shutdown;
+#[cfg(feature = "signals")]
+mod signals;
+mod span_trace;
+mod startup;
+#[cfg(feature = "syslog")]
+mod syslog;
+#[cfg(feature = "systemd")]
+mod systemd;
+mod timer;
+#[cfg(feature = "update-notifier")]
+mod update;
+#[cfg(feature = "vault")]
+mod vault;
+mod verbosity;
+#[cfg(feature = "watch")]
+mod watch;
+mod watchdog;
+mod workdir;
+mod writer;
+
 /// re-export [`entrypoint_macros`](https://crates.io/crates/entrypoint_macros)
 #[cfg(feature = "macros")]
 pub mod macros {
     pub use crate::entrypoint_macros::entrypoint;
+    pub use crate::entrypoint_macros::Dispatch;
     pub use crate::entrypoint_macros::DotEnvDefault;
+    pub use crate::entrypoint_macros::EntrypointDefault;
     pub use crate::entrypoint_macros::LoggerDefault;
 }
 
+pub mod testing;
+
 /// essential [traits](#traits) and re-exports
 pub mod prelude {
     pub use crate::anyhow;
@@ -80,36 +209,92 @@ pub mod prelude {
     };
     pub use crate::tracing::{debug_span, error_span, info_span, span, trace_span, warn_span};
 
+    pub use crate::tracing_appender;
+
+    pub use crate::tracing_error;
+    pub use crate::tracing_error::SpanTrace;
+
     pub use crate::tracing_subscriber;
     pub use crate::tracing_subscriber::filter::LevelFilter;
     pub use crate::tracing_subscriber::fmt::{
-        format::{Compact, Format, Full, Json, Pretty},
+        format::{Compact, FmtSpan, Format, Full, Json, Pretty},
         FormatEvent, FormatFields, Layer, MakeWriter,
     };
     pub use crate::tracing_subscriber::prelude::*;
     pub use crate::tracing_subscriber::registry::LookupSpan;
     pub use crate::tracing_subscriber::reload;
+    pub use crate::tracing_subscriber::EnvFilter;
     pub use crate::tracing_subscriber::Registry;
 
-    pub use crate::Entrypoint;
+    pub use crate::build_info::BuildInfo;
+    pub use crate::config::{ConfigFile, ConfigSource};
+    pub use crate::dispatch::Dispatch;
+    pub use crate::dotenv::DotEnvFile;
+    pub use crate::dotenv_audit::DotEnvAudit;
+    pub use crate::env_map::EnvMap;
+    pub use crate::env_schema::EnvRule;
+    pub use crate::env_source::{EnvSource, SecretDir};
+    pub use crate::handle::LoggerHandle;
+    pub use crate::precedence::PrecedencePolicy;
+    pub use crate::provenance::EnvOrigin;
+    pub use crate::retry::RetryPolicy;
+    #[cfg(feature = "shutdown")]
+    pub use crate::shutdown::{ShutdownReason, ShutdownToken};
+    #[cfg(feature = "signals")]
+    pub use crate::signals::{Signal, SignalAction, SignalConfig};
+    pub use crate::span_trace::SpanTraceExt;
+    #[cfg(feature = "tokio")]
+    pub use crate::RuntimeConfig;
+    pub use crate::{ConfigParser, ConfigParserConfig};
     pub use crate::{DotEnvParser, DotEnvParserConfig};
+    pub use crate::{Entrypoint, EntrypointConfig};
+
+    #[cfg(feature = "wasm")]
+    pub use crate::console::ConsoleWriter;
+    #[cfg(feature = "syslog")]
+    pub use crate::syslog::Syslog;
+    pub use crate::timer::{TimeZone, Timer, ZonedTimer};
+    #[cfg(feature = "update-notifier")]
+    pub use crate::update::UpdateSource;
+    pub use crate::verbosity::Verbosity;
+    pub use crate::workdir::WorkdirConfig;
+    pub use crate::writer::{Rotation, SplitWriter};
     pub use crate::{Logger, LoggerConfig};
 
     #[cfg(feature = "macros")]
     pub use crate::macros::*;
 }
 
+use crate::anyhow::Context as _; // i.e. shadowed by the (entrypoint) Context re-export
+pub use crate::build_info::emit_build_info;
+pub use crate::config_dir::config_dir;
+pub use crate::context::{Context, StartupTimings};
+pub use crate::env_config::from_env;
+pub use crate::flush::FlushGuard;
+pub use crate::handle::logger_handle;
+#[cfg(feature = "health")]
+pub use crate::health::{set_healthy, set_ready};
 pub use crate::prelude::*;
+pub use crate::provenance::{env_origin, env_origins};
+#[cfg(feature = "windows-service")]
+pub use crate::service::service_paused;
+#[cfg(feature = "tokio")]
+pub use crate::shutdown::cancellation_token;
+#[cfg(feature = "shutdown")]
+pub use crate::shutdown::{shutdown_reason, shutdown_requested};
+pub use crate::span_trace::span_trace;
+pub use crate::watchdog::Watchdog;
 
 /// blanket implementation to wrap a function with "`main()`" setup/initialization boilerplate
 ///
 /// Refer to required [trait](crate#traits) bounds for more information and customization options.
+/// The args must also be [`Clone`] & [`Send`] (refer to [`EntrypointConfig::post_run`] & [`EntrypointConfig::on_shutdown`]).
 ///
 /// # Examples
 /// **Don't copy this code example. Use the [`macros::entrypoint`] attribute macro instead.**
 /// ```
 /// # use entrypoint::prelude::*;
-/// # #[derive(clap::Parser, DotEnvDefault, LoggerDefault)]
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
 /// struct Args {}
 ///
 /// // this function "replaces" `main()`
@@ -119,398 +304,4563 @@ pub use crate::prelude::*;
 ///
 /// // execute entrypoint from main
 /// fn main() -> anyhow::Result<()> {
-///     <Args as clap::Parser>::parse().entrypoint(entrypoint)
+///     <Args as entrypoint::Entrypoint>::parse_args().entrypoint(entrypoint)
 /// }
 /// ```
-pub trait Entrypoint: clap::Parser + DotEnvParserConfig + LoggerConfig {
+pub trait Entrypoint:
+    clap::Parser + Clone + Send + 'static + DotEnvParserConfig + EntrypointConfig + LoggerConfig
+{
+    /// parse CLI args, handling builtin entrypoint flags
+    ///
+    /// Drop-in replacement for [`clap::Parser::parse`].
+    /// Builtin flags (e.g. [`--capabilities`](EntrypointConfig::capabilities_flag)) are processed first;
+    /// these exit the process before any dotenv/logging setup occurs.
+    ///
+    /// The [`clap::Command`] is customized per [`EntrypointConfig::cli_styles`] and [`EntrypointConfig::cli_help_template`],
+    /// and the builtin [`--color`](EntrypointConfig::color_flag), [`--completions`](EntrypointConfig::completions_flag),
+    /// & [`--doctor`](EntrypointConfig::doctor_flag) (and [`--dump-env`](EntrypointConfig::dump_env_flag), if enabled) args are added
+    /// (as is `--daemon`, w/ the `daemon` [feature](crate#feature-flags)).
+    #[must_use]
+    fn parse_args() -> Self {
+        if Self::capabilities_flag() && builtin_flag_requested(capabilities::FLAG) {
+            println!("{}", Self::capabilities());
+            std::process::exit(0);
+        }
+
+        if Self::build_info_flag() && builtin_flag_requested(build_info::FLAG) {
+            println!("{}", Self::build_details());
+            std::process::exit(0);
+        }
+
+        let mut command = command::<Self>();
+        if let Some(shell) = completions::requested().filter(|_| Self::completions_flag()) {
+            print!("{}", completions::generate(&mut command, shell));
+            std::process::exit(0);
+        }
+
+        let mut matches = command.get_matches_mut();
+        color::set(&matches);
+        doctor::set(&matches);
+        dump_env::set(&matches);
+        #[cfg(feature = "daemon")]
+        daemon::set(&matches);
+        precedence::set(&command, &matches);
+        verbosity::set(&matches);
+        Self::from_arg_matches_mut(&mut matches).unwrap_or_else(|e| e.format(&mut command).exit())
+    }
+
+    /// machine-readable (JSON) description of this binary
+    ///
+    /// Includes the name, version, enabled entrypoint features,
+    /// required (`#[arg(env)]`) environment variables, and subcommands.
+    ///
+    /// This is what the builtin [`--capabilities`](EntrypointConfig::capabilities_flag) flag prints.
+    #[must_use]
+    fn capabilities() -> String {
+        capabilities::describe(&<Self as clap::CommandFactory>::command()).to_string()
+    }
+
+    /// shell completion script for this binary (i.e. w/ the builtin args)
+    ///
+    /// This is what the builtin [`--completions <SHELL>`](EntrypointConfig::completions_flag) flag prints.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// # #[command(name = "my-app")]
+    /// struct Args {}
+    ///
+    /// let script = Args::completions(entrypoint::clap_complete::Shell::Bash);
+    /// assert!(script.contains("my-app"));
+    /// ```
+    #[must_use]
+    fn completions(shell: clap_complete::Shell) -> String {
+        completions::generate(&mut command::<Self>(), shell)
+    }
+
+    /// machine-readable (JSON) build information of this binary
+    ///
+    /// Includes the name, version, and the [`EntrypointConfig::build_info`] fields (i.e. git commit, build timestamp,
+    /// rustc version, target, profile, and enabled features).
+    ///
+    /// This is what the builtin [`--build-info`](EntrypointConfig::build_info_flag) flag prints.
+    #[must_use]
+    fn build_details() -> String {
+        build_info::describe(
+            &<Self as clap::CommandFactory>::command(),
+            &Self::build_info(),
+        )
+        .to_string()
+    }
+
+    /// the fully-resolved environment, as sorted `KEY=value` lines
+    ///
+    /// Values of sensitive keys (i.e. `*_SECRET`, `*_TOKEN`, `*_KEY`, `*PASSWORD*`,
+    /// and any [`LoggerConfig::default_log_redact`] patterns) are masked as `[REDACTED]`.
+    ///
+    /// This is what the builtin [`--dump-env`](EntrypointConfig::dump_env_flag) flag prints.
+    #[must_use]
+    fn dump_env(&self) -> String {
+        dump_env::describe(&self.default_log_redact())
+    }
+
+    /// the process exit code for the result of [`Entrypoint::entrypoint`] (i.e. what `main` returns)
+    ///
+    /// An `Ok` value is [reported](std::process::Termination::report) as usual (e.g. `()` is success).
+    /// An error is printed to stderr (as `main` would), then mapped via [`EntrypointConfig::exit_code`].
+    ///
+    /// The [`macros::entrypoint`] attribute macro's generated `main` returns this.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     Args::exit(Args::parse_args().entrypoint(|args| {
+    ///         info!("this is my main function!");
+    ///         Ok(())
+    ///     }))
+    /// }
+    /// ```
+    fn exit<T: std::process::Termination>(result: anyhow::Result<T>) -> std::process::ExitCode {
+        match result {
+            Ok(value) => value.report(),
+            Err(e) => {
+                eprintln!("Error: {e:?}");
+                Self::exit_code(&e)
+            }
+        }
+    }
+
+    /// run setup/configuration/initialization (i.e. [`dotenv`](DotEnvParserConfig) & [logging](LoggerConfig)) and return the args
+    ///
+    /// For applications that can't hand control of `main` to [`Entrypoint::entrypoint`] (e.g. existing frameworks,
+    /// FFI hosts, plugin systems), but still want the setup pipeline. Setup is the same as [`Entrypoint::entrypoint`]'s:
+    /// * [`dotenv`](DotEnvParserConfig) processing (incl. the builtin `--doctor` & `--dump-env` flags)
+    /// * [required](DotEnvParserConfig::required_env_vars), [schema](DotEnvParserConfig::env_schema),
+    ///   & [audit](DotEnvParserConfig::dotenv_audit) verification
+    /// * [daemonizing](EntrypointConfig::daemonize) (w/ the `daemon` [feature](crate#feature-flags)) and
+    ///   changing the [working directory](EntrypointConfig::workdir_config)
+    /// * [`Logger::log_init`]
+    ///
+    /// Events logged during setup are buffered, then replayed through the configured layers once [`Logger::log_init`]
+    /// completes (or to stderr, if setup fails). Replayed events are timestamped when replayed.
+    ///
+    /// Everything else [`Entrypoint::entrypoint`] does around the function (e.g. hooks, shutdown/signal handling,
+    /// the execution timeout, metrics & health probes) is left to the caller. Hold a [`FlushGuard`] to flush any
+    /// [non-blocking](LoggerConfig::default_log_non_blocking) log lines on exit.
+    ///
+    /// Setup registers the global log subscriber, so only call this once (and not alongside [`Entrypoint::entrypoint`]).
+    ///
+    /// # Errors
+    /// * failure processing [`dotenv`](DotEnvParserConfig) file(s)
+    /// * missing [required](DotEnvParserConfig::required_env_vars) environment variable(s)
+    /// * [invalid](DotEnvParserConfig::env_schema) environment variable(s)
+    /// * [unknown](DotEnvParserConfig::dotenv_audit) dotenv variable(s)
+    /// * failure configuring [logging](LoggerConfig) (e.g. a global subscriber is already registered)
+    /// * failure changing the [working directory](EntrypointConfig::workdir_config)
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// // e.g. called by a plugin host, which owns `main`
+    /// fn plugin_load() -> anyhow::Result<()> {
+    ///     let _flush = entrypoint::FlushGuard::new();
+    ///     let args = Args::parse_args().init()?;
+    ///     info!("plugin loaded");
+    /// #   let _ = args;
+    ///     Ok(())
+    /// }
+    /// # plugin_load().unwrap();
+    /// ```
+    fn init(self) -> anyhow::Result<Self> {
+        let started = std::time::Instant::now();
+        let mut dotenv = std::time::Duration::ZERO;
+
+        // buffer setup events until the global subscriber is set by log_init()
+        let startup = startup::Buffer::default();
+        let entrypoint = tracing::subscriber::with_default(
+            Registry::default().with(startup.clone()),
+            || -> anyhow::Result<Self> {
+                let policy = self.precedence_policy();
+                let doctor = self.doctor();
+                let entrypoint = self.process_dotenv_files().and_then(|entrypoint| {
+                    if policy == PrecedencePolicy::CliEnv {
+                        Ok(entrypoint) // dotenv doesn't affect the args
+                    } else {
+                        Self::parse_args() // parse again, dotenv might have defined some of the arg(env) fields
+                            .process_dotenv_files() // dotenv, again... same reason as above
+                    }
+                });
+                if doctor {
+                    doctor::run(entrypoint);
+                }
+                let entrypoint = entrypoint?;
+                precedence::log(policy);
+
+                if let Some(exit) = dump_env::requested() {
+                    print!("{}", entrypoint.dump_env());
+                    if exit {
+                        std::process::exit(0);
+                    }
+                }
+                entrypoint.verify_required_env_vars()?;
+                entrypoint.verify_env_schema()?;
+                entrypoint.verify_dotenv_keys()?;
+
+                // before any threads are started (i.e. by log_init)
+                #[cfg(feature = "daemon")]
+                if entrypoint.daemonize() {
+                    daemon::daemonize(
+                        entrypoint.daemon_pid_file().as_deref(),
+                        entrypoint
+                            .default_log_file()
+                            .map(|file| writer::expand_home(&file))
+                            .as_deref(),
+                    )?;
+                }
+                entrypoint.workdir_config().apply()?;
+                dotenv = started.elapsed();
+
+                entrypoint.log_init(None)
+            },
+        );
+        startup.replay();
+        context::record(started, dotenv, started.elapsed().saturating_sub(dotenv));
+        entrypoint
+    }
+
     /// run setup/configuration/initialization and execute supplied function
     ///
     /// Customize if/as needed with the other entrypoint [traits](crate#traits).
     ///
+    /// Setup is performed by [`Entrypoint::init`] (i.e. events logged during setup are buffered & replayed).
+    ///
+    /// Any buffered [non-blocking](LoggerConfig::default_log_non_blocking) log lines (and network exporters)
+    /// are flushed after the function returns, or panics (refer to [`FlushGuard`]).
+    ///
+    /// With an [`EntrypointConfig::execution_timeout`], the process is aborted (w/ exit code `124`) if the function
+    /// doesn't return in time.
+    ///
+    /// With the `shutdown` [feature](crate#feature-flags), ctrl-c/`SIGTERM` trigger a graceful shutdown
+    /// (refer to [`EntrypointConfig::shutdown_signals`]). W/ the `tokio` feature (which enables `shutdown`), the `cancellation_token()`
+    /// (also passed via [`Context`]) is cancelled.
+    ///
+    /// With the `systemd` [feature](crate#feature-flags), `READY=1` is sent (to the `NOTIFY_SOCKET`) before the function runs,
+    /// the watchdog is pinged (if `WATCHDOG_USEC` is set), and `STOPPING=1` is sent on shutdown (or once the function returns).
+    ///
+    /// With the `windows-service` [feature](crate#feature-flags), the process runs as a Windows service when started by the
+    /// Service Control Manager (refer to [`EntrypointConfig::windows_service_name`]).
+    ///
+    /// With the `metrics` [feature](crate#feature-flags), a Prometheus `/metrics` endpoint is served on
+    /// `METRICS_ADDR` (e.g. `0.0.0.0:9000`), if set. Record app metrics w/ the re-exported [`metrics`](https://docs.rs/metrics) crate.
+    /// Builtin metrics are `entrypoint_startup_seconds` (setup duration) and `entrypoint_result_total` (labeled `result="ok|error"`).
+    ///
+    /// With the `health` [feature](crate#feature-flags), `/healthz` & `/readyz` probes are served on `HEALTH_ADDR` (e.g. `0.0.0.0:8080`), if set.
+    /// `/readyz` is OK once setup (incl. [`EntrypointConfig::pre_run`]) completes, until the function returns (or a shutdown is requested).
+    /// Report app health w/ [`set_healthy`] & [`set_ready`].
+    ///
     /// # Errors
     /// * failure processing [`dotenv`](DotEnvParserConfig) file(s)
+    /// * missing [required](DotEnvParserConfig::required_env_vars) environment variable(s)
+    /// * [invalid](DotEnvParserConfig::env_schema) environment variable(s)
+    /// * [unknown](DotEnvParserConfig::dotenv_audit) dotenv variable(s)
     /// * failure configuring [logging](LoggerConfig)
+    /// * failure changing the [working directory](EntrypointConfig::workdir_config)
+    /// * [`EntrypointConfig::pre_run`] failure
+    #[allow(clippy::too_many_lines)]
     fn entrypoint<F, T>(self, function: F) -> anyhow::Result<T>
     where
         F: FnOnce(Self) -> anyhow::Result<T>,
     {
-        let entrypoint = {
-            // use temp/local/default log subscriber until global is set by log_init()
-            let _log = tracing::subscriber::set_default(
-                Registry::default().with(self.default_log_layer()),
+        // flush when the function returns (or panics)
+        let _flush = FlushGuard::new();
+
+        #[cfg(feature = "metrics")]
+        let started = std::time::Instant::now();
+
+        let entrypoint = self.init()?;
+        #[cfg(feature = "metrics")]
+        if prometheus::install() {
+            prometheus::startup(started);
+        }
+        #[cfg(feature = "health")]
+        health::install();
+
+        let build = Self::build_info();
+        info!(
+            build.commit = build.git_commit,
+            build.timestamp = build.timestamp,
+            build.rustc = build.rustc,
+            "setup/config complete; executing entrypoint function"
+        );
+
+        #[cfg(any(feature = "signals", feature = "watch"))]
+        dotenv_reload::start();
+
+        #[cfg(feature = "watch")]
+        if entrypoint.dotenv_watch() {
+            if let Err(e) = watch::spawn(dotenv_reload::reload::<Self>) {
+                warn!("failed to watch dotenv file(s): {e:#}");
+            }
+        }
+
+        #[cfg(feature = "update-notifier")]
+        if let (Some(source), Some(version), false) = (
+            entrypoint.update_source(),
+            <Self as clap::CommandFactory>::command().get_version(),
+            entrypoint.telemetry_disabled(),
+        ) {
+            update::spawn(
+                <Self as clap::CommandFactory>::command()
+                    .get_name()
+                    .to_owned(),
+                version.to_owned(),
+                source,
             );
+        }
+
+        // after log_init(), so panics are also logged (refer to LoggerConfig::default_log_panics)
+        #[cfg(feature = "crash-report")]
+        if entrypoint.crash_report() {
+            let command = <Self as clap::CommandFactory>::command();
+            crash_report::install(crash_report::CrashReport {
+                name: command.get_name().to_owned(),
+                version: command.get_version().unwrap_or_default().to_owned(),
+                dir: entrypoint.crash_report_dir(),
+                contact: entrypoint.crash_report_contact(),
+            });
+        }
+
+        #[cfg(feature = "shutdown")]
+        {
+            let args = entrypoint.clone();
+            shutdown::configure(move || args.on_shutdown(), entrypoint.shutdown_timeout());
+            if entrypoint.shutdown_signals() {
+                shutdown::listen();
+            }
+        }
+
+        #[cfg(feature = "signals")]
+        {
+            signals::configure(reload_config::<Self>);
+            signals::install(entrypoint.signal_config());
+        }
 
-            self.process_dotenv_files()?;
+        #[cfg(feature = "windows-service")]
+        if let Some(name) = entrypoint.windows_service_name() {
+            service::start(&name);
+        }
 
-            Self::parse() // parse again, dotenv might have defined some of the arg(env) fields
-                .process_dotenv_files()? // dotenv, again... same reason as above
-                .log_init(None)?
+        let span = entrypoint
+            .default_log_app_metadata()
+            .then(|| app::span(&<Self as clap::CommandFactory>::command()));
+        let result = {
+            let _app = span.as_ref().map(tracing::Span::enter);
+            let _watchdog = entrypoint.execution_timeout().map(Watchdog::start);
+            let result = entrypoint
+                .pre_run()
+                .context("pre_run hook failed")
+                .and_then(|()| {
+                    #[cfg(feature = "systemd")]
+                    systemd::ready();
+                    #[cfg(feature = "windows-service")]
+                    service::running();
+                    #[cfg(feature = "health")]
+                    health::running();
+                    function(entrypoint.clone())
+                });
+            #[cfg(feature = "health")]
+            health::stopped();
+            #[cfg(feature = "systemd")]
+            systemd::stopping();
+            #[cfg(feature = "windows-service")]
+            service::stopping();
+            entrypoint.post_run(&result);
+            result
         };
-        info!("setup/config complete; executing entrypoint function");
+        #[cfg(feature = "shutdown")]
+        shutdown::complete();
+        #[cfg(feature = "windows-service")]
+        service::stopped(result.is_ok());
+        if let Some((e, trace)) = result
+            .as_ref()
+            .err()
+            .and_then(|e| Some((e, span_trace(e)?)))
+        {
+            error!("entrypoint function failed: {e:#}\n{trace}");
+        }
+
+        if result.is_err() {
+            ring_buffer::dump();
+        }
+
+        #[cfg(feature = "metrics")]
+        prometheus::result(&result);
+
+        #[cfg(feature = "sentry")]
+        if let Err(e) = &result {
+            sentry::capture(e);
+        }
 
-        function(entrypoint)
+        result
     }
-}
-impl<T: clap::Parser + DotEnvParserConfig + LoggerConfig> Entrypoint for T {}
 
-/// automatic [`tracing`] & [`tracing_subscriber`] configuration
-///
-/// Available configuration for the [`Logger`] trait.
-///
-/// Default implementations are what you'd expect.
-/// Use this [derive macro](macros::LoggerDefault) for typical use cases.
-///
-/// # Examples
-/// ```
-/// # use entrypoint::prelude::*;
-/// # #[derive(clap::Parser, DotEnvDefault)]
-/// #[derive(LoggerDefault)]
-/// #[log_format(full)]
-/// #[log_level(entrypoint::LevelFilter::DEBUG)]
-/// #[log_writer(std::io::stdout)]
-/// struct Args {}
-///
-/// #[entrypoint::entrypoint]
-/// fn main(args: Args) -> anyhow::Result<()> {
-///     // logs are ready to use
-///     info!("hello!");
-/// #   Ok(())
-/// }
-/// ```
-/// For advanced customization requirements, refer to [`LoggerConfig::bypass_log_init`].
-pub trait LoggerConfig: clap::Parser {
-    /// hook to disable/enable automatic initialization
+    /// [`Entrypoint::entrypoint`], also handing the function a typed config extracted from the environment
     ///
-    /// This disrupts automatic initialization so that completely custom [`Layer`]s can be provided to [`Logger::log_init`].
-    /// This is intended only for advanced use cases, such as:
-    /// 1. multiple [`Layer`]s are required
-    /// 2. a [reload handle](tracing_subscriber::reload::Handle) needs to be kept accessible
+    /// After setup (i.e. once [`dotenv`](DotEnvParserConfig) processing completes), the environment is
+    /// deserialized into `C` (refer to [`from_env`] & [`DotEnvParserConfig::env_config_prefix`]).
     ///
-    /// Default behvaior ([`false`]) is to call [`Logger::log_init`] on startup and
-    /// register the default layer provided by [`LoggerConfig::default_log_layer`].
+    /// The [`macros::entrypoint`] attribute macro uses this for functions w/ a second (config) parameter.
     ///
-    /// Overriding this to [`true`] will **not** automatically call [`Logger::log_init`] on startup.
-    /// All other defaults provided by the [`LoggerConfig`] trait methods are ignored.
-    /// The application is then **required** to directly call [`Logger::log_init`] with explicitly provided layer(s).
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
+    /// * failure extracting the config from the environment
     ///
     /// # Examples
     /// ```
     /// # use entrypoint::prelude::*;
-    /// # #[derive(clap::Parser, DotEnvDefault)]
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
     /// struct Args {}
     ///
-    /// impl entrypoint::LoggerConfig for Args {
-    ///     fn bypass_log_init(&self) -> bool { true }
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     #[serde(default)]
+    ///     port: u16,
     /// }
     ///
     /// #[entrypoint::entrypoint]
-    /// fn main(args: Args) -> anyhow::Result<()> {
-    ///     // logging hasn't been configured yet
-    ///     assert!(!enabled!(entrypoint::Level::ERROR));
+    /// fn main(args: Args, config: Config) -> anyhow::Result<()> {
+    ///     info!("listening on {}", config.port);
+    /// #   Ok(())
+    /// }
+    /// ```
+    fn entrypoint_with_config<C, F, T>(self, function: F) -> anyhow::Result<T>
+    where
+        C: serde::de::DeserializeOwned,
+        F: FnOnce(Self, C) -> anyhow::Result<T>,
+    {
+        self.entrypoint(|args| {
+            let config = from_env(args.env_config_prefix().as_deref())
+                .inspect_err(|e| error!("failed to extract config from the environment: {e}"))
+                .context("failed to extract config from the environment")?;
+
+            function(args, config)
+        })
+    }
+
+    /// [`Entrypoint::entrypoint`], also handing the function a typed config merged from config files & the environment
     ///
-    ///     // must manually config/init logging
-    ///     let (layer, reload_handle) = reload::Layer::new(
-    ///         tracing_subscriber::fmt::Layer::default()
-    ///             .event_format(args.default_log_format())
-    ///             .with_writer(args.default_log_writer())
-    ///             .with_filter(args.default_log_level()),
-    ///     );
-    ///     let args = args.log_init(Some(vec![layer.boxed()]))?;
+    /// After setup (i.e. once [`dotenv`](DotEnvParserConfig) processing completes), the config layers are
+    /// merged & deserialized into `C` (refer to [`ConfigParser::load_config`]).
     ///
-    ///     // OK... now logging should work
-    ///     assert!( enabled!(entrypoint::Level::ERROR));
-    ///     assert!(!enabled!(entrypoint::Level::TRACE));
+    /// The [`macros::entrypoint`] attribute macro uses this w/ the `config_files` argument (i.e. `#[entrypoint::entrypoint(config_files)]`).
     ///
-    ///     // we've maintained direct access to the layer and reload handle
-    ///     let _ = reload_handle.modify(|layer| *layer.filter_mut() = entrypoint::LevelFilter::TRACE);
-    ///     assert!( enabled!(entrypoint::Level::TRACE));
-    /// #   Ok(())
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
+    /// * failure loading the config (refer to [`ConfigParser::load_config`])
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// impl ConfigParserConfig for Args {
+    ///     fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+    ///         vec![Box::new(ConfigFile::Optional("config.json".into()))]
+    ///     }
+    /// }
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     #[serde(default)]
+    ///     port: u16,
+    /// }
+    ///
+    /// fn main() -> anyhow::Result<()> {
+    ///     Args::parse_args().entrypoint_with_config_files(|args, config: Config| {
+    ///         info!("listening on {}", config.port);
+    ///         Ok(())
+    ///     })
     /// }
     /// ```
-    fn bypass_log_init(&self) -> bool {
-        false
+    fn entrypoint_with_config_files<C, F, T>(self, function: F) -> anyhow::Result<T>
+    where
+        Self: ConfigParser,
+        C: serde::de::DeserializeOwned,
+        F: FnOnce(Self, C) -> anyhow::Result<T>,
+    {
+        self.entrypoint(|args| {
+            let config = args
+                .load_config()
+                .inspect_err(|e| error!("failed to load config: {e:#}"))
+                .context("failed to load config")?;
+
+            function(args, config)
+        })
     }
 
-    /// define the default [`tracing_subscriber`] [`LevelFilter`]
+    /// [`Entrypoint::entrypoint`], also handing the function the [`DotEnvParser::dotenv_env_map`] variables
     ///
-    /// Defaults to [`DEFAULT_MAX_LEVEL`](tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL).
+    /// Intended for [isolated](DotEnvParserConfig::dotenv_isolated) dotenv processing, where the process environment isn't mutated.
     ///
-    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    /// The [`macros::entrypoint`] attribute macro uses this w/ the `env` argument (i.e. `#[entrypoint::entrypoint(env)]`).
+    ///
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
+    /// * failure processing [`dotenv`](DotEnvParserConfig) file(s)
     ///
     /// # Examples
     /// ```
     /// # use entrypoint::prelude::*;
-    /// # #[derive(clap::Parser)]
-    /// struct Args {
-    ///     /// allow user to pass in debug level
-    ///     #[arg(long)]
-    ///     default_log_level: LevelFilter,
+    /// # #[derive(clap::Parser, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// impl DotEnvParserConfig for Args {
+    ///     fn dotenv_isolated(&self) -> bool { true }
     /// }
     ///
-    /// impl entrypoint::LoggerConfig for Args {
-    ///     fn default_log_level(&self) -> LevelFilter {
-    ///         self.default_log_level.clone()
-    ///     }
+    /// #[entrypoint::entrypoint(env)]
+    /// fn main(args: Args, env: EnvMap) -> anyhow::Result<()> {
+    ///     info!("running in {:?}", env.get("APP_ENV"));
+    /// #   Ok(())
     /// }
     /// ```
-    fn default_log_level(&self) -> LevelFilter {
-        tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL
+    fn entrypoint_with_env<F, T>(self, function: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(Self, EnvMap) -> anyhow::Result<T>,
+    {
+        self.entrypoint(|args| {
+            let env = args.dotenv_env_map()?;
+            function(args, env)
+        })
     }
 
-    /// define the default [`tracing_subscriber`] [`Format`]
+    /// [`Entrypoint::entrypoint`], also handing the function the [`Context`] built during setup
     ///
-    /// Defaults to [`Format::default`].
+    /// The context carries the shutdown token, the logger reload handle, the resolved environment variable
+    /// provenance, and the startup timings.
     ///
-    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    /// The [`macros::entrypoint`] attribute macro uses this w/ the `context` argument (i.e. `#[entrypoint::entrypoint(context)]`).
+    ///
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
     ///
     /// # Examples
     /// ```
     /// # use entrypoint::prelude::*;
-    /// # #[derive(clap::Parser)]
-    /// # struct Args {}
-    /// impl entrypoint::LoggerConfig for Args {
-    ///     fn default_log_format<S,N>(&self) -> impl FormatEvent<S,N> + Send + Sync + 'static
-    ///     where
-    ///         S: Subscriber + for<'a> LookupSpan<'a>,
-    ///         N: for<'writer> FormatFields<'writer> + 'static,
-    ///     {
-    ///         Format::default().pretty()
-    ///     }
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// fn main() -> anyhow::Result<()> {
+    ///     Args::parse_args().entrypoint_with_context(|args, ctx| {
+    ///         info!("setup took {:?}", ctx.timings.setup);
+    ///         Ok(())
+    ///     })
     /// }
     /// ```
-    fn default_log_format<S, N>(&self) -> impl FormatEvent<S, N> + Send + Sync + 'static
+    fn entrypoint_with_context<F, T>(self, function: F) -> anyhow::Result<T>
     where
-        S: Subscriber + for<'a> LookupSpan<'a>,
-        N: for<'writer> FormatFields<'writer> + 'static,
+        F: FnOnce(Self, Context) -> anyhow::Result<T>,
     {
-        Format::default()
+        self.entrypoint(|args| function(args, Context::current()))
     }
 
-    /// define the default [`tracing_subscriber`] [`MakeWriter`]
+    /// [`Entrypoint::entrypoint`], re-running the function on failure per the [`EntrypointConfig::retry_policy`]
     ///
-    /// Defaults to [`std::io::stdout`].
+    /// Transient startup failures (e.g. the database isn't up yet, a DNS blip) are retried (w/ backoff),
+    /// logging each failed attempt, rather than requiring an external supervisor loop.
+    /// The args are parsed once; each attempt receives a clone of them.
     ///
-    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    /// Setup (and [`EntrypointConfig::pre_run`]/[`EntrypointConfig::post_run`]) happens once, around all the attempts.
+    ///
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
+    /// * the last attempt's error (or the first non-[retryable](RetryPolicy::retry_if) error)
     ///
     /// # Examples
     /// ```
     /// # use entrypoint::prelude::*;
-    /// # #[derive(clap::Parser)]
-    /// # struct Args {}
-    /// impl entrypoint::LoggerConfig for Args {
-    ///     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
-    ///         std::io::stderr
+    /// # #[derive(clap::Parser, DotEnvDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn retry_policy(&self) -> RetryPolicy {
+    ///         RetryPolicy::new(3).backoff(std::time::Duration::from_millis(10))
     ///     }
     /// }
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     Args::exit(Args::parse_args().entrypoint_with_retry(|args| {
+    ///         info!("connecting...");
+    ///         Ok(())
+    ///     }))
+    /// }
     /// ```
-    fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
-        std::io::stdout
+    fn entrypoint_with_retry<F, T>(self, function: F) -> anyhow::Result<T>
+    where
+        F: FnMut(Self) -> anyhow::Result<T>,
+    {
+        self.entrypoint(|args| {
+            let policy = args.retry_policy();
+            retry::run(&policy, &args, function)
+        })
+    }
+
+    /// [`Entrypoint::entrypoint`], routing the `subcommand` to its handler function (refer to [`Dispatch`])
+    ///
+    /// Shared setup runs once, then the selected subcommand's handler receives the args (i.e. multi-command CLIs
+    /// don't need a `match` in the entrypoint function).
+    ///
+    /// # Errors
+    /// * refer to [`Entrypoint::entrypoint`]
+    /// * the handler function's error
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use entrypoint::prelude::*;
+    /// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {
+    ///     #[command(subcommand)]
+    ///     command: Command,
+    /// }
+    ///
+    /// #[derive(clap::Subcommand, Dispatch, Clone)]
+    /// #[dispatch(Args)]
+    /// enum Command {
+    ///     Serve { port: u16 },
+    ///     #[dispatch(run_migrations)]
+    ///     Migrate,
+    /// }
+    ///
+    /// fn serve(args: &Args, port: &u16) -> anyhow::Result<()> {
+    ///     info!("serving on {port}");
+    ///     Ok(())
+    /// }
+    ///
+    /// fn run_migrations(args: &Args) -> anyhow::Result<()> {
+    ///     info!("migrating");
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     Args::exit(Args::parse_args().entrypoint_dispatch(|args| &args.command))
+    /// }
+    /// ```
+    fn entrypoint_dispatch<C, F>(self, subcommand: F) -> anyhow::Result<()>
+    where
+        C: Dispatch<Self>,
+        F: FnOnce(&Self) -> &C,
+    {
+        self.entrypoint(|args| subcommand(&args).dispatch(&args))
+    }
+
+    /// one-time setup for benchmark binaries (e.g. [`criterion`](https://crates.io/crates/criterion))
+    ///
+    /// Benchmark harnesses own `main()` and the CLI, so this:
+    /// * parses args **without** CLI input (i.e. defaults and `#[arg(env)]` values only)
+    /// * processes [`dotenv`](DotEnvParserConfig) file(s)
+    /// * registers a quiet ([`LevelFilter::ERROR`]) global log subscriber, using the configured
+    ///   [format](LoggerConfig::default_log_format) and [writer](LoggerConfig::default_log_writer)
+    ///
+    /// Setup only happens once per process; call this freely from every bench group/function.
+    /// An already registered global subscriber is left in place.
+    ///
+    /// # Errors
+    /// * failure parsing args (e.g. a required arg has no default/env value)
+    /// * failure processing [`dotenv`](DotEnvParserConfig) file(s)
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// fn bench_group() -> anyhow::Result<()> {
+    ///     let args = Args::bench_setup()?;
+    ///     // ... criterion benchmarks ...
+    /// #   Ok(())
+    /// }
+    /// # bench_group().unwrap();
+    /// # bench_group().unwrap();
+    /// ```
+    fn bench_setup() -> anyhow::Result<Self> {
+        static SETUP: std::sync::OnceLock<Result<(), String>> = std::sync::OnceLock::new();
+
+        let parse = || Self::try_parse_from(std::env::args_os().take(1));
+
+        SETUP
+            .get_or_init(|| {
+                let setup = || -> anyhow::Result<()> {
+                    let args = parse()?.process_dotenv_files()?;
+
+                    let _ = tracing_subscriber::registry()
+                        .with(
+                            tracing_subscriber::fmt::Layer::default()
+                                .event_format(args.default_log_format())
+                                .with_writer(args.default_log_writer())
+                                .with_filter(LevelFilter::ERROR),
+                        )
+                        .try_init(); // don't fight over the global subscriber
+
+                    Ok(())
+                };
+
+                setup().map_err(|e| format!("{e:#}"))
+            })
+            .clone()
+            .map_err(anyhow::Error::msg)?;
+
+        Ok(parse()?)
+    }
+}
+impl<T> Entrypoint for T where
+    T: clap::Parser + Clone + Send + 'static + DotEnvParserConfig + EntrypointConfig + LoggerConfig
+{
+}
+
+/// the [`clap::Command`] for `T`, w/ [`EntrypointConfig`] customizations applied
+fn command<T: EntrypointConfig>() -> clap::Command {
+    let mut command = T::command();
+
+    if let Some(styles) = T::cli_styles() {
+        command = command.styles(styles);
+    }
+
+    if let Some(template) = T::cli_help_template() {
+        command = command.help_template(template);
+    }
+
+    let info = T::build_info();
+    if let Some(version) = command.get_version().filter(|_| !info.is_empty()) {
+        let long_version = build_info::long_version(version, &info);
+        command = command.long_version(long_version);
+    }
+
+    if T::color_flag()
+        && !command
+            .get_arguments()
+            .any(|arg| arg.get_id() == color::FLAG || arg.get_long() == Some(color::FLAG))
+    {
+        command = command.arg(color::arg());
+    }
+
+    if T::completions_flag()
+        && !command.get_arguments().any(|arg| {
+            arg.get_id() == completions::FLAG || arg.get_long() == Some(completions::FLAG)
+        })
+    {
+        command = command.arg(completions::arg());
+    }
+
+    if T::doctor_flag()
+        && !command
+            .get_arguments()
+            .any(|arg| arg.get_id() == doctor::FLAG || arg.get_long() == Some(doctor::FLAG))
+    {
+        command = command.arg(doctor::arg());
+    }
+
+    if T::dump_env_flag()
+        && !command
+            .get_arguments()
+            .any(|arg| arg.get_id() == dump_env::FLAG || arg.get_long() == Some(dump_env::FLAG))
+    {
+        command = command.arg(dump_env::arg());
+    }
+
+    #[cfg(feature = "daemon")]
+    if T::daemon_flag()
+        && !command
+            .get_arguments()
+            .any(|arg| arg.get_id() == daemon::FLAG || arg.get_long() == Some(daemon::FLAG))
+    {
+        command = command.arg(daemon::arg());
+    }
+
+    command
+}
+
+/// whether a builtin flag was passed on the command line
+///
+/// Checked before the user's [`clap::Parser`] runs, so builtin flags work regardless of
+/// required args/subcommands. Anything after a `--` terminator is ignored.
+fn builtin_flag_requested(flag: &str) -> bool {
+    std::env::args_os()
+        .skip(1)
+        .take_while(|arg| arg != "--")
+        .any(|arg| arg == flag)
+}
+
+/// [`dotenv_reload::reload`], logging any failure (for [`SignalAction::ReloadConfig`])
+#[cfg(feature = "signals")]
+fn reload_config<T: Entrypoint>() {
+    if let Err(e) = dotenv_reload::reload::<T>() {
+        error!("failed to reload dotenv file(s): {e:#}");
+    }
+}
+
+/// automatic [`Entrypoint`] configuration
+///
+/// Available configuration for the [`Entrypoint`] trait (i.e. builtin flags & the entrypoint function's lifecycle).
+///
+/// Default implementations are what you'd expect.
+/// Use this [derive macro](macros::EntrypointDefault) for typical use cases.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, LoggerDefault, Clone)]
+/// #[derive(EntrypointDefault)]
+/// struct Args {}
+///
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> anyhow::Result<()> {
+/// #   Ok(())
+/// }
+/// ```
+pub trait EntrypointConfig: clap::Parser {
+    /// whether the builtin `--capabilities` flag is enabled
+    ///
+    /// When passed, a JSON description of the binary (refer to [`Entrypoint::capabilities`])
+    /// is printed to stdout and the process exits. Orchestration/packaging tools can use this
+    /// to introspect entrypoint binaries uniformly.
+    ///
+    /// The flag is hidden from `--help` output.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    /// Override to [`false`] if this conflicts with an application defined `--capabilities` arg.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn capabilities_flag() -> bool { false }
+    /// }
+    /// ```
+    #[must_use]
+    fn capabilities_flag() -> bool {
+        true
+    }
+
+    /// whether the builtin `--build-info` flag is enabled
+    ///
+    /// When passed, a JSON description of the build (refer to [`Entrypoint::build_details`])
+    /// is printed to stdout and the process exits.
+    ///
+    /// The flag is hidden from `--help` output.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    /// Override to [`false`] if this conflicts with an application defined `--build-info` arg.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn build_info_flag() -> bool { false }
+    /// }
+    /// ```
+    #[must_use]
+    fn build_info_flag() -> bool {
+        true
+    }
+
+    /// build information of the application (i.e. git commit, build timestamp, rustc version, etc.)
+    ///
+    /// When known, `--version` lists these after the version (`-V` is unchanged),
+    /// the builtin [`--build-info`](EntrypointConfig::build_info_flag) flag prints them,
+    /// and the startup event includes the `build.commit`, `build.timestamp`, and `build.rustc` fields.
+    ///
+    /// Populate these by calling [`emit_build_info`] from the application's build script.
+    /// [`EntrypointDefault`](macros::EntrypointDefault) implements this w/ [`build_info!`].
+    ///
+    /// Defaults to unknown (i.e. [`BuildInfo::default`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn build_info() -> entrypoint::BuildInfo {
+    ///         entrypoint::build_info!()
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    fn build_info() -> BuildInfo {
+        BuildInfo::default()
+    }
+
+    /// whether the builtin `--color={auto,always,never}` arg is added to the command
+    ///
+    /// The parsed value drives the [`LoggerConfig::default_log_ansi`] default.
+    /// The arg is skipped if the application already defines a `color` arg.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn color_flag() -> bool { false }
+    /// }
+    /// ```
+    #[must_use]
+    fn color_flag() -> bool {
+        true
+    }
+
+    /// whether the builtin `--completions <SHELL>` arg is added to the command
+    ///
+    /// When passed, the shell completion script (i.e. `bash`, `elvish`, `fish`, `powershell`, or `zsh`) for the command
+    /// is printed to stdout and the process exits (before any dotenv/logging setup occurs).
+    /// The arg is skipped if the application already defines a `completions` arg.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    /// Override to [`false`] if this conflicts with an application defined `--completions` arg.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn completions_flag() -> bool { false }
+    /// }
+    /// ```
+    #[must_use]
+    fn completions_flag() -> bool {
+        true
+    }
+
+    /// whether the builtin `--doctor` arg is added to the command
+    ///
+    /// When passed, the configuration is checked & reported (refer to [`EntrypointConfig::doctor`]).
+    /// The arg is skipped if the application already defines a `doctor` arg.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn doctor_flag() -> bool { false }
+    /// }
+    /// ```
+    #[must_use]
+    fn doctor_flag() -> bool {
+        true
+    }
+
+    /// whether to check & report the configuration, then exit (instead of running the entrypoint function)
+    ///
+    /// After [`dotenv`](DotEnvParserConfig) processing, [`Entrypoint::entrypoint`] prints a report to stdout of:
+    /// * the dotenv files found, in processing order (& any processing failure)
+    /// * which [required](DotEnvParserConfig::required_env_vars) environment variables are missing
+    /// * whether the [log file](LoggerConfig::default_log_file) (if any) is writable
+    /// * the effective log filter (refer to [`LoggerConfig::default_env_filter`] & [`LoggerConfig::default_log_level`])
+    ///
+    /// The process then exits, w/ failure if any check failed.
+    ///
+    /// Defaults to whether the builtin [`--doctor`](EntrypointConfig::doctor_flag) flag was passed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     #[arg(long, env = "APP_DIAGNOSE")]
+    ///     diagnose: bool,
+    /// }
+    ///
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn doctor(&self) -> bool { self.diagnose }
+    /// }
+    /// ```
+    fn doctor(&self) -> bool {
+        doctor::requested()
+    }
+
+    /// whether the builtin `--dump-env[=exit|continue]` arg is added to the command
+    ///
+    /// When passed, the fully-resolved environment (i.e. after all [`dotenv`](DotEnvParserConfig) processing)
+    /// is printed to stdout w/ sensitive values masked (refer to [`Entrypoint::dump_env`]).
+    /// The process then exits (`--dump-env`, `--dump-env=exit`) or continues (`--dump-env=continue`).
+    ///
+    /// The flag is hidden from `--help` output.
+    /// The arg is skipped if the application already defines a `dump-env` arg.
+    ///
+    /// Default behavior is disabled ([`false`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn dump_env_flag() -> bool { true }
+    /// }
+    /// ```
+    #[must_use]
+    fn dump_env_flag() -> bool {
+        false
+    }
+
+    /// whether the builtin `--daemon` arg is added to the command (w/ the `daemon` [feature](crate#feature-flags))
+    ///
+    /// When passed, the process detaches into the background (refer to [`EntrypointConfig::daemonize`]).
+    /// The arg is skipped if the application already defines a `daemon` arg.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn daemon_flag() -> bool { false }
+    /// }
+    /// ```
+    #[cfg(feature = "daemon")]
+    #[must_use]
+    fn daemon_flag() -> bool {
+        true
+    }
+
+    /// [`clap`] help/error [`Styles`](clap::builder::Styles) to apply to the command
+    ///
+    /// Defaults to an entrypoint theme that matches the default log level colors
+    /// (or [`Styles::plain`](clap::builder::Styles::plain) if [`NO_COLOR`](https://no-color.org) is set).
+    ///
+    /// Override to [`None`] to keep [`clap`]'s styling (including any `#[command(styles = ...)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn cli_styles() -> Option<entrypoint::clap::builder::Styles> {
+    ///         Some(entrypoint::clap::builder::Styles::styled())
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    fn cli_styles() -> Option<clap::builder::Styles> {
+        use clap::builder::styling::{AnsiColor, Styles};
+
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Some(Styles::plain());
+        }
+
+        Some(
+            Styles::styled()
+                .header(AnsiColor::Green.on_default().bold().underline())
+                .usage(AnsiColor::Green.on_default().bold().underline())
+                .literal(AnsiColor::Blue.on_default().bold())
+                .placeholder(AnsiColor::Magenta.on_default())
+                .valid(AnsiColor::Green.on_default())
+                .invalid(AnsiColor::Yellow.on_default().bold())
+                .error(AnsiColor::Red.on_default().bold()),
+        )
+    }
+
+    /// [`clap`] help template to apply to the command
+    ///
+    /// Defaults to [`clap`]'s standard layout, headed by the name, version, and author.
+    /// Refer to [`clap::Command::help_template`] for available tags.
+    ///
+    /// Override to [`None`] to keep [`clap`]'s template (including any `#[command(help_template = ...)]`).
+    #[must_use]
+    fn cli_help_template() -> Option<&'static str> {
+        Some(
+            "\
+{before-help}{name} {version}
+{author-with-newline}{about-with-newline}
+{usage-heading} {usage}
+
+{all-args}{after-help}",
+        )
+    }
+
+    /// where to check for a newer release of this binary
+    ///
+    /// After setup, the latest version is looked up asynchronously in the background (at most once per day,
+    /// whether or not the lookup succeeds; cached in the per-user cache directory, e.g. `~/.cache/<name>/`)
+    /// and a single notice is logged if it's newer than the `#[command(version)]`.
+    /// Skipped when [`LoggerConfig::telemetry_disabled`].
+    ///
+    /// Defaults to [`None`] (i.e. no network access). Override to opt in.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn update_source(&self) -> Option<entrypoint::UpdateSource> {
+    ///         Some(entrypoint::UpdateSource::GitHubReleases(String::from("melloyawn/entrypoint")))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "update-notifier")]
+    fn update_source(&self) -> Option<UpdateSource> {
+        None
+    }
+
+    /// overall deadline for the entrypoint function (e.g. batch jobs run by cron/kubernetes)
+    ///
+    /// When the deadline passes, a timeout error is logged & the process exits w/ code `124` (refer to [`Watchdog`]).
+    /// The deadline covers [`EntrypointConfig::pre_run`], the function (including any retries), & [`EntrypointConfig::post_run`].
+    ///
+    /// This can also be set w/ the [`macros::entrypoint`] attribute macro (i.e. `#[entrypoint::entrypoint(timeout = "30s")]`).
+    ///
+    /// Defaults to none (i.e. no deadline).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn execution_timeout(&self) -> Option<std::time::Duration> {
+    ///         Some(std::time::Duration::from_secs(30 * 60))
+    ///     }
+    /// }
+    /// ```
+    fn execution_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    /// when (and how often) to retry a failed entrypoint function (refer to [`Entrypoint::entrypoint_with_retry`])
+    ///
+    /// Defaults to a single attempt (i.e. no retries).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn retry_policy(&self) -> RetryPolicy {
+    ///         RetryPolicy::new(5)
+    ///             .backoff(std::time::Duration::from_millis(500))
+    ///             .retry_if(|e| e.downcast_ref::<std::io::Error>().is_some())
+    ///     }
+    /// }
+    /// ```
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// hook run (after setup, i.e. w/ logging initialized) immediately before the entrypoint function
+    ///
+    /// Use it for additional startup config/checks (e.g. open a connection pool, verify a dependency is reachable).
+    /// On error, the entrypoint function is skipped & the error is returned (refer to [`EntrypointConfig::post_run`]).
+    ///
+    /// Default behavior is to do nothing.
+    ///
+    /// # Errors
+    /// * application defined; fails startup
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn pre_run(&self) -> entrypoint::anyhow::Result<()> {
+    ///         info!("connecting to the database");
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    fn pre_run(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// hook run immediately after the entrypoint function returns (i.e. teardown)
+    ///
+    /// Receives the function's result (or the [`EntrypointConfig::pre_run`] error).
+    /// The entrypoint function consumes its args, so `self` is a clone of them (taken before the function ran).
+    ///
+    /// Default behavior is to do nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn post_run<T>(&self, result: &entrypoint::anyhow::Result<T>) {
+    ///         info!("closing the database connection (success: {})", result.is_ok());
+    ///     }
+    /// }
+    /// ```
+    fn post_run<T>(&self, result: &anyhow::Result<T>) {
+        let _ = result;
+    }
+
+    /// map an error (returned from the entrypoint function, or setup) to the process exit code
+    ///
+    /// Inspect the error (e.g. [downcast](anyhow::Error::downcast_ref) to the application's error types)
+    /// to choose an exit code, decoupling the error taxonomy from the function's return type.
+    /// The error is still printed/logged as usual (refer to [`Entrypoint::exit`]).
+    ///
+    /// Defaults to [`ExitCode::FAILURE`](std::process::ExitCode::FAILURE) (i.e. `1`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Usage,
+    ///     Partial,
+    /// }
+    /// # impl std::fmt::Display for AppError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{self:?}") }
+    /// # }
+    /// # impl std::error::Error for AppError {}
+    ///
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn exit_code(error: &entrypoint::anyhow::Error) -> std::process::ExitCode {
+    ///         match error.downcast_ref::<AppError>() {
+    ///             Some(AppError::Usage) => std::process::ExitCode::from(2),
+    ///             Some(AppError::Partial) => std::process::ExitCode::from(3),
+    ///             None => std::process::ExitCode::FAILURE,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    fn exit_code(error: &anyhow::Error) -> std::process::ExitCode {
+        let _ = error;
+        std::process::ExitCode::FAILURE
+    }
+
+    /// whether to detach into the background (i.e. run as a unix daemon) before the entrypoint function runs
+    ///
+    /// After [`dotenv`](DotEnvParserConfig) processing & before logging is initialized (i.e. before any threads start),
+    /// the process double-forks & starts a new session (`setsid`); the original process exits w/ success.
+    /// The working directory is kept, stdin is `/dev/null`, & stdout/stderr are appended to the
+    /// [`LoggerConfig::default_log_file`] (or discarded, if none).
+    ///
+    /// Fails startup on non-unix platforms.
+    ///
+    /// Defaults to whether the builtin [`--daemon`](EntrypointConfig::daemon_flag) flag was passed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn daemonize(&self) -> bool {
+    ///         std::env::var_os("MY_APP_FOREGROUND").is_none()
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "daemon")]
+    fn daemonize(&self) -> bool {
+        daemon::requested()
+    }
+
+    /// file the daemon's pid is written to (refer to [`EntrypointConfig::daemonize`])
+    ///
+    /// Startup fails if the file is locked by another (running) instance.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn daemon_pid_file(&self) -> Option<std::path::PathBuf> {
+    ///         Some(std::path::PathBuf::from("/run/my-app.pid"))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "daemon")]
+    fn daemon_pid_file(&self) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// working directory & umask to set before the entrypoint function runs
+    ///
+    /// These are set after [`dotenv`](DotEnvParserConfig) processing (i.e. dotenv files are found relative to the
+    /// original working directory) & before logging is initialized (i.e. a relative [`LoggerConfig::default_log_file`]
+    /// is opened in the new working directory, w/ the new umask).
+    ///
+    /// Defaults to none (i.e. an empty [`WorkdirConfig`]; the working directory & umask are inherited).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// working directory
+    ///     #[arg(long, env = "MY_APP_WORKDIR", default_value = "/var/lib/my-app")]
+    ///     workdir: std::path::PathBuf,
+    /// }
+    ///
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn workdir_config(&self) -> WorkdirConfig {
+    ///         WorkdirConfig::new()
+    ///             .chdir(&self.workdir)
+    ///             .create(true)
+    ///             .umask(0o027)
+    ///     }
+    /// }
+    /// ```
+    fn workdir_config(&self) -> WorkdirConfig {
+        WorkdirConfig::new()
+    }
+
+    /// whether to write a crash report file when the application panics
+    ///
+    /// When enabled, [`Entrypoint::entrypoint`] installs a panic hook (after any [logging](LoggerConfig::default_log_panics) one)
+    /// writing a `report-{name}-{timestamp}-{pid}.toml` file to the [`EntrypointConfig::crash_report_dir`] w/:
+    /// * the app name & version (from the [`clap::Command`])
+    /// * the OS & architecture
+    /// * the panic message, location, thread, and backtrace
+    ///
+    /// A short message is then printed to stderr, telling the user where the report is & where to send it
+    /// (refer to [`EntrypointConfig::crash_report_contact`]).
+    ///
+    /// Defaults to enabled ([`true`]) for release builds (i.e. w/o `debug_assertions`), unless `RUST_BACKTRACE` is set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn crash_report(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "crash-report")]
+    fn crash_report(&self) -> bool {
+        !cfg!(debug_assertions) && std::env::var_os("RUST_BACKTRACE").is_none()
+    }
+
+    /// directory to write [crash reports](EntrypointConfig::crash_report) to
+    ///
+    /// Defaults to [`std::env::temp_dir`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn crash_report_dir(&self) -> std::path::PathBuf {
+    ///         std::path::PathBuf::from("/var/crash")
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "crash-report")]
+    fn crash_report_dir(&self) -> std::path::PathBuf {
+        std::env::temp_dir()
+    }
+
+    /// where users should send [crash reports](EntrypointConfig::crash_report) (e.g. an issue tracker URL or email)
+    ///
+    /// Defaults to the `#[command(author)]`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn crash_report_contact(&self) -> Option<String> {
+    ///         Some(String::from("https://github.com/melloyawn/entrypoint/issues"))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "crash-report")]
+    fn crash_report_contact(&self) -> Option<String> {
+        <Self as clap::CommandFactory>::command()
+            .get_author()
+            .map(ToOwned::to_owned)
+    }
+
+    /// whether to shut down gracefully on `SIGINT` (i.e. ctrl-c) & `SIGTERM`
+    ///
+    /// When enabled, [`Entrypoint::entrypoint`] listens for either signal while the entrypoint function runs.
+    /// On the first one received, the reason is logged & [`EntrypointConfig::on_shutdown`] is run.
+    /// The entrypoint function then has up to [`EntrypointConfig::shutdown_timeout`] to return
+    /// (poll [`shutdown_requested`] to notice the request).
+    /// Otherwise (or on a second signal), output is flushed & the process exits w/ the [`ShutdownReason::exit_code`].
+    ///
+    /// On windows, only ctrl-c is handled (i.e. there's no `SIGTERM`); this has no effect on other non-unix platforms.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn shutdown_signals(&self) -> bool { false }
+    /// }
+    /// ```
+    #[cfg(feature = "shutdown")]
+    fn shutdown_signals(&self) -> bool {
+        true
+    }
+
+    /// hook run when a shutdown is requested (refer to [`EntrypointConfig::shutdown_signals`])
+    ///
+    /// This runs on a shutdown thread, against a clone of the args (taken before the entrypoint function runs).
+    /// Use it to notify/stop the application (e.g. close a channel, cancel a token, etc.).
+    ///
+    /// Default behavior is to do nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// static STOP: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    ///
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn on_shutdown(&self) {
+    ///         STOP.store(true, std::sync::atomic::Ordering::SeqCst);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "shutdown")]
+    fn on_shutdown(&self) {}
+
+    /// how long the entrypoint function has to return once a shutdown is requested
+    ///
+    /// Refer to [`EntrypointConfig::shutdown_signals`].
+    ///
+    /// Defaults to 10 seconds.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn shutdown_timeout(&self) -> std::time::Duration {
+    ///         std::time::Duration::from_secs(30)
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "shutdown")]
+    fn shutdown_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(10)
+    }
+
+    /// signals to handle while the entrypoint function runs, & what to do on each
+    ///
+    /// The mapping is installed by [`Entrypoint::entrypoint`] after setup.
+    /// It's in addition to [`LoggerConfig::default_log_signals`] & [`EntrypointConfig::shutdown_signals`] (w/ the `shutdown`
+    /// [feature](crate#feature-flags)), so a signal may run several actions.
+    ///
+    /// Defaults to none (i.e. an empty [`SignalConfig`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn signal_config(&self) -> SignalConfig {
+    ///         SignalConfig::new()
+    ///             .on(Signal::Hangup, SignalAction::ReloadConfig)
+    ///             .on(Signal::User1, SignalAction::ReopenLogs)
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "signals")]
+    fn signal_config(&self) -> SignalConfig {
+        SignalConfig::new()
+    }
+
+    /// name to run as when started by the Windows Service Control Manager, if any ([`None`] to always run as a console app)
+    ///
+    /// After setup, [`Entrypoint::entrypoint`] connects to the Service Control Manager (in a new thread).
+    /// When started as a service, `RUNNING` is reported before the entrypoint function runs, & `STOPPED` once it returns.
+    /// Stop/shutdown requests trigger a graceful shutdown (i.e. [`ShutdownReason::ServiceStop`]; refer to [`EntrypointConfig::shutdown_signals`]),
+    /// and pause/continue requests toggle [`service_paused`].
+    /// Otherwise (e.g. run from a console), the entrypoint function runs as usual; so one binary is both.
+    ///
+    /// Services have no console & start in the system directory;
+    /// consider a [log file](LoggerConfig::default_log_file) & [working directory](EntrypointConfig::workdir_config).
+    ///
+    /// Has no effect on non-windows platforms.
+    ///
+    /// Defaults to the [`clap::Command`] name.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::EntrypointConfig for Args {
+    ///     fn windows_service_name(&self) -> Option<String> {
+    ///         Some(String::from("MyService"))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "windows-service")]
+    fn windows_service_name(&self) -> Option<String> {
+        Some(
+            <Self as clap::CommandFactory>::command()
+                .get_name()
+                .to_owned(),
+        )
+    }
+}
+
+/// automatic [`tracing`] & [`tracing_subscriber`] configuration
+///
+/// Available configuration for the [`Logger`] trait.
+///
+/// Default implementations are what you'd expect.
+/// Use this [derive macro](macros::LoggerDefault) for typical use cases.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, Clone)]
+/// #[derive(LoggerDefault)]
+/// #[log_format(full)]
+/// #[log_level(entrypoint::LevelFilter::DEBUG)]
+/// #[log_writer(std::io::stdout)]
+/// struct Args {}
+///
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> anyhow::Result<()> {
+///     // logs are ready to use
+///     info!("hello!");
+/// #   Ok(())
+/// }
+/// ```
+/// For advanced customization requirements, refer to [`LoggerConfig::bypass_log_init`].
+pub trait LoggerConfig: clap::Parser {
+    /// hook to disable/enable automatic initialization
+    ///
+    /// This disrupts automatic initialization so that completely custom [`Layer`]s can be provided to [`Logger::log_init`].
+    /// This is intended only for advanced use cases, such as:
+    /// 1. the default [`Layer`] isn't wanted at all (additional layers can be registered via [`LoggerConfig::default_log_layers`])
+    /// 2. a [reload handle](tracing_subscriber::reload::Handle) for a custom [`Layer`] needs to be kept accessible
+    ///    (the default layer's filter can already be changed at runtime via [`logger_handle`])
+    ///
+    /// Default behvaior ([`false`]) is to call [`Logger::log_init`] on startup and
+    /// register the default layers provided by [`LoggerConfig::default_log_layers`].
+    ///
+    /// Overriding this to [`true`] will **not** automatically call [`Logger::log_init`] on startup.
+    /// All other defaults provided by the [`LoggerConfig`] trait methods are ignored.
+    /// The application is then **required** to directly call [`Logger::log_init`] with explicitly provided layer(s).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, Clone)]
+    /// struct Args {}
+    ///
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn bypass_log_init(&self) -> bool { true }
+    /// }
+    ///
+    /// #[entrypoint::entrypoint]
+    /// fn main(args: Args) -> anyhow::Result<()> {
+    ///     // logging hasn't been configured yet
+    ///     assert!(!enabled!(entrypoint::Level::ERROR));
+    ///
+    ///     // must manually config/init logging
+    ///     let (layer, reload_handle) = reload::Layer::new(
+    ///         tracing_subscriber::fmt::Layer::default()
+    ///             .event_format(args.default_log_format())
+    ///             .with_writer(args.default_log_writer())
+    ///             .with_filter(args.default_log_level()),
+    ///     );
+    ///     let args = args.log_init(Some(vec![layer.boxed()]))?;
+    ///
+    ///     // OK... now logging should work
+    ///     assert!( enabled!(entrypoint::Level::ERROR));
+    ///     assert!(!enabled!(entrypoint::Level::TRACE));
+    ///
+    ///     // we've maintained direct access to the layer and reload handle
+    ///     let _ = reload_handle.modify(|layer| *layer.filter_mut() = entrypoint::LevelFilter::TRACE);
+    ///     assert!( enabled!(entrypoint::Level::TRACE));
+    /// #   Ok(())
+    /// }
+    /// ```
+    fn bypass_log_init(&self) -> bool {
+        false
+    }
+
+    /// define the default [`tracing_subscriber`] [`LevelFilter`]
+    ///
+    /// Defaults to the [`LoggerConfig::default_log_level_env`] value (if set & valid),
+    /// otherwise [`DEFAULT_MAX_LEVEL`](tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL);
+    /// then raised/lowered per any parsed [`Verbosity`] flags.
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// allow user to pass in debug level
+    ///     #[arg(long)]
+    ///     default_log_level: LevelFilter,
+    /// }
+    ///
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_level(&self) -> LevelFilter {
+    ///         self.default_log_level.clone()
+    ///     }
+    /// }
+    /// ```
+    fn default_log_level(&self) -> LevelFilter {
+        Verbosity::parsed_log_level(
+            self.default_log_level_env()
+                .and_then(|var| std::env::var(var).ok())
+                .and_then(|level| level.parse().ok())
+                .unwrap_or(tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL),
+        )
+    }
+
+    /// define the env var consulted by [`LoggerConfig::default_log_level`]
+    ///
+    /// The var is read after [`dotenv`](DotEnvParserConfig) processing
+    /// and takes precedence over the default level. Invalid values are ignored.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_level_env(&self) -> Option<String> {
+    ///         Some(String::from("APP_LOG_LEVEL"))
+    ///     }
+    /// }
+    /// ```
+    fn default_log_level_env(&self) -> Option<String> {
+        None
+    }
+
+    /// define the default [`tracing_subscriber`] [`EnvFilter`]
+    ///
+    /// When defined, this is used instead of the [`LoggerConfig::default_log_level`]
+    /// (e.g. to support per-target directives like `info,hyper=warn`).
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes),
+    /// which builds the filter from `RUST_LOG` (after [`dotenv`](DotEnvParserConfig) processing)
+    /// w/ [`LoggerConfig::default_log_level`] as the default directive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_env_filter(&self) -> Option<EnvFilter> {
+    ///         Some(EnvFilter::new(format!(
+    ///             "{},{}",
+    ///             self.default_log_level(),
+    ///             std::env::var("RUST_LOG").unwrap_or_default()
+    ///         )))
+    ///     }
+    /// }
+    /// ```
+    fn default_env_filter(&self) -> Option<EnvFilter> {
+        None
+    }
+
+    /// define the default [`tracing_subscriber`] [`Format`]
+    ///
+    /// Defaults to [`Format::default`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes),
+    /// including a user formatter function (i.e. `#[log_format(custom = path::to::formatter)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_format<S,N>(&self) -> impl FormatEvent<S,N> + Send + Sync + 'static
+    ///     where
+    ///         S: Subscriber + for<'a> LookupSpan<'a>,
+    ///         N: for<'writer> FormatFields<'writer> + 'static,
+    ///     {
+    ///         self.default_log_format_base().pretty()
+    ///     }
+    /// }
+    /// ```
+    fn default_log_format<S, N>(&self) -> impl FormatEvent<S, N> + Send + Sync + 'static
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        N: for<'writer> FormatFields<'writer> + 'static,
+    {
+        self.default_log_format_base()
+    }
+
+    /// define the base [`Format`] that [`LoggerConfig::default_log_format`] builds upon
+    ///
+    /// Applies the [`LoggerConfig::default_log_timer`] (in the [`LoggerConfig::default_log_time_zone`]) and the `default_log_with_*` field toggles.
+    ///
+    /// **You ***probably*** don't want to override this default implementation.**
+    /// Override [`LoggerConfig::default_log_format`] to select a style (e.g. `self.default_log_format_base().pretty()`).
+    fn default_log_format_base(&self) -> Format<Full, ZonedTimer> {
+        let timer = self
+            .default_log_timer()
+            .with_time_zone(self.default_log_time_zone());
+
+        let format = match timer.timer {
+            Timer::None => Format::default().without_time().with_timer(timer),
+            _ => Format::default().with_timer(timer),
+        };
+
+        format
+            .with_target(self.default_log_with_target())
+            .with_file(self.default_log_with_file())
+            .with_line_number(self.default_log_with_line_number())
+            .with_thread_ids(self.default_log_with_thread_ids())
+            .with_thread_names(self.default_log_with_thread_names())
+    }
+
+    /// define the JSON [`Format`] that [`LoggerConfig::default_log_format`] can select
+    ///
+    /// Builds upon [`LoggerConfig::default_log_format_base`] and applies the `default_log_json_*` options.
+    ///
+    /// **You ***probably*** don't want to override this default implementation.**
+    /// Override [`LoggerConfig::default_log_format`] to select it (i.e. `self.default_log_format_json()`),
+    /// along w/ [`LoggerConfig::default_log_json`].
+    fn default_log_format_json<S, N>(&self) -> impl FormatEvent<S, N> + Send + Sync + 'static
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        N: for<'writer> FormatFields<'writer> + 'static,
+    {
+        json::Renamed::new(
+            self.default_log_format_base()
+                .json()
+                .flatten_event(self.default_log_json_flatten_event())
+                .with_current_span(self.default_log_json_with_current_span())
+                .with_span_list(self.default_log_json_with_span_list()),
+            self.default_log_json_rename(),
+        )
+    }
+
+    /// define the [logfmt](https://brandur.org/logfmt) format that [`LoggerConfig::default_log_format`] can select
+    ///
+    /// Writes one `key=value` line per event (e.g. `ts=... level=info target=app msg="hello world"`),
+    /// honoring the [`LoggerConfig::default_log_timer`] and the `default_log_with_*` field toggles.
+    ///
+    /// **You ***probably*** don't want to override this default implementation.**
+    /// Override [`LoggerConfig::default_log_format`] to select it (i.e. `self.default_log_format_logfmt()`).
+    fn default_log_format_logfmt<S, N>(&self) -> impl FormatEvent<S, N> + Send + Sync + 'static
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        N: for<'writer> FormatFields<'writer> + 'static,
+    {
+        logfmt::Logfmt {
+            timer: self
+                .default_log_timer()
+                .with_time_zone(self.default_log_time_zone()),
+            with_target: self.default_log_with_target(),
+            with_file: self.default_log_with_file(),
+            with_line_number: self.default_log_with_line_number(),
+            with_thread_ids: self.default_log_with_thread_ids(),
+            with_thread_names: self.default_log_with_thread_names(),
+        }
+    }
+
+    /// whether [`LoggerConfig::default_log_format`] is JSON (i.e. span fields are recorded as JSON)
+    ///
+    /// The JSON format requires span fields to be recorded as JSON objects;
+    /// enable this whenever [`LoggerConfig::default_log_format`] is overridden to a JSON format.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This is automatically set by the convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_format(json)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_format<S,N>(&self) -> impl FormatEvent<S,N> + Send + Sync + 'static
+    ///     where
+    ///         S: Subscriber + for<'a> LookupSpan<'a>,
+    ///         N: for<'writer> FormatFields<'writer> + 'static,
+    ///     {
+    ///         self.default_log_format_json()
+    ///     }
+    ///
+    ///     fn default_log_json(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_json(&self) -> bool {
+        false
+    }
+
+    /// whether to render events as an indented tree of nested spans (via [`tracing_tree`](https://docs.rs/tracing-tree)) instead of the [`LoggerConfig::default_log_format`]
+    ///
+    /// Intended for development; span nesting is hard to follow in the flat formats.
+    /// Honors the `default_log_with_*` target/thread toggles, but not the [`LoggerConfig::default_log_timer`].
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_format(tree)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_tree(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "tree")]
+    fn default_log_tree(&self) -> bool {
+        false
+    }
+
+    /// whether to write [bunyan](https://github.com/trentm/node-bunyan) JSON (via [`tracing_bunyan_formatter`](https://docs.rs/tracing-bunyan-formatter)) instead of the [`LoggerConfig::default_log_format`]
+    ///
+    /// Events carry the fields of their enclosing spans; span start/end are logged as events too.
+    /// The bunyan `name` is the [`clap::Command`] name.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_format(bunyan)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_bunyan(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "bunyan")]
+    fn default_log_bunyan(&self) -> bool {
+        false
+    }
+
+    /// whether JSON event fields are flattened into the top-level object (i.e. no `fields` object)
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_json(flatten_event)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_json_flatten_event(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_json_flatten_event(&self) -> bool {
+        false
+    }
+
+    /// whether JSON events include the current span (i.e. a `span` object)
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_json(with_current_span = false)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_json_with_current_span(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_json_with_current_span(&self) -> bool {
+        true
+    }
+
+    /// whether JSON events include all entered spans (i.e. a `spans` list)
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_json(with_span_list = false)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_json_with_span_list(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_json_with_span_list(&self) -> bool {
+        true
+    }
+
+    /// `(from, to)` JSON keys to rename (e.g. `message` -> `msg`)
+    ///
+    /// Keys are renamed in the event object and its `fields` object (unless [flattened](LoggerConfig::default_log_json_flatten_event)).
+    ///
+    /// Defaults to none.
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_json(rename(message = "msg"))]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_json_rename(&self) -> Vec<(String, String)> {
+    ///         vec![(String::from("level"), String::from("severity"))]
+    ///     }
+    /// }
+    /// ```
+    fn default_log_json_rename(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// define the default log event [`Timer`] (timestamp format)
+    ///
+    /// Defaults to [`Timer::Rfc3339`].
+    /// Use [`Timer::None`] where the log collector adds its own timestamps (e.g. systemd, docker).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_timer(&self) -> entrypoint::Timer {
+    ///         entrypoint::Timer::Custom(String::from("%Y-%m-%d %H:%M:%S"))
+    ///     }
+    /// }
+    /// ```
+    fn default_log_timer(&self) -> Timer {
+        Timer::Rfc3339
+    }
+
+    /// define the default log event [`TimeZone`] (applies to [`LoggerConfig::default_log_timer`])
+    ///
+    /// Defaults to [`TimeZone::Utc`].
+    /// [`TimeZone::Local`] suits interactive use; fleet logs should usually stay in UTC.
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_time_zone(&self) -> entrypoint::TimeZone {
+    ///         entrypoint::TimeZone::Local
+    ///     }
+    /// }
+    /// ```
+    fn default_log_time_zone(&self) -> TimeZone {
+        TimeZone::Utc
+    }
+
+    /// whether the event target (i.e. module path) is logged
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_with(target = false)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_with_target(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_with_target(&self) -> bool {
+        true
+    }
+
+    /// whether the event source file is logged
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_with(file)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_with_file(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_with_file(&self) -> bool {
+        false
+    }
+
+    /// whether the event source line number is logged
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_with(line_number)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_with_line_number(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_with_line_number(&self) -> bool {
+        false
+    }
+
+    /// whether the emitting thread's id is logged
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_with(thread_ids)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_with_thread_ids(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_with_thread_ids(&self) -> bool {
+        false
+    }
+
+    /// whether the emitting thread's name is logged
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_with(thread_names)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_with_thread_names(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_with_thread_names(&self) -> bool {
+        false
+    }
+
+    /// define which span lifecycle events (i.e. [`FmtSpan`]) are logged
+    ///
+    /// [`FmtSpan::CLOSE`] events include the span's busy/idle timing.
+    ///
+    /// Defaults to [`FmtSpan::NONE`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_span_events(&self) -> FmtSpan {
+    ///         FmtSpan::NEW | FmtSpan::CLOSE
+    ///     }
+    /// }
+    /// ```
+    fn default_log_span_events(&self) -> FmtSpan {
+        FmtSpan::NONE
+    }
+
+    /// define the default [`tracing_subscriber`] [`MakeWriter`]
+    ///
+    /// Defaults to [`std::io::stdout`].
+    /// For web builds, use the `ConsoleWriter` (w/ the `wasm` [feature](crate#feature-flags)).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+    ///         std::io::stderr
+    ///     }
+    /// }
+    /// ```
+    fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+        std::io::stdout
+    }
+
+    /// define the default log file
+    ///
+    /// When defined, logs are written to this file (via [`tracing_appender::rolling`]) instead of
+    /// the [`LoggerConfig::default_log_writer`] (or in addition to it, refer to [`LoggerConfig::default_log_tee`]).
+    /// ANSI escape codes are disabled for file output.
+    ///
+    /// A leading `~` is expanded to the user's home directory and missing parent directories are created.
+    /// [`Logger::log_init`] fails if the file can't be opened.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// allow user to pass in a log file
+    ///     #[arg(long)]
+    ///     log_file: Option<std::path::PathBuf>,
+    /// }
+    ///
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_file(&self) -> Option<std::path::PathBuf> {
+    ///         self.log_file.clone()
+    ///     }
+    /// }
+    /// ```
+    fn default_log_file(&self) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// level of the log file layer, replacing [`LoggerConfig::default_log_level`] for that layer
+    ///
+    /// e.g. write `DEBUG` to the file, but only `INFO` to the [`LoggerConfig::default_log_writer`] (refer to [`LoggerConfig::default_log_writer_level`]).
+    /// Only the default level directive is replaced; any target specific [`LoggerConfig::default_env_filter`] directives still apply.
+    ///
+    /// Defaults to [`None`] (i.e. [`LoggerConfig::default_log_level`]).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_file_level(&self) -> Option<entrypoint::LevelFilter> {
+    ///         Some(entrypoint::LevelFilter::DEBUG)
+    ///     }
+    /// }
+    /// ```
+    fn default_log_file_level(&self) -> Option<LevelFilter> {
+        None
+    }
+
+    /// level of the log writer layer, replacing [`LoggerConfig::default_log_level`] for that layer
+    ///
+    /// Also applies to the `journald`/`syslog`/`eventlog` layers, which replace the log writer.
+    /// Refer to [`LoggerConfig::default_log_file_level`].
+    ///
+    /// Defaults to [`None`] (i.e. [`LoggerConfig::default_log_level`]).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_writer_level(&self) -> Option<entrypoint::LevelFilter> {
+    ///         Some(entrypoint::LevelFilter::WARN)
+    ///     }
+    /// }
+    /// ```
+    fn default_log_writer_level(&self) -> Option<LevelFilter> {
+        None
+    }
+
+    /// define the default log file [`Rotation`] policy
+    ///
+    /// Only applies when a [`LoggerConfig::default_log_file`] is defined.
+    ///
+    /// Defaults to [`Rotation::Never`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_file(&self) -> Option<std::path::PathBuf> {
+    ///         Some(std::path::PathBuf::from("logs/app.log"))
+    ///     }
+    ///
+    ///     fn default_log_rotation(&self) -> entrypoint::Rotation {
+    ///         entrypoint::Rotation::Size(10 * 1024 * 1024)
+    ///     }
+    /// }
+    /// ```
+    fn default_log_rotation(&self) -> Rotation {
+        Rotation::Never
+    }
+
+    /// whether to write to both the [`LoggerConfig::default_log_writer`] and [`LoggerConfig::default_log_file`]
+    ///
+    /// When enabled (and a log file is defined), the same events are emitted by two separate layers.
+    ///
+    /// Defaults to [`false`] (i.e. the log file replaces the log writer).
+    ///
+    /// The convenience [`macros`](macros::LoggerDefault#attributes) enable this when both
+    /// `#[log_writer]` and `#[log_file]` are supplied.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_file(&self) -> Option<std::path::PathBuf> {
+    ///         Some(std::path::PathBuf::from("logs/app.log"))
+    ///     }
+    ///
+    ///     fn default_log_tee(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_tee(&self) -> bool {
+        false
+    }
+
+    /// whether ANSI colors are used by the [`LoggerConfig::default_log_writer`]
+    ///
+    /// Colors are never used for the [`LoggerConfig::default_log_file`].
+    ///
+    /// Defaults to the builtin [`--color`](EntrypointConfig::color_flag) arg. In `auto` mode (the default),
+    /// colors are used only if stdout is a terminal and [`NO_COLOR`](https://no-color.org) isn't set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_ansi(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_ansi(&self) -> bool {
+        color::ansi()
+    }
+
+    /// whether the default log writer is [non-blocking](tracing_appender::non_blocking)
+    ///
+    /// When enabled, log lines are written by a dedicated worker thread.
+    /// [`Entrypoint::entrypoint`] flushes any buffered lines after the entrypoint function returns.
+    /// The worker hands the writer each line's level (e.g. for [`SplitWriter`]), but no other event metadata.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_non_blocking(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_non_blocking(&self) -> bool {
+        false
+    }
+
+    /// max events per second, per callsite, written by the default log writer(s)
+    ///
+    /// Excess events (e.g. from tight loops) are dropped; a `WARN` summary of the number of
+    /// suppressed events per callsite is logged periodically (i.e. once per second) instead.
+    /// Only events enabled by the log level/filter count toward the limit.
+    ///
+    /// Defaults to [`None`] (i.e. unlimited).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_rate_limit(&self) -> Option<u32> { Some(100) }
+    /// }
+    /// ```
+    fn default_log_rate_limit(&self) -> Option<u32> {
+        None
+    }
+
+    /// number of recent events to keep in memory, regardless of the log level (i.e. at `TRACE` detail)
+    ///
+    /// Buffered events are dumped to the [`LoggerConfig::default_log_writer`] when an `ERROR` event occurs,
+    /// or the entrypoint function returns an error. This gives post-mortem detail w/o logging at `TRACE` all the time.
+    /// Dumps include events the log writer already wrote. Every event is formatted, so this has a runtime cost.
+    ///
+    /// Defaults to [`None`] (i.e. no ring buffer).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_ring_buffer(&self) -> Option<usize> { Some(1000) }
+    /// }
+    /// ```
+    fn default_log_ring_buffer(&self) -> Option<usize> {
+        None
+    }
+
+    /// whether to log panics through the subscriber (i.e. as `ERROR` events)
+    ///
+    /// When enabled, [`Logger::log_init`] installs a panic hook logging the message, location, thread, and
+    /// (always captured) backtrace as an `ERROR` event w/ the `panic` target, before unwinding.
+    /// This replaces the default hook, so panics don't bypass the configured format/writers (e.g. JSON) as raw stderr text.
+    /// The replaced hook still runs if no subscriber has been set.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_panics(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_panics(&self) -> bool {
+        true
+    }
+
+    /// whether to attach application metadata to every event
+    ///
+    /// When enabled, [`Entrypoint::entrypoint`] runs the entrypoint function within a root `app` span
+    /// (at `ERROR` level, so it's always enabled) w/ these fields:
+    /// * `app.name` and `app.version` from the [`clap::Command`]
+    /// * `pid` (i.e. [`std::process::id`])
+    /// * `hostname` from `HOSTNAME`/`COMPUTERNAME` or `/etc/hostname` (if known)
+    ///
+    /// Events from other threads/tasks aren't in the span unless it's propagated (e.g. via [`tracing::Instrument`]).
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_app_metadata(&self) -> bool { true }
+    /// }
+    /// ```
+    fn default_log_app_metadata(&self) -> bool {
+        false
+    }
+
+    /// whether to register a [`tracing_error::ErrorLayer`] (i.e. enable [`SpanTrace`] capture)
+    ///
+    /// Span traces are captured by [`SpanTraceExt::with_span_trace`];
+    /// [`Entrypoint::entrypoint`] logs the span trace of a returned error.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_span_trace(&self) -> bool { false }
+    /// }
+    /// ```
+    fn default_log_span_trace(&self) -> bool {
+        true
+    }
+
+    /// env var key patterns whose values are redacted from formatted log output
+    ///
+    /// Patterns are case-insensitive & support `*` wildcards (e.g. `*_SECRET`, `*_TOKEN`, `PASSWORD`).
+    /// Matching values (e.g. loaded via [`dotenv`](DotEnvParserConfig)) are replaced w/ `[REDACTED]`
+    /// in the [`LoggerConfig::default_log_writer`] & [`LoggerConfig::default_log_file`] output.
+    /// Values are captured when the layer is built; values shorter than 4 characters are ignored.
+    ///
+    /// Defaults to none (i.e. no redaction).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_redact(&self) -> Vec<String> {
+    ///         vec![String::from("*_SECRET"), String::from("*_TOKEN")]
+    ///     }
+    /// }
+    /// ```
+    fn default_log_redact(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// whether to write to the systemd journal (via [`tracing_journald`]) instead of the [`LoggerConfig::default_log_writer`]
+    ///
+    /// Events are emitted as native structured journal entries.
+    /// Falls back to the [`LoggerConfig::default_log_writer`] if the journal is unavailable.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_writer(journald)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_journald(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "journald")]
+    fn default_log_journald(&self) -> bool {
+        false
+    }
+
+    /// define the default [`Syslog`] target to write to (RFC 5424) instead of the [`LoggerConfig::default_log_writer`]
+    ///
+    /// The facility (name or code, defaults to `user`) and app-name (defaults to the [`clap::Command`] name)
+    /// can be set with the `SYSLOG_FACILITY` and `SYSLOG_APP_NAME` env vars, after [`dotenv`](DotEnvParserConfig) processing.
+    /// Falls back to the [`LoggerConfig::default_log_writer`] if the target is unavailable.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes) (i.e. `#[log_writer(syslog)]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_syslog(&self) -> Option<entrypoint::Syslog> {
+    ///         Some(entrypoint::Syslog::Udp(String::from("logs.example.com:514")))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "syslog")]
+    fn default_log_syslog(&self) -> Option<Syslog> {
+        None
+    }
+
+    /// define the Windows Event Log source name to write to instead of the [`LoggerConfig::default_log_writer`]
+    ///
+    /// Intended for services installed via the Service Control Manager.
+    /// Ignored on non-Windows targets. Falls back to the [`LoggerConfig::default_log_writer`] if the
+    /// event source can't be registered.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes)
+    /// (i.e. `#[log_writer(eventlog)]`, which uses the [`clap::Command`] name).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_eventlog(&self) -> Option<String> {
+    ///         Some(String::from("MyService"))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "eventlog")]
+    fn default_log_eventlog(&self) -> Option<String> {
+        None
+    }
+
+    /// define the file to record [`tracing_flame`] folded stack samples to (i.e. profiling mode)
+    ///
+    /// When defined, span timings are recorded regardless of the log level.
+    /// [`Entrypoint::entrypoint`] flushes the file after the entrypoint function returns.
+    /// Render it w/ [`inferno`](https://crates.io/crates/inferno) (e.g. `inferno-flamegraph < tracing.folded > flamegraph.svg`).
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// record a flamegraph profile
+    ///     #[arg(long)]
+    ///     profile: Option<std::path::PathBuf>,
+    /// }
+    ///
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_flame(&self) -> Option<std::path::PathBuf> {
+    ///         self.profile.clone()
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "flame")]
+    fn default_log_flame(&self) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// whether to adjust the log level at runtime via unix signals
+    ///
+    /// When enabled, [`Logger::log_init`] maps (refer to [`SignalConfig`]):
+    /// * `SIGUSR1` to raise the level one step (e.g. `INFO` -> `DEBUG`)
+    /// * `SIGUSR2` to lower the level one step (e.g. `INFO` -> `WARN`)
+    /// * `SIGHUP` to restore the [`LoggerConfig::default_env_filter`] (or [`LoggerConfig::default_log_level`])
+    ///
+    /// Raising/lowering replaces any per-target directives w/ a single level.
+    /// Changes are applied via [`logger_handle`]; this has no effect on non-unix platforms.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::LoggerDefault#attributes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_signals(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "signals")]
+    fn default_log_signals(&self) -> bool {
+        false
     }
 
     /// define the default [`tracing_subscriber`] [`Layer`] to register
     ///
-    /// This method uses the defaults defined by [`LoggerConfig`] methods and composes a default [`Layer`] to register.
+    /// This method uses the defaults defined by [`LoggerConfig`] methods and composes a default [`Layer`] to register.
+    ///
+    /// With the `otel` [feature](crate#feature-flags), an OpenTelemetry (OTLP/HTTP) export layer is also composed
+    /// when `OTEL_EXPORTER_OTLP_ENDPOINT` (or `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT`) is set and
+    /// [`LoggerConfig::telemetry_disabled`] is false. The exporter honors the standard `OTEL_*` env vars.
+    /// The resource is read from `OTEL_SERVICE_NAME` and `OTEL_RESOURCE_ATTRIBUTES` (after dotenv processing),
+    /// falling back to the [`clap::Command`] name & version for `service.name` & `service.version`.
+    /// [`Entrypoint::entrypoint`] shuts the tracer provider down after the entrypoint function returns.
+    ///
+    /// With the `gelf` [feature](crate#feature-flags), a [GELF](https://go2docs.graylog.org/current/getting_in_log_data/gelf.html)
+    /// (Graylog) UDP layer is also composed when `GELF_HOST` (and optionally `GELF_PORT`; defaults to `12201`) is set and
+    /// [`LoggerConfig::telemetry_disabled`] is false.
+    ///
+    /// With the `sentry` [feature](crate#feature-flags), a [`sentry`](https://crates.io/crates/sentry-tracing) layer is
+    /// also composed when `SENTRY_DSN` is set and [`LoggerConfig::telemetry_disabled`] is false.
+    /// [`Entrypoint::entrypoint`] reports any returned error, then flushes the client.
+    ///
+    /// **You ***probably*** don't want to override this default implementation.**
+    /// 1. For standard customization, override these other trait methods:
+    ///    * [`LoggerConfig::default_log_level`]
+    ///    * [`LoggerConfig::default_env_filter`]
+    ///    * [`LoggerConfig::default_log_format`]
+    ///    * [`LoggerConfig::default_log_timer`]
+    ///    * [`LoggerConfig::default_log_time_zone`]
+    ///    * [`LoggerConfig::default_log_span_events`]
+    ///    * `LoggerConfig::default_log_with_*` (e.g. [`LoggerConfig::default_log_with_line_number`])
+    ///    * [`LoggerConfig::default_log_writer`]
+    ///    * [`LoggerConfig::default_log_file`]
+    ///    * [`LoggerConfig::default_log_writer_level`]
+    ///    * [`LoggerConfig::default_log_file_level`]
+    ///    * [`LoggerConfig::default_log_rotation`]
+    ///    * [`LoggerConfig::default_log_tee`]
+    ///    * [`LoggerConfig::default_log_ansi`]
+    ///    * [`LoggerConfig::default_log_non_blocking`]
+    ///    * [`LoggerConfig::default_log_rate_limit`]
+    ///    * [`LoggerConfig::default_log_ring_buffer`]
+    ///    * [`LoggerConfig::default_log_redact`]
+    ///    * [`LoggerConfig::default_log_span_trace`]
+    ///    * `LoggerConfig::default_log_journald` (w/ the `journald` [feature](crate#feature-flags))
+    ///    * `LoggerConfig::default_log_syslog` (w/ the `syslog` [feature](crate#feature-flags))
+    ///    * `LoggerConfig::default_log_eventlog` (w/ the `eventlog` [feature](crate#feature-flags))
+    ///    * `LoggerConfig::default_log_flame` (w/ the `flame` [feature](crate#feature-flags))
+    /// 2. Additional layers (e.g. metrics, custom exporters) can be registered alongside this one
+    ///    by overriding [`LoggerConfig::default_log_layers`].
+    /// 3. Minor/static customization(s) ***can*** be achieved by overriding this method...
+    ///    though this might warrant moving to the 'advanced requirements' option below.
+    /// 4. Otherwise, for advanced requirements, refer to [`LoggerConfig::bypass_log_init`].
+    ///
+    /// # Panics
+    /// * the [`LoggerConfig::default_log_file`] can't be opened
+    /// * the `LoggerConfig::default_log_flame` file can't be created
+    fn default_log_layer(
+        &self,
+    ) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static> {
+        let file = self.default_log_file().map(|file| {
+            writer::take_log_file(&file, self.default_log_rotation())
+                .unwrap_or_else(|e| panic!("{e:#}"))
+        });
+
+        let console = (file.is_none() || self.default_log_tee()).then(|| {
+            tracing_subscriber::fmt::writer::BoxMakeWriter::new(self.default_log_writer())
+        });
+
+        let mut layers = Vec::new();
+
+        // span fields for every bunyan layer (i.e. console & file); must precede them
+        #[cfg(feature = "bunyan")]
+        if self.default_log_bunyan() {
+            layers.push(tracing_bunyan_formatter::JsonStorageLayer.boxed());
+        }
+
+        if let Some(console) = console {
+            layers.push(
+                journald_layer(self)
+                    .or_else(|| syslog_layer(self))
+                    .or_else(|| eventlog_layer(self))
+                    .unwrap_or_else(|| {
+                        fmt_layer(
+                            self,
+                            console,
+                            self.default_log_ansi(),
+                            self.default_log_writer_level(),
+                        )
+                    }),
+            );
+        }
+        if let Some(file) = file {
+            layers.push(fmt_layer(self, file, false, self.default_log_file_level()));
+        }
+
+        if let Some(capacity) = self.default_log_ring_buffer() {
+            layers.push(ring_buffer_layer(self, capacity));
+        }
+
+        if self.default_log_span_trace() {
+            layers.push(filtered(self, tracing_error::ErrorLayer::default()));
+        }
+
+        #[cfg(feature = "flame")]
+        if let Some(file) = self.default_log_flame() {
+            layers.push(flame::layer(&file).boxed());
+        }
+
+        #[cfg(feature = "otel")]
+        if !self.telemetry_disabled() {
+            if let Some(layer) = otel::layer(&<Self as clap::CommandFactory>::command()) {
+                layers.push(filtered(self, layer));
+            }
+        }
+
+        #[cfg(feature = "gelf")]
+        if !self.telemetry_disabled() {
+            if let Some(layer) = gelf::layer(<Self as clap::CommandFactory>::command().get_name()) {
+                layers.push(filtered(self, layer));
+            }
+        }
+
+        #[cfg(feature = "sentry")]
+        if !self.telemetry_disabled() {
+            let command = <Self as clap::CommandFactory>::command();
+            let release = command
+                .get_version()
+                .map(|version| format!("{}@{version}", command.get_name()));
+
+            if let Some(layer) = sentry::layer(release) {
+                layers.push(filtered(self, layer));
+            }
+        }
+
+        if layers.len() == 1 {
+            layers.remove(0)
+        } else {
+            layers.boxed()
+        }
+    }
+
+    /// the layers registered by [`Logger::log_init`] (unless [bypassed](LoggerConfig::bypass_log_init))
+    ///
+    /// Override to register additional layers alongside the [`LoggerConfig::default_log_layer`],
+    /// without having to [bypass](LoggerConfig::bypass_log_init) automatic initialization.
+    ///
+    /// Defaults to just the [`LoggerConfig::default_log_layer`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn default_log_layers(&self) -> Vec<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    ///         vec![
+    ///             self.default_log_layer(),
+    ///             tracing_subscriber::fmt::Layer::default()
+    ///                 .with_writer(std::io::stderr)
+    ///                 .with_filter(LevelFilter::ERROR)
+    ///                 .boxed(),
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    fn default_log_layers(
+        &self,
+    ) -> Vec<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+        vec![self.default_log_layer()]
+    }
+
+    /// application specific environment variable to opt-out of network telemetry
+    ///
+    /// Checked in addition to the [`DO_NOT_TRACK`](https://consoledonottrack.com/) convention.
+    /// Refer to [`LoggerConfig::telemetry_disabled`].
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::LoggerConfig for Args {
+    ///     fn telemetry_opt_out_var(&self) -> Option<String> {
+    ///         Some(String::from("MYAPP_NO_TELEMETRY"))
+    ///     }
+    /// }
+    /// ```
+    fn telemetry_opt_out_var(&self) -> Option<String> {
+        None
+    }
+
+    /// whether network telemetry is disabled
+    ///
+    /// Network telemetry (i.e. exporter [features](crate#feature-flags)) is skipped when disabled.
+    /// Local logging is unaffected.
+    ///
+    /// Defaults to disabled if `DO_NOT_TRACK`, or the [`LoggerConfig::telemetry_opt_out_var`],
+    /// is set to a truthy value (i.e. anything other than empty, `0`, `false`, `no`, or `off`).
+    /// This is evaluated after [`dotenv`](DotEnvParserConfig) processing.
+    fn telemetry_disabled(&self) -> bool {
+        env_flag("DO_NOT_TRACK")
+            || self
+                .telemetry_opt_out_var()
+                .is_some_and(|var| env_flag(&var))
+    }
+}
+
+/// filtered [`tracing_subscriber::fmt::Layer`] for a single writer of [`LoggerConfig::default_log_layer`]
+fn fmt_layer<T: LoggerConfig>(
+    config: &T,
+    mut writer: tracing_subscriber::fmt::writer::BoxMakeWriter,
+    ansi: bool,
+    level: Option<LevelFilter>,
+) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static> {
+    let secrets = redact::secrets(&config.default_log_redact());
+    if !secrets.is_empty() {
+        writer = redact::writer(writer, secrets);
+    }
+
+    if config.default_log_non_blocking() {
+        writer = writer::non_blocking(writer);
+    }
+
+    #[cfg(feature = "bunyan")]
+    if config.default_log_bunyan() {
+        let layer = tracing_bunyan_formatter::BunyanFormattingLayer::new(
+            String::from(<T as clap::CommandFactory>::command().get_name()),
+            writer,
+        )
+        .with_filter(
+            config
+                .default_log_rate_limit()
+                .map(rate_limit::RateLimit::new),
+        );
+
+        return filtered_at(config, level, layer);
+    }
+
+    #[cfg(feature = "tree")]
+    if config.default_log_tree() {
+        let layer = tracing_tree::HierarchicalLayer::new(2)
+            .with_indent_lines(true)
+            .with_targets(config.default_log_with_target())
+            .with_thread_ids(config.default_log_with_thread_ids())
+            .with_thread_names(config.default_log_with_thread_names())
+            .with_ansi(ansi)
+            .with_writer(writer)
+            .with_filter(
+                config
+                    .default_log_rate_limit()
+                    .map(rate_limit::RateLimit::new),
+            );
+
+        return filtered_at(config, level, layer);
+    }
+
+    let layer = tracing_subscriber::fmt::Layer::default()
+        .with_span_events(config.default_log_span_events())
+        .fmt_fields(json::Fields::new(config.default_log_json()))
+        .event_format(config.default_log_format())
+        .with_ansi(ansi)
+        .with_writer(writer)
+        .with_filter(
+            config
+                .default_log_rate_limit()
+                .map(rate_limit::RateLimit::new),
+        );
+
+    filtered_at(config, level, layer)
+}
+
+/// unfiltered layer buffering the last `capacity` events (refer to [`LoggerConfig::default_log_ring_buffer`])
+fn ring_buffer_layer<T: LoggerConfig>(
+    config: &T,
+    capacity: usize,
+) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static> {
+    let mut writer =
+        tracing_subscriber::fmt::writer::BoxMakeWriter::new(config.default_log_writer());
+    let secrets = redact::secrets(&config.default_log_redact());
+    if !secrets.is_empty() {
+        writer = redact::writer(writer, secrets);
+    }
+
+    tracing_subscriber::fmt::Layer::default()
+        .fmt_fields(json::Fields::new(config.default_log_json()))
+        .event_format(config.default_log_format())
+        .with_ansi(config.default_log_ansi())
+        .with_writer(ring_buffer::writer(capacity, writer))
+        .boxed()
+}
+
+/// filtered [`tracing_journald`] layer, if enabled & available
+#[cfg(feature = "journald")]
+fn journald_layer<T: LoggerConfig>(
+    config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    if !config.default_log_journald() {
+        return None;
+    }
+
+    match tracing_journald::layer() {
+        Ok(layer) => Some(filtered_at(
+            config,
+            config.default_log_writer_level(),
+            layer,
+        )),
+        Err(e) => {
+            eprintln!("journald unavailable ({e}); using log writer");
+            None
+        }
+    }
+}
+
+#[cfg(not(feature = "journald"))]
+#[allow(clippy::unnecessary_wraps)]
+const fn journald_layer<T: LoggerConfig>(
+    _config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    None
+}
+
+/// filtered syslog layer, if enabled & available
+#[cfg(feature = "syslog")]
+fn syslog_layer<T: LoggerConfig>(
+    config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    let target = config.default_log_syslog()?;
+
+    match syslog::SyslogWriter::new(&target, <T as clap::CommandFactory>::command().get_name()) {
+        Ok(writer) => Some(filtered_at(
+            config,
+            config.default_log_writer_level(),
+            tracing_subscriber::fmt::Layer::default()
+                .without_time()
+                .with_level(false)
+                .with_ansi(false)
+                .with_writer(writer),
+        )),
+        Err(e) => {
+            eprintln!("syslog unavailable ({e}); using log writer");
+            None
+        }
+    }
+}
+
+#[cfg(not(feature = "syslog"))]
+#[allow(clippy::unnecessary_wraps)]
+const fn syslog_layer<T: LoggerConfig>(
+    _config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    None
+}
+
+/// filtered Windows Event Log layer, if enabled & available
+#[cfg(all(windows, feature = "eventlog"))]
+fn eventlog_layer<T: LoggerConfig>(
+    config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    let source = config.default_log_eventlog()?;
+
+    match tracing_layer_win_eventlog::EventLogLayer::new(&source) {
+        Ok(layer) => Some(filtered_at(
+            config,
+            config.default_log_writer_level(),
+            layer,
+        )),
+        Err(e) => {
+            eprintln!("event log unavailable ({e}); using log writer");
+            None
+        }
+    }
+}
+
+#[cfg(not(all(windows, feature = "eventlog")))]
+#[allow(clippy::unnecessary_wraps)]
+const fn eventlog_layer<T: LoggerConfig>(
+    _config: &T,
+) -> Option<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+    None
+}
+
+/// apply the [`LoggerConfig::default_env_filter`] (or [`LoggerConfig::default_log_level`]) to a layer
+///
+/// The filter is reloadable via [`logger_handle`].
+fn filtered<T: LoggerConfig, L>(
+    config: &T,
+    layer: L,
+) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>
+where
+    L: tracing_subscriber::Layer<Registry> + Send + Sync + 'static,
+{
+    filtered_at(config, None, layer)
+}
+
+/// [`filtered`], w/ `level` (if any) replacing the default level directive
+fn filtered_at<T: LoggerConfig, L>(
+    config: &T,
+    level: Option<LevelFilter>,
+    layer: L,
+) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>
+where
+    L: tracing_subscriber::Layer<Registry> + Send + Sync + 'static,
+{
+    let mut filter = config
+        .default_env_filter()
+        .unwrap_or_else(|| EnvFilter::default().add_directive(config.default_log_level().into()));
+    if let Some(level) = level {
+        // target specific directives still apply
+        filter = filter.add_directive(level.into());
+    }
+    let (filter, handle) = reload::Layer::new(filter);
+    handle::push(handle);
+
+    layer.with_filter(filter).boxed()
+}
+
+/// whether the environment variable is set to a truthy value
+///
+/// Anything other than empty, `0`, `false`, `no`, or `off` (case-insensitive) is truthy.
+fn env_flag(var: &str) -> bool {
+    std::env::var(var).is_ok_and(|value| {
+        !matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "" | "0" | "false" | "no" | "off"
+        )
+    })
+}
+
+/// blanket implementation for automatic [`tracing`] & [`tracing_subscriber`] initialization
+///
+/// Refer to [`LoggerConfig`] for configuration options.
+pub trait Logger: LoggerConfig {
+    /// register the supplied layers with the global tracing subscriber
+    ///
+    /// Default behvaior is to automatically (on startup) register the layers provided by [`LoggerConfig::default_log_layers`].
+    ///
+    /// With the `log` [feature](crate#feature-flags) (enabled by default), records from the
+    /// [`log`](https://crates.io/crates/log) crate (i.e. dependencies using `log::info!`, etc.) are forwarded to the subscriber.
+    ///
+    /// With the `tokio-console` [feature](crate#feature-flags), a [`console_subscriber`](https://docs.rs/console-subscriber)
+    /// layer is also registered (in addition to the default/supplied layers) when `TOKIO_CONSOLE` is set to a truthy value.
+    /// The console server is configured via the `TOKIO_CONSOLE_*` env vars (e.g. `TOKIO_CONSOLE_BIND`).
+    /// This requires building w/ `RUSTFLAGS="--cfg tokio_unstable"`; otherwise, the layer is skipped (w/ a warning).
+    ///
+    /// Once the default layer is registered, its filter can be changed at runtime via [`logger_handle`].
+    ///
+    /// Panics are logged as `ERROR` events, unless disabled via [`LoggerConfig::default_log_panics`].
+    ///
+    /// When calling this directly, hold a [`FlushGuard`] so buffered/network output is flushed before exiting.
+    ///
+    /// This automatic setup/config can be disabled with [`LoggerConfig::bypass_log_init`].
+    /// When bypassed, **[`Logger::log_init`] must be manually/directly called from the application.**
+    /// This is an advanced use case. Refer to [`LoggerConfig::bypass_log_init`] for more details.
+    ///
+    /// # Errors
+    /// * the [`LoggerConfig::default_log_file`] (or its directory) can't be created/opened
+    /// * [`tracing::subscriber::set_global_default`] was unsuccessful, likely because a global subscriber was already installed
+    fn log_init(
+        self,
+        layers: Option<Vec<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>>>,
+    ) -> anyhow::Result<Self> {
+        // before the layers, so hook chaining integrations (e.g. Sentry) still run
+        if self.default_log_panics() {
+            panic::install();
+        }
+
+        let layers = match (self.bypass_log_init(), &layers) {
+            (false, Some(_)) => {
+                anyhow::bail!("bypass_log_init() is false, but layers were passed into log_init()");
+            }
+            (false, None) => {
+                // fail w/ a clear error here, rather than panicking in default_log_layer() (which takes the writer)
+                let _preopened = self
+                    .default_log_file()
+                    .map(|file| writer::preopen_log_file(&file, self.default_log_rotation()))
+                    .transpose()?;
+
+                handle::clear();
+                Some(self.default_log_layers())
+            }
+            (true, _) => layers,
+        };
+
+        #[cfg(feature = "tokio-console")]
+        let console = env_flag("TOKIO_CONSOLE");
+        #[cfg(feature = "tokio-console")]
+        let layers = layers.map(|mut layers| {
+            if console && cfg!(tokio_unstable) {
+                layers.push(console_subscriber::spawn().boxed());
+            }
+            layers
+        });
+
+        if let Some(layers) = layers {
+            if tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layers))
+                .is_err()
+            {
+                anyhow::bail!("tracing::subscriber::set_global_default failed");
+            }
+
+            #[cfg(feature = "log")]
+            if let Err(e) = tracing_log::LogTracer::init() {
+                warn!("log records won't be forwarded ({e})");
+            }
+
+            // once the subscriber is installed, so it's logged
+            #[cfg(feature = "tokio-console")]
+            if console && !cfg!(tokio_unstable) {
+                warn!("tokio-console unavailable (requires RUSTFLAGS=\"--cfg tokio_unstable\")");
+            }
+        }
+
+        handle::install();
+
+        #[cfg(feature = "signals")]
+        if logger_handle().is_some() {
+            signals::default_filter(self.default_env_filter().map_or_else(
+                || self.default_log_level().to_string(),
+                |filter| filter.to_string(),
+            ));
+
+            if self.default_log_signals() {
+                signals::install(
+                    SignalConfig::new()
+                        .on(Signal::User1, SignalAction::RaiseLogLevel)
+                        .on(Signal::User2, SignalAction::LowerLogLevel)
+                        .on(Signal::Hangup, SignalAction::ResetLogLevel),
+                );
+            }
+        }
+
+        // LevelFilter::current() also reflects the (thread local) startup buffer, if any
+        info!(
+            "log level: {}",
+            logger_handle()
+                .and_then(|handle| handle.level())
+                .unwrap_or_else(LevelFilter::current)
+                .into_level()
+                .expect("invalid LevelFilter::current()")
+        );
+
+        Ok(self)
+    }
+}
+impl<T: LoggerConfig> Logger for T {}
+
+/// automatic [`dotenv`](dotenvy) processing configuration
+///
+/// Available configuration for the [`DotEnvParser`] trait.
+///
+/// Default implementations are what you'd expect.
+/// Use this [derive macro](macros::DotEnvDefault) for typical use cases.
+///
+/// # Order Matters!
+/// Environment variables are processed/set in this order:
+/// 1. Preexisting variables already defined in environment.
+/// 2. The `.env` file, if present (or the decrypted `.env.vault` file, w/ the `vault` [feature](crate#feature-flags)).
+///    For [tests](DotEnvParserConfig::dotenv_test), the `.env.test` file is preferred.
+/// 3. The `.env` file in the platform config directory, if present (refer to [`dotenv_config_dir`]).
+/// 4. The `.env.{environment}` file, if present (refer to [`dotenv_environment`]).
+/// 5. The `.env.local` file, if present & the environment isn't `production` (refer to [`dotenv_local`]).
+/// 6. The `.env.{environment}.local` file, if present.
+/// 7. [`dotenv_files`] supplied file(s) (sequentially, as supplied; defaults to the [`additional_dotenv_files`]).
+/// 8. [`env_sources`] supplied source(s) (sequentially, as supplied).
+/// 9. The [`builtin_dotenv`] content, if any. This never overrides (i.e. it has the lowest precedence).
+///
+/// Keep in mind:
+/// * Depending on [`dotenv_can_override`], environment variable values may be the first *or* last processed/set.
+///   Preexisting variables are only overridden per the [`precedence_policy`].
+/// * Regardless, the `.env.{environment}`, `.env.local`, & `.env.{environment}.local` files override the values
+///   processed before them (i.e. the most specific file wins), but not preexisting variables (per the [`precedence_policy`]).
+/// * [`additional_dotenv_files`] should be supplied in the order to be processed.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, EntrypointDefault, LoggerDefault, Clone)]
+/// #[derive(DotEnvDefault)]
+/// struct Args {}
+///
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> anyhow::Result<()> {
+///     // .env variables should now be in the environment
+///     for (key, value) in std::env::vars() {
+///         println!("{key}: {value}");
+///     }
+/// #   Ok(())
+/// }
+/// ```
+/// [`additional_dotenv_files`]: DotEnvParserConfig#method.additional_dotenv_files
+/// [`dotenv_can_override`]: DotEnvParserConfig#method.dotenv_can_override
+/// [`precedence_policy`]: DotEnvParserConfig#method.precedence_policy
+/// [`dotenv_config_dir`]: DotEnvParserConfig#method.dotenv_config_dir
+/// [`dotenv_environment`]: DotEnvParserConfig#method.dotenv_environment
+/// [`dotenv_local`]: DotEnvParserConfig#method.dotenv_local
+/// [`builtin_dotenv`]: DotEnvParserConfig#method.builtin_dotenv
+/// [`env_sources`]: DotEnvParserConfig#method.env_sources
+/// [`dotenv_files`]: DotEnvParserConfig#method.dotenv_files
+pub trait DotEnvParserConfig: clap::Parser {
+    /// additional dotenv files to process
+    ///
+    /// Default behavior is to only use `.env` (i.e. no additional files).
+    /// This preserves the stock/default [`dotenvy`] behavior.
+    ///
+    /// File names may contain `*`/`?` wildcards (e.g. `conf/*.env`); matches are processed in sorted order.
+    /// A supplied file (or pattern w/o any match) that can't be found is an error.
+    ///
+    /// This can be easily set with convenience [`macros`](macros::DotEnvDefault#attributes) (i.e. `#[dotenv_files(...)]`).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// allow user to pass in additional env files
+    ///     #[arg(long)]
+    ///     user_dotenv: Option<std::path::PathBuf>,
+    /// }
+    ///
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+    ///         self.user_dotenv.clone().map(|p| vec![p])
+    ///     }
+    /// }
+    /// ```
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        None
+    }
+
+    /// additional dotenv files to process, each tagged [required](DotEnvFile::Required) or [optional](DotEnvFile::Optional)
+    ///
+    /// A missing optional file (e.g. a developer overlay) is skipped, while a missing required file is an error.
+    /// Optional files that are present, but invalid, are still an error.
+    ///
+    /// Defaults to the [`DotEnvParserConfig::additional_dotenv_files`] (all required).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::DotEnvDefault#attributes) (i.e. `#[dotenv_files(optional(...))]`).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_files(&self) -> Vec<DotEnvFile> {
+    ///         vec![
+    ///             DotEnvFile::Required(std::path::PathBuf::from("config/app.env")),
+    ///             DotEnvFile::Optional(std::path::PathBuf::from("config/overlay.env")),
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_files(&self) -> Vec<DotEnvFile> {
+        self.additional_dotenv_files()
+            .unwrap_or_default()
+            .into_iter()
+            .map(DotEnvFile::Required)
+            .collect()
+    }
+
+    /// whether successive dotenv files can override already defined environment variables
+    ///
+    /// Default behavior is to not override.
+    /// This preserves the stock/default [`dotenvy`] behavior.
+    ///
+    /// Variables defined in the process environment are only overridden per the [`DotEnvParserConfig::precedence_policy`].
+    ///
+    /// This can be easily set with convenience [`macros`](macros::DotEnvDefault#attributes) (i.e. `#[dotenv_override]`).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_can_override(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_can_override(&self) -> bool {
+        false
+    }
+
+    /// precedence of CLI args, the process environment, dotenv files, and defaults
+    ///
+    /// [`Entrypoint::entrypoint`] enforces this while processing dotenv files & (re)parsing args,
+    /// and logs how each `#[arg(env)]` field was resolved at the `DEBUG` level.
+    ///
+    /// Default behavior is [`PrecedencePolicy::CliDotEnvEnv`] when [`DotEnvParserConfig::dotenv_can_override`],
+    /// otherwise [`PrecedencePolicy::CliEnvDotEnv`].
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn precedence_policy(&self) -> PrecedencePolicy {
+    ///         PrecedencePolicy::CliEnv
+    ///     }
+    /// }
+    /// ```
+    fn precedence_policy(&self) -> PrecedencePolicy {
+        if self.dotenv_can_override() {
+            PrecedencePolicy::CliDotEnvEnv
+        } else {
+            PrecedencePolicy::CliEnvDotEnv
+        }
+    }
+
+    /// whether the `.env` file is required
+    ///
+    /// When required, a missing (or invalid) `.env` file fails startup, rather than logging a warning.
+    /// Use this for deployments that'd otherwise run half-configured.
+    ///
+    /// Default behavior is optional ([`false`]).
+    ///
+    /// This can be easily set with convenience [`macros`](macros::DotEnvDefault#attributes) (i.e. `#[dotenv_required]`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_required(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_required(&self) -> bool {
+        false
+    }
+
+    /// dotenv content compiled into (or otherwise supplied by) the binary, processed w/ the lowest precedence
+    ///
+    /// This provides defaults that `.env` file(s) and the real environment can override.
+    /// It's processed last, so its variables can't be referenced by dotenv file values.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+    ///         Some(Box::new("PORT=8080\nLOG_LEVEL=INFO\n".as_bytes())) // e.g. include_str!("../defaults.env")
+    ///     }
+    /// }
+    /// ```
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        None
+    }
+
+    /// environment (i.e. profile) selecting the `.env.{environment}` & `.env.{environment}.local` files to process
+    ///
+    /// Evaluated after the `.env` file is processed, so it can be defined there.
+    /// Missing profile files are skipped.
+    ///
+    /// Defaults to the `APP_ENV` env var (e.g. `APP_ENV=production` selects `.env.production`), if set.
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// allow user to select the environment
+    ///     #[arg(long, env = "MYAPP_ENV")]
+    ///     environment: Option<String>,
+    /// }
+    ///
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_environment(&self) -> Option<String> {
+    ///         self.environment.clone()
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_environment(&self) -> Option<String> {
+        std::env::var("APP_ENV")
+            .ok()
+            .filter(|environment| !environment.is_empty())
+    }
+
+    /// whether the `.env.test` file (if present) is processed in place of the `.env` file (i.e. isolated test config)
+    ///
+    /// The `.env.local` file is also skipped, so test runs are reproducible across machines.
+    /// Evaluated before the `.env` file is processed.
+    ///
+    /// Default behavior is enabled ([`true`]) in the `test` [environment](DotEnvParserConfig::dotenv_environment) (e.g. `APP_ENV=test`).
+    /// [`macros::DotEnvDefault`] also enables it when compiled for tests (i.e. `cfg(test)`).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_test(&self) -> bool {
+    ///         cfg!(test) || std::env::var_os("CI").is_some()
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_test(&self) -> bool {
+        self.dotenv_environment().as_deref() == Some("test")
+    }
+
+    /// whether to process the `.env.local` file (i.e. machine specific overrides; keep it out of version control)
+    ///
+    /// It's processed unless the [environment](DotEnvParserConfig::dotenv_environment) is `production` (including when
+    /// no environment is set), and never for [tests](DotEnvParserConfig::dotenv_test).
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_local(&self) -> bool { false }
+    /// }
+    /// ```
+    fn dotenv_local(&self) -> bool {
+        true
+    }
+
+    /// whether to process the `.env` file in the platform config directory (refer to [`config_dir`]), named for the [`clap::Command`]
+    ///
+    /// Use this for CLIs installed system-wide (i.e. w/o a project directory to hold a `.env` file).
+    /// It's skipped if missing.
+    ///
+    /// Default behavior is disabled ([`false`]).
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_config_dir(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_config_dir(&self) -> bool {
+        false
+    }
+
+    /// whether dotenv files are searched for in parent directories (like `git` does), when missing from the current directory
+    ///
+    /// Applies to relative paths (i.e. `.env`, the profile files, and [`DotEnvParserConfig::additional_dotenv_files`]).
+    /// The search stops at the [`DotEnvParserConfig::dotenv_search_boundary`], if defined.
+    ///
+    /// Default behavior is enabled ([`true`]).
+    /// This preserves the stock/default [`dotenvy`] behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_search_parents(&self) -> bool { false }
+    /// }
+    /// ```
+    fn dotenv_search_parents(&self) -> bool {
+        true
+    }
+
+    /// last (i.e. top-most) directory searched by [`DotEnvParserConfig::dotenv_search_parents`] (e.g. the workspace root)
+    ///
+    /// Defaults to [`None`] (i.e. search up to the filesystem root).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_search_boundary(&self) -> Option<std::path::PathBuf> {
+    ///         Some(std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_search_boundary(&self) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// environment variables that must be set once dotenv processing completes
+    ///
+    /// [`Entrypoint::entrypoint`] verifies these (refer to [`DotEnvParser::verify_required_env_vars`])
+    /// and fails w/ a single error listing every missing variable.
+    ///
+    /// Defaults to none.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn required_env_vars(&self) -> Vec<String> {
+    ///         vec![String::from("DATABASE_URL"), String::from("API_TOKEN")]
+    ///     }
+    /// }
+    /// ```
+    fn required_env_vars(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// how variables loaded from dotenv files, but not known to the application, are reported (e.g. a `LOG_LEVLE` typo)
+    ///
+    /// Known variables are the [`DotEnvParserConfig::dotenv_known_vars`], the `env =` bindings of the [`clap`] args,
+    /// the [`DotEnvParserConfig::required_env_vars`], and the [`DotEnvParserConfig::env_schema`] keys.
+    /// [`Entrypoint::entrypoint`] audits these after dotenv processing (refer to [`DotEnvParser::verify_dotenv_keys`]).
+    ///
+    /// Default behavior is [`DotEnvAudit::Off`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_audit(&self) -> DotEnvAudit {
+    ///         DotEnvAudit::Deny
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_audit(&self) -> DotEnvAudit {
+        DotEnvAudit::Off
+    }
+
+    /// additional variables known to the application (i.e. allowed in dotenv files)
+    ///
+    /// Refer to [`DotEnvParserConfig::dotenv_audit`].
+    ///
+    /// Defaults to none.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_known_vars(&self) -> Vec<String> {
+    ///         vec![String::from("APP_ENV"), String::from("RUST_LOG")]
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_known_vars(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// whether keys loaded from dotenv files (& [sources](DotEnvParserConfig::env_sources)) are normalized
+    /// (i.e. uppercase, w/ `-` & `.` replaced by `_`; e.g. `db.url` -> `DB_URL`)
+    ///
+    /// Normalized keys are also used by the [`DotEnvParserConfig::dotenv_audit`].
+    ///
+    /// Default behavior is disabled ([`false`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_normalize_keys(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_normalize_keys(&self) -> bool {
+        false
+    }
+
+    /// how keys loaded from dotenv files (& [sources](DotEnvParserConfig::env_sources)) that differ, but normalize identically, are reported (e.g. `db_url` & `DB_URL`)
+    ///
+    /// W/o [normalization](DotEnvParserConfig::dotenv_normalize_keys), these are silent duplicates.
+    /// W/ it, only the first processed value is used (unless [overriding](DotEnvParserConfig::dotenv_can_override)).
+    /// [`Entrypoint::entrypoint`] reports these after dotenv processing (refer to [`DotEnvParser::verify_dotenv_keys`]).
     ///
-    /// **You ***probably*** don't want to override this default implementation.**
-    /// 1. For standard customization, override these other trait methods:
-    ///    * [`LoggerConfig::default_log_level`]
-    ///    * [`LoggerConfig::default_log_format`]
-    ///    * [`LoggerConfig::default_log_writer`]
-    /// 2. Minor/static customization(s) ***can*** be achieved by overriding this method...
-    ///    though this might warrant moving to the 'advanced requirements' option below.
-    /// 3. Otherwise, for advanced requirements, refer to [`LoggerConfig::bypass_log_init`].
-    fn default_log_layer(
-        &self,
-    ) -> Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static> {
-        let (layer, _) = reload::Layer::new(
-            tracing_subscriber::fmt::Layer::default()
-                .event_format(self.default_log_format())
-                .with_writer(self.default_log_writer())
-                .with_filter(self.default_log_level()),
-        );
+    /// Default behavior is [`DotEnvAudit::Off`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_key_collisions(&self) -> DotEnvAudit {
+    ///         DotEnvAudit::Warn
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_key_collisions(&self) -> DotEnvAudit {
+        DotEnvAudit::Off
+    }
+
+    /// validation [rules](EnvRule) for environment variable values (e.g. types, ranges, enumerations)
+    ///
+    /// [`Entrypoint::entrypoint`] verifies these after dotenv processing (refer to [`DotEnvParser::verify_env_schema`])
+    /// and fails w/ a single error listing every invalid variable. Unset variables are skipped.
+    ///
+    /// Defaults to none.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn env_schema(&self) -> Vec<EnvRule> {
+    ///         vec![
+    ///             EnvRule::new("PORT").parse::<u16>(),
+    ///             EnvRule::new("APP_ENV").one_of(["dev", "staging", "prod"]),
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    fn env_schema(&self) -> Vec<EnvRule> {
+        Vec::new()
+    }
+
+    /// whether referencing an undefined variable in a dotenv value is an error
+    ///
+    /// Values can reference variables (e.g. `DATABASE_URL=postgres://${DB_USER}@${DB_HOST}/app`)
+    /// already in the environment (e.g. from previously processed files) or defined earlier in the same file.
+    /// References are not expanded within single quotes, and `\$` escapes a literal `$`.
+    ///
+    /// Default behavior is lenient ([`false`]); undefined variables expand to an empty string.
+    /// This preserves the stock/default [`dotenvy`] behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_strict_interpolation(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_strict_interpolation(&self) -> bool {
+        false
+    }
+
+    /// key used to decrypt the `.env.vault` file (i.e. the [dotenv-vault](https://www.dotenv.org/docs/security/env-vault) format)
+    ///
+    /// When defined (and the `.env.vault` file is found), the decrypted environment is processed in place of the plaintext `.env` file.
+    /// The key is a `dotenv://:key_<hex>@dotenv.org/vault/.env.vault?environment=<name>` URI, which selects the `DOTENV_VAULT_<NAME>` environment.
+    /// Multiple comma separated keys are tried in order (e.g. during key rotation).
+    ///
+    /// Defaults to the `DOTENV_KEY` env var, if set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// struct Args {
+    ///     /// allow user to supply the key
+    ///     #[arg(long, env = "MYAPP_VAULT_KEY")]
+    ///     vault_key: Option<String>,
+    /// }
+    ///
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_vault_key(&self) -> Option<String> {
+    ///         self.vault_key.clone()
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "vault")]
+    fn dotenv_vault_key(&self) -> Option<String> {
+        std::env::var("DOTENV_KEY")
+            .ok()
+            .filter(|key| !key.is_empty())
+    }
+
+    /// only extract variables w/ this key prefix (e.g. `MYAPP_`) into the [`Entrypoint::entrypoint_with_config`] config
+    ///
+    /// The prefix is removed before matching field names (e.g. `MYAPP_PORT` -> `port`).
+    ///
+    /// Defaults to [`None`] (i.e. consider all variables).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn env_config_prefix(&self) -> Option<String> {
+    ///         Some(String::from("MYAPP_"))
+    ///     }
+    /// }
+    /// ```
+    fn env_config_prefix(&self) -> Option<String> {
+        None
+    }
+
+    /// whether to watch the processed dotenv files, and re-process them all when any change (i.e. hot-reload)
+    ///
+    /// A reload starts from scratch: previously loaded values are removed, then the files are re-processed
+    /// (args are re-parsed, so `#[arg(env)]` defaults can be reevaluated). Changed keys are logged
+    /// and passed to [`DotEnvParserConfig::dotenv_on_reload`].
+    ///
+    /// Files that didn't exist at startup aren't watched.
+    ///
+    /// Default behavior is disabled ([`false`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_watch(&self) -> bool { true }
+    /// }
+    /// ```
+    #[cfg(feature = "watch")]
+    fn dotenv_watch(&self) -> bool {
+        false
+    }
+
+    /// callback after a reload changes any variables
+    ///
+    /// Reloads are triggered by `DotEnvParserConfig::dotenv_watch` (w/ the `watch` [feature](crate#feature-flags))
+    /// or a `SignalAction::ReloadConfig` signal (w/ the `signals` [feature](crate#feature-flags)).
+    ///
+    /// Called w/ the (sorted) added, removed, or changed keys, on the (freshly re-parsed) args of the reload.
+    ///
+    /// Defaults to a no-op.
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_on_reload(&self, changed: &[String]) {
+    ///         if changed.iter().any(|key| key == "LOG_LEVEL") {
+    ///             let level = std::env::var("LOG_LEVEL").unwrap_or_default();
+    ///             if let Some(handle) = entrypoint::logger_handle() {
+    ///                 let _ = handle.set_filter(&level);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(any(feature = "signals", feature = "watch"))]
+    fn dotenv_on_reload(&self, changed: &[String]) {
+        let _ = changed;
+    }
+
+    /// whether dotenv processing is isolated (i.e. merged into an [`EnvMap`] instead of mutating the process environment)
+    ///
+    /// When enabled, [`DotEnvParser::process_dotenv_files`] is a no-op. The merged variables are available via
+    /// [`DotEnvParser::dotenv_env_map`] (e.g. handed to the function by [`Entrypoint::entrypoint_with_env`]).
+    /// Useful for library-style embedding & parallel tests, where mutating the global environment is unacceptable.
+    ///
+    /// Keep in mind:
+    /// * `#[arg(env)]` fields only see the process environment.
+    /// * Dotenv values can only reference the process environment & earlier variables of the same file.
+    ///
+    /// Default behavior is disabled ([`false`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_isolated(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_isolated(&self) -> bool {
+        false
+    }
+
+    /// additional (non-file) [`EnvSource`]s to process (e.g. an HTTP endpoint, AWS SSM, mounted secrets)
+    ///
+    /// Sources are processed after the dotenv files, w/ the same [`DotEnvParserConfig::dotenv_can_override`] behavior.
+    /// A source that fails to supply its variables is an error.
+    ///
+    /// Defaults to none.
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// struct Defaults;
+    ///
+    /// impl EnvSource for Defaults {
+    ///     fn name(&self) -> String {
+    ///         String::from("defaults")
+    ///     }
+    ///
+    ///     fn vars(&self) -> anyhow::Result<Vec<(String, String)>> {
+    ///         Ok(vec![(String::from("PORT"), String::from("8080"))])
+    ///     }
+    /// }
+    ///
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+    ///         vec![Box::new(Defaults)]
+    ///     }
+    /// }
+    /// ```
+    fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+        Vec::new()
+    }
+
+    /// only import dotenv variables w/ this key prefix (e.g. `MYAPP_`)
+    ///
+    /// Keeps a shared dotenv file (e.g. a monorepo/compose `.env`) from polluting the environment of a specific service.
+    /// Applies to every processed dotenv file, including the [`DotEnvParserConfig::builtin_dotenv`] content.
+    /// Variables already in the environment are unaffected.
+    ///
+    /// Defaults to [`None`] (i.e. import all variables).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_prefix(&self) -> Option<String> {
+    ///         Some(String::from("MYAPP_"))
+    ///     }
+    /// }
+    /// ```
+    fn dotenv_prefix(&self) -> Option<String> {
+        None
+    }
+
+    /// whether the [`DotEnvParserConfig::dotenv_prefix`] is removed from imported keys (e.g. `MYAPP_PORT` -> `PORT`)
+    ///
+    /// Default behavior is disabled ([`false`]; i.e. keys are imported as-is).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::DotEnvParserConfig for Args {
+    ///     fn dotenv_prefix(&self) -> Option<String> {
+    ///         Some(String::from("MYAPP_"))
+    ///     }
+    ///
+    ///     fn dotenv_strip_prefix(&self) -> bool { true }
+    /// }
+    /// ```
+    fn dotenv_strip_prefix(&self) -> bool {
+        false
+    }
+}
+
+/// blanket implementation for automatic [`dotenv`](dotenvy) processing
+///
+/// Refer to [`DotEnvParserConfig`] for configuration options.
+pub trait DotEnvParser: DotEnvParserConfig {
+    /// process dotenv files and populate variables into the environment
+    ///
+    /// This will run automatically at startup.
+    /// This is a no-op on `wasm32` targets.
+    ///
+    /// The origin of each variable is recorded (refer to [`env_origin`]) & logged at the `DEBUG` level.
+    ///
+    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    ///
+    /// # Errors
+    /// * the `.env` file is missing or invalid, when [required](DotEnvParserConfig::dotenv_required)
+    /// * failure processing a present `.env.{environment}` or `.env.local` file (refer to [`DotEnvParserConfig::dotenv_environment`])
+    /// * an undefined variable is referenced, when [strict](DotEnvParserConfig::dotenv_strict_interpolation)
+    /// * failure processing a [`DotEnvParserConfig::dotenv_files`] supplied file (or a required one is missing)
+    /// * failure processing an [`DotEnvParserConfig::env_sources`] supplied source
+    /// * failure processing the [`DotEnvParserConfig::builtin_dotenv`] content
+    fn process_dotenv_files(self) -> anyhow::Result<Self> {
+        // no filesystem (or process env) in the browser
+        if cfg!(target_arch = "wasm32") || self.dotenv_isolated() {
+            return Ok(self);
+        }
+
+        provenance::init();
+        #[cfg(any(feature = "signals", feature = "watch"))]
+        dotenv_reload::init();
+
+        let (can_override, protect) = overrides(&self);
+        merge_dotenv(
+            &self,
+            &dotenv::Merge {
+                can_override,
+                protect,
+                prefix: self.dotenv_prefix(),
+                strip: self.dotenv_strip_prefix(),
+                normalize: self.dotenv_normalize_keys(),
+                isolated: None,
+            },
+        )?;
+
+        Ok(self)
+    }
+
+    /// verify every [`DotEnvParserConfig::env_schema`] rule against the (set) variables
+    ///
+    /// This will run automatically at startup, after dotenv processing.
+    ///
+    /// # Errors
+    /// * any variable is invalid; the error lists all of them
+    fn verify_env_schema(&self) -> anyhow::Result<()> {
+        let isolated = if self.dotenv_isolated() {
+            Some(self.dotenv_env_map()?)
+        } else {
+            None
+        };
+
+        let invalid: Vec<String> = self
+            .env_schema()
+            .iter()
+            .filter_map(|rule| {
+                let value = isolated.as_ref().map_or_else(
+                    || std::env::var(rule.key()).ok(),
+                    |env| env.get(rule.key()).map(str::to_owned),
+                )?;
+
+                rule.validate(&value)
+                    .err()
+                    .map(|e| format!("{}={value:?}: {e}", rule.key()))
+            })
+            .collect();
+
+        if !invalid.is_empty() {
+            anyhow::bail!(
+                "invalid environment variable(s):\n  {}",
+                invalid.join("\n  ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// report variables loaded from dotenv files that aren't known, per [`DotEnvParserConfig::dotenv_audit`],
+    /// or that collide, per [`DotEnvParserConfig::dotenv_key_collisions`]
+    ///
+    /// This will run automatically at startup, after dotenv processing.
+    ///
+    /// # Errors
+    /// * any variable is unknown (w/ [`DotEnvAudit::Deny`]); the error lists all of them
+    /// * any variables collide (w/ [`DotEnvAudit::Deny`]); the error lists all of them
+    fn verify_dotenv_keys(&self) -> anyhow::Result<()> {
+        let audit = self.dotenv_audit();
+        if audit != DotEnvAudit::Off {
+            let mut known = dotenv_audit::bindings(&<Self as clap::CommandFactory>::command());
+            known.extend(self.dotenv_known_vars());
+            known.extend(self.required_env_vars());
+            known.extend(self.env_schema().iter().map(|rule| rule.key().to_owned()));
+
+            let unknown = dotenv_audit::unknown(&known, self.dotenv_normalize_keys());
+            dotenv_audit::report(audit, "unknown dotenv variable(s)", &unknown)?;
+        }
+
+        let audit = self.dotenv_key_collisions();
+        if audit != DotEnvAudit::Off {
+            let collisions = dotenv_audit::collisions();
+            dotenv_audit::report(audit, "colliding dotenv variable(s)", &collisions)?;
+        }
+
+        Ok(())
+    }
+
+    /// merge the process environment & dotenv files into an [`EnvMap`], w/o mutating the process environment
+    ///
+    /// Processing is otherwise identical to [`DotEnvParser::process_dotenv_files`]
+    /// (refer to [`DotEnvParserConfig::dotenv_isolated`]).
+    ///
+    /// # Errors
+    /// * refer to [`DotEnvParser::process_dotenv_files`]
+    fn dotenv_env_map(&self) -> anyhow::Result<EnvMap> {
+        env_map(self, self.dotenv_prefix(), self.dotenv_strip_prefix())
+    }
+
+    /// the resolved environment to forward to child processes (e.g. via [`EnvMap::command`] or [`std::process::Command::envs`])
+    ///
+    /// Like [`DotEnvParser::dotenv_env_map`], but every dotenv variable is included, regardless of the
+    /// [`DotEnvParserConfig::dotenv_prefix`] (or [`DotEnvParserConfig::dotenv_isolated`]).
+    /// The process environment isn't mutated. Variables are sorted by key, for deterministic forwarding.
+    ///
+    /// # Errors
+    /// * refer to [`DotEnvParser::process_dotenv_files`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+    /// # struct Args {}
+    /// #[entrypoint::entrypoint]
+    /// fn main(args: Args) -> anyhow::Result<()> {
+    ///     let env = args.dotenv_child_env()?;
+    ///     # if false {
+    ///     let status = env.command("worker").arg("--once").status()?;
+    ///     # }
+    /// #   Ok(())
+    /// }
+    /// ```
+    fn dotenv_child_env(&self) -> anyhow::Result<EnvMap> {
+        env_map(self, None, false)
+    }
+
+    /// verify every [`DotEnvParserConfig::required_env_vars`] variable is set
+    ///
+    /// This will run automatically at startup, after dotenv processing.
+    ///
+    /// # Errors
+    /// * any required variable isn't set; the error lists all of them
+    fn verify_required_env_vars(&self) -> anyhow::Result<()> {
+        let isolated = if self.dotenv_isolated() {
+            Some(self.dotenv_env_map()?)
+        } else {
+            None
+        };
+
+        let missing: Vec<String> = self
+            .required_env_vars()
+            .into_iter()
+            .filter(|var| {
+                isolated.as_ref().map_or_else(
+                    || std::env::var_os(var).is_none(),
+                    |env| !env.contains_key(var),
+                )
+            })
+            .collect();
+
+        if !missing.is_empty() {
+            anyhow::bail!(
+                "missing required environment variable(s): {}",
+                missing.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+}
+impl<T: DotEnvParserConfig> DotEnvParser for T {}
 
-        layer.boxed()
+/// whether dotenv variables can override existing ones, and whether the process environment is protected regardless
+///
+/// Refer to [`DotEnvParserConfig::precedence_policy`].
+fn overrides<T: DotEnvParserConfig>(config: &T) -> (bool, bool) {
+    let can_override = config.dotenv_can_override();
+    match config.precedence_policy() {
+        PrecedencePolicy::CliDotEnvEnv => (true, false),
+        _ => (can_override, can_override),
     }
 }
 
-/// blanket implementation for automatic [`tracing`] & [`tracing_subscriber`] initialization
+/// merge the process environment & dotenv files into an [`EnvMap`] (w/ the `prefix`, if any)
 ///
-/// Refer to [`LoggerConfig`] for configuration options.
-pub trait Logger: LoggerConfig {
-    /// register the supplied layers with the global tracing subscriber
-    ///
-    /// Default behvaior is to automatically (on startup) register the layer provided by [`LoggerConfig::default_log_layer`].
-    ///
-    /// This automatic setup/config can be disabled with [`LoggerConfig::bypass_log_init`].
-    /// When bypassed, **[`Logger::log_init`] must be manually/directly called from the application.**
-    /// This is an advanced use case. Refer to [`LoggerConfig::bypass_log_init`] for more details.
-    ///
-    /// # Errors
-    /// * [`tracing::subscriber::set_global_default`] was unsuccessful, likely because a global subscriber was already installed
-    fn log_init(
-        self,
-        layers: Option<Vec<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>>>,
-    ) -> anyhow::Result<Self> {
-        let layers = match (self.bypass_log_init(), &layers) {
-            (false, Some(_)) => {
-                anyhow::bail!("bypass_log_init() is false, but layers were passed into log_init()");
-            }
-            (false, None) => Some(vec![self.default_log_layer()]),
-            (true, _) => layers,
-        };
+/// Refer to [`DotEnvParser::dotenv_env_map`].
+fn env_map<T: DotEnvParserConfig>(
+    config: &T,
+    prefix: Option<String>,
+    strip: bool,
+) -> anyhow::Result<EnvMap> {
+    let vars = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .collect();
 
-        if layers.is_some()
-            && tracing_subscriber::registry()
-                .with(layers)
-                .try_init()
-                .is_err()
-        {
-            anyhow::bail!("tracing::subscriber::set_global_default failed");
+    let (can_override, protect) = overrides(config);
+    let merge = dotenv::Merge {
+        can_override,
+        protect,
+        prefix,
+        strip,
+        normalize: config.dotenv_normalize_keys(),
+        isolated: Some(std::cell::RefCell::new(vars)),
+    };
+    if !cfg!(target_arch = "wasm32") {
+        merge_dotenv(config, &merge)?;
+    }
+
+    Ok(EnvMap::from(
+        merge.isolated.unwrap_or_default().into_inner(),
+    ))
+}
+
+/// merge the dotenv files (& other sources) configured by `config`, per `merge`
+///
+/// Refer to [`DotEnvParser::process_dotenv_files`].
+#[allow(clippy::too_many_lines)]
+fn merge_dotenv<T: DotEnvParserConfig>(config: &T, merge: &dotenv::Merge) -> anyhow::Result<()> {
+    dotenv::init();
+    dotenv_audit::init();
+
+    let find = |file: &std::path::Path| {
+        dotenv::find(
+            file,
+            config.dotenv_search_parents(),
+            config.dotenv_search_boundary().as_deref(),
+        )
+    };
+
+    let verify = |path: &std::path::Path| {
+        if config.dotenv_strict_interpolation() {
+            dotenv::verify_references(path)
+        } else {
+            Ok(())
         }
+    };
+    let load = |path: &std::path::Path| {
+        verify(path)?;
+        merge
+            .load(path)
+            .with_context(|| format!("failed to process {}", path.display()))
+    };
+    let overlay = |path: &std::path::Path| {
+        verify(path)?;
+        merge
+            .overlay(path)
+            .with_context(|| format!("failed to process {}", path.display()))
+    };
 
-        info!(
-            "log level: {}",
-            LevelFilter::current()
-                .into_level()
-                .expect("invalid LevelFilter::current()")
-        );
+    // with a DOTENV_KEY, the encrypted .env.vault replaces the plaintext .env
+    #[cfg(feature = "vault")]
+    let vault = config.dotenv_vault_key().map_or(Ok(None), |key| {
+        vault::open(find(".env.vault".as_ref()), &key)
+    })?;
+    #[cfg(not(feature = "vault"))]
+    let vault: Option<String> = None;
 
-        Ok(self)
+    let test = config.dotenv_test();
+    let dotenv = if vault.is_some() {
+        None
+    } else if test {
+        find(".env.test".as_ref()).or_else(|| find(".env".as_ref()))
+    } else {
+        find(".env".as_ref())
+    };
+
+    if let Some(content) = vault {
+        if config.dotenv_strict_interpolation() {
+            dotenv::verify_content(".env.vault", &content)?;
+        }
+        merge.audit(dotenvy::from_read_iter(content.as_bytes()));
+        provenance::track(&EnvOrigin::Vault, || {
+            merge.read(content.as_bytes(), merge.can_override)
+        })
+        .inspect(|()| info!("dotenv::from_read(.env.vault)"))
+        .inspect_err(|_| error!("dotenv::from_read(.env.vault)"))
+        .context("failed to process .env.vault")?;
+    } else if let Some(path) = &dotenv {
+        if config.dotenv_required() {
+            load(path)?;
+        } else {
+            verify(path)?;
+            merge.load(path).unwrap_or(());
+            // failures are logged, but suppressed
+        }
+    } else if config.dotenv_required() {
+        error!("no .env file found");
+        anyhow::bail!("required .env file not found");
+    } else {
+        warn!("no .env file found"); // suppress, no .env is a valid use case
+    }
+
+    // optional; skipped if missing
+    if let Some(path) = config
+        .dotenv_config_dir()
+        .then(|| config_dir(<T as clap::CommandFactory>::command().get_name()))
+        .flatten()
+        .map(|dir| dir.join(".env"))
+        .filter(|path| path.is_file())
+    {
+        load(&path)?;
+    }
+
+    let environment = config.dotenv_environment();
+    let local = config.dotenv_local() && !test && environment.as_deref() != Some("production");
+    for file in [
+        environment
+            .as_ref()
+            .map(|environment| format!(".env.{environment}")),
+        local.then(|| String::from(".env.local")),
+        environment.map(|environment| format!(".env.{environment}.local")),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        // optional; skipped if missing (or already processed, i.e. .env.test)
+        // more specific, so these override the .env values (regardless of dotenv_can_override)
+        if let Some(path) = find(file.as_ref()).filter(|path| dotenv.as_ref() != Some(path)) {
+            overlay(&path)?;
+        }
+    }
+
+    // try all, so any/all failures will be in the log
+    #[allow(clippy::manual_try_fold)]
+    config
+        .dotenv_files()
+        .into_iter()
+        .flat_map(|file| {
+            let paths = dotenv::find_all(
+                file.path(),
+                config.dotenv_search_parents(),
+                config.dotenv_search_boundary().as_deref(),
+            );
+            if paths.is_empty() && file.is_required() {
+                error!("dotenv file not found ({})", file.path().display());
+                vec![Err(anyhow::anyhow!(
+                    "dotenv file not found ({})",
+                    file.path().display()
+                ))]
+            } else {
+                if paths.is_empty() {
+                    info!("optional dotenv file not found ({})", file.path().display());
+                }
+                paths.into_iter().map(Ok).collect()
+            }
+        })
+        .fold(Ok(()), |accum, path| {
+            path.and_then(|path| load(&path)).and(accum)
+        })?; // bail if any of the dotenv_files failed
+
+    for source in config.env_sources() {
+        env_source::merge(source.as_ref(), merge)
+            .with_context(|| format!("failed to process {}", source.name()))?;
     }
+
+    if let Some(builtin) = config.builtin_dotenv() {
+        provenance::track(&EnvOrigin::Builtin, || merge.read(builtin, false))
+            .inspect(|()| info!("dotenv::from_read(builtin)"))
+            .inspect_err(|_| error!("dotenv::from_read(builtin)"))
+            .context("failed to process builtin dotenv")?;
+    }
+
+    Ok(())
 }
-impl<T: LoggerConfig> Logger for T {}
 
-/// automatic [`dotenv`](dotenvy) processing configuration
-///
-/// Available configuration for the [`DotEnvParser`] trait.
+/// layered (i.e. structured) config file configuration
 ///
-/// Default implementations are what you'd expect.
-/// Use this [derive macro](macros::DotEnvDefault) for typical use cases.
+/// Available configuration for the [`ConfigParser`] trait.
+/// Use this for nested configuration that's too awkward for flat environment variables.
 ///
 /// # Order Matters!
-/// Environment variables are processed/set in this order:
-/// 1. Preexisting variables already defined in environment.
-/// 2. The `.env` file, if present.
-/// 3. [`additional_dotenv_files`] supplied file(s) (sequentially, as supplied).
-///
-/// Keep in mind:
-/// * Depending on [`dotenv_can_override`], environment variable values may be the first *or* last processed/set.
-/// * [`additional_dotenv_files`] should be supplied in the order to be processed.
+/// Config layers are merged in this order (i.e. later layers take precedence):
+/// 1. Field defaults (e.g. `#[serde(default)]`).
+/// 2. The [`config_dir_file`] in the platform config directory, if present.
+/// 3. [`config_sources`] supplied source(s) (sequentially, as supplied); nested tables are merged, not replaced.
+/// 4. Environment variables w/ the [`config_env_prefix`] (if any), nested by the [`config_env_separator`]
+///    (e.g. `MYAPP_DATABASE__URL` -> `database.url`).
+///    [`dotenv`](DotEnvParserConfig) processing completes beforehand, so dotenv values are included.
 ///
 /// # Examples
 /// ```
 /// # use entrypoint::prelude::*;
-/// # #[derive(clap::Parser, LoggerDefault)]
-/// #[derive(DotEnvDefault)]
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
 /// struct Args {}
 ///
-/// #[entrypoint::entrypoint]
-/// fn main(args: Args) -> anyhow::Result<()> {
-///     // .env variables should now be in the environment
-///     for (key, value) in std::env::vars() {
-///         println!("{key}: {value}");
+/// impl ConfigParserConfig for Args {
+///     fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+///         vec![
+///             Box::new(ConfigFile::Optional("config/default.json".into())),
+///             Box::new(ConfigFile::Optional("config/local.json".into())),
+///         ]
+///     }
+///
+///     fn config_env_prefix(&self) -> Option<String> {
+///         Some(String::from("MYAPP_"))
 ///     }
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct Database {
+///     #[serde(default)]
+///     url: String,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(default)]
+///     port: u16,
+///     database: Option<Database>,
+/// }
+///
+/// #[entrypoint::entrypoint(config_files)]
+/// fn main(args: Args, config: Config) -> anyhow::Result<()> {
+///     info!("listening on {}", config.port);
 /// #   Ok(())
 /// }
 /// ```
-/// [`additional_dotenv_files`]: DotEnvParserConfig#method.additional_dotenv_files
-/// [`dotenv_can_override`]: DotEnvParserConfig#method.dotenv_can_override
-pub trait DotEnvParserConfig: clap::Parser {
-    /// additional dotenv files to process
+/// [`config_dir_file`]: ConfigParserConfig#method.config_dir_file
+/// [`config_sources`]: ConfigParserConfig#method.config_sources
+/// [`config_env_prefix`]: ConfigParserConfig#method.config_env_prefix
+/// [`config_env_separator`]: ConfigParserConfig#method.config_env_separator
+pub trait ConfigParserConfig: clap::Parser {
+    /// structured config source(s) (e.g. [`ConfigFile`]s), merged in order
     ///
-    /// Default behavior is to only use `.env` (i.e. no additional files).
-    /// This preserves the stock/default [`dotenvy`] behavior.
+    /// Defaults to none.
     ///
-    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    /// **[Order Matters!](ConfigParserConfig#order-matters)**
     ///
     /// # Examples
     /// ```
+    /// # use entrypoint::prelude::*;
     /// # #[derive(clap::Parser)]
     /// struct Args {
-    ///     /// allow user to pass in additional env files
+    ///     /// allow user to pass in a config file
     ///     #[arg(long)]
-    ///     user_dotenv: Option<std::path::PathBuf>,
+    ///     config: Option<std::path::PathBuf>,
     /// }
     ///
-    /// impl entrypoint::DotEnvParserConfig for Args {
-    ///     fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
-    ///         self.user_dotenv.clone().map(|p| vec![p])
+    /// impl entrypoint::ConfigParserConfig for Args {
+    ///     fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+    ///         let mut sources: Vec<Box<dyn ConfigSource>> =
+    ///             vec![Box::new(ConfigFile::Required("config.toml".into()))];
+    ///         sources.extend(
+    ///             self.config
+    ///                 .clone()
+    ///                 .map(|path| Box::new(ConfigFile::Required(path)) as Box<dyn ConfigSource>),
+    ///         );
+    ///         sources
     ///     }
     /// }
     /// ```
-    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+    fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+        Vec::new()
+    }
+
+    /// config file (e.g. `config.toml`) to merge from the platform config directory (refer to [`config_dir`]), named for the [`clap::Command`]
+    ///
+    /// Use this for CLIs installed system-wide (i.e. w/o a project directory to hold config files).
+    /// It's skipped if missing.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// **[Order Matters!](ConfigParserConfig#order-matters)**
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::ConfigParserConfig for Args {
+    ///     fn config_dir_file(&self) -> Option<String> {
+    ///         Some(String::from("config.toml"))
+    ///     }
+    /// }
+    /// ```
+    fn config_dir_file(&self) -> Option<String> {
         None
     }
 
-    /// whether successive dotenv files can override already defined environment variables
+    /// only merge environment variables w/ this key prefix (e.g. `MYAPP_`) into the config
     ///
-    /// Default behavior is to not override.
-    /// This preserves the stock/default [`dotenvy`] behavior.
+    /// The prefix is removed before matching field names (e.g. `MYAPP_PORT` -> `port`).
     ///
-    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    /// Defaults to [`None`] (i.e. consider all variables).
+    ///
+    /// **[Order Matters!](ConfigParserConfig#order-matters)**
     ///
     /// # Examples
     /// ```
     /// # #[derive(clap::Parser)]
     /// # struct Args {}
-    /// impl entrypoint::DotEnvParserConfig for Args {
-    ///     fn dotenv_can_override(&self) -> bool { true }
+    /// impl entrypoint::ConfigParserConfig for Args {
+    ///     fn config_env_prefix(&self) -> Option<String> {
+    ///         Some(String::from("MYAPP_"))
+    ///     }
     /// }
     /// ```
-    fn dotenv_can_override(&self) -> bool {
-        false
+    fn config_env_prefix(&self) -> Option<String> {
+        None
+    }
+
+    /// separator of nested keys in environment variable names (e.g. `DATABASE__URL` -> `database.url`)
+    ///
+    /// Defaults to `__`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::ConfigParserConfig for Args {
+    ///     fn config_env_separator(&self) -> String {
+    ///         String::from("__")
+    ///     }
+    /// }
+    /// ```
+    fn config_env_separator(&self) -> String {
+        String::from("__")
     }
 }
 
-/// blanket implementation for automatic [`dotenv`](dotenvy) processing
+/// blanket implementation for layered config processing
 ///
-/// Refer to [`DotEnvParserConfig`] for configuration options.
-pub trait DotEnvParser: DotEnvParserConfig {
-    /// process dotenv files and populate variables into the environment
+/// Refer to [`ConfigParserConfig`] for configuration options.
+pub trait ConfigParser: ConfigParserConfig {
+    /// merge the config layers & deserialize them into `C` (e.g. a [`serde::Deserialize`] config struct)
     ///
-    /// This will run automatically at startup.
+    /// String values (e.g. from the environment) are parsed per the field type, like [`from_env`].
     ///
-    /// **[Order Matters!](DotEnvParserConfig#order-matters)**
+    /// **[Order Matters!](ConfigParserConfig#order-matters)**
     ///
     /// # Errors
-    /// * failure processing an [`DotEnvParserConfig::additional_dotenv_files`] supplied file
-    fn process_dotenv_files(self) -> anyhow::Result<Self> {
-        if self.dotenv_can_override() {
-            dotenvy::dotenv_override()
-                .map(|file| info!("dotenv::from_filename_override({})", file.display()))
-        } else {
-            dotenvy::dotenv().map(|file| info!("dotenv::from_filename({})", file.display()))
-        }
-        .map_err(|_| warn!("no .env file found"))
-        .unwrap_or(()); // suppress, no .env is a valid use case
-
-        self.additional_dotenv_files().map_or(Ok(()), |files| {
-            // try all, so any/all failures will be in the log
-            #[allow(clippy::manual_try_fold)]
-            files.into_iter().fold(Ok(()), |accum, file| {
-                let process = |res: Result<std::path::PathBuf, dotenvy::Error>, msg| {
-                    res.map(|_| info!(msg)).map_err(|e| {
-                        error!(msg);
-                        e
-                    })
-                };
+    /// * failure processing a [`ConfigParserConfig::config_sources`] supplied source (or a required file is missing)
+    /// * a (non-[`Option`]/`#[serde(default)]`) field isn't set
+    /// * a value can't be parsed as its field type
+    fn load_config<C: serde::de::DeserializeOwned>(&self) -> anyhow::Result<C> {
+        let mut tree = serde_json::Value::Object(serde_json::Map::new());
 
-                if self.dotenv_can_override() {
-                    process(
-                        dotenvy::from_filename_override(file.clone()),
-                        format!("dotenv::from_filename_override({})", file.display()),
-                    )
-                } else {
-                    process(
-                        dotenvy::from_filename(file.clone()),
-                        format!("dotenv::from_filename({})", file.display()),
-                    )
-                }
-                .and(accum)
-            })
-        })?; // bail if any of the additional_dotenv_files failed
+        let platform = self.config_dir_file().and_then(|file| {
+            config_dir(<Self as clap::CommandFactory>::command().get_name())
+                .map(|dir| Box::new(ConfigFile::Optional(dir.join(file))) as Box<dyn ConfigSource>)
+        });
 
-        Ok(self)
+        for source in platform.into_iter().chain(self.config_sources()) {
+            let name = source.name();
+            if let Some(layer) = source
+                .load()
+                .inspect_err(|_| error!("config::load({name})"))
+                .with_context(|| format!("failed to process {name}"))?
+            {
+                info!("config::load({name})");
+                config::merge(&mut tree, layer);
+            }
+        }
+
+        config::merge(
+            &mut tree,
+            config::env_layer(
+                self.config_env_prefix().as_deref(),
+                &self.config_env_separator(),
+            ),
+        );
+
+        config::from_tree(tree)
+    }
+}
+impl<T: ConfigParserConfig> ConfigParser for T {}
+
+/// tokio runtime configuration (w/ the `tokio` [feature](crate#feature-flags))
+///
+/// The runtime an async entrypoint function runs on (i.e. `#[entrypoint::entrypoint(tokio)]`) is built by [`tokio_runtime`].
+/// It's built after setup, so [`dotenv`](DotEnvParserConfig) processing & logging are initialized before any
+/// runtime thread starts (i.e. runtime threads inherit the environment & log subscriber).
+///
+/// The `tokio` feature also enables `shutdown` (i.e. ctrl-c/`SIGTERM` handlers are installed by default; refer to
+/// [`EntrypointConfig::shutdown_signals`]).
+///
+/// Defaults are sourced from environment variables (i.e. dotenv files may set them):
+/// * `TOKIO_WORKER_THREADS` ([`runtime_worker_threads`])
+/// * `TOKIO_THREAD_NAME` ([`runtime_thread_name`])
+/// * `TOKIO_MAX_BLOCKING_THREADS` ([`runtime_max_blocking_threads`])
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// struct Args {
+///     /// number of worker threads
+///     #[arg(long, env = "WORKERS", default_value_t = 4)]
+///     workers: usize,
+/// }
+///
+/// impl RuntimeConfig for Args {
+///     fn runtime_worker_threads(&self) -> Option<usize> {
+///         Some(self.workers)
+///     }
+///
+///     fn runtime_thread_name(&self) -> Option<String> {
+///         Some(String::from("my-app-worker"))
+///     }
+/// }
+///
+/// #[entrypoint::entrypoint(tokio)]
+/// async fn main(args: Args) -> anyhow::Result<()> {
+///     info!("running on {} worker thread(s)", args.workers);
+/// #   Ok(())
+/// }
+/// ```
+/// [`tokio_runtime`]: RuntimeConfig#method.tokio_runtime
+/// [`runtime_worker_threads`]: RuntimeConfig#method.runtime_worker_threads
+/// [`runtime_thread_name`]: RuntimeConfig#method.runtime_thread_name
+/// [`runtime_max_blocking_threads`]: RuntimeConfig#method.runtime_max_blocking_threads
+#[cfg(feature = "tokio")]
+pub trait RuntimeConfig: clap::Parser {
+    /// number of worker threads
+    ///
+    /// Defaults to `TOKIO_WORKER_THREADS`, if set; otherwise, tokio's default (i.e. the number of cores).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn runtime_worker_threads(&self) -> Option<usize> {
+    ///         Some(2)
+    ///     }
+    /// }
+    /// ```
+    fn runtime_worker_threads(&self) -> Option<usize> {
+        env_usize("TOKIO_WORKER_THREADS")
+    }
+
+    /// worker (and blocking) thread name prefix
+    ///
+    /// Defaults to `TOKIO_THREAD_NAME`, if set; otherwise, tokio's default (i.e. `tokio-runtime-worker`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn runtime_thread_name(&self) -> Option<String> {
+    ///         Some(String::from("my-app-worker"))
+    ///     }
+    /// }
+    /// ```
+    fn runtime_thread_name(&self) -> Option<String> {
+        std::env::var("TOKIO_THREAD_NAME")
+            .ok()
+            .filter(|name| !name.is_empty())
+    }
+
+    /// maximum number of blocking (i.e. `spawn_blocking`) threads
+    ///
+    /// Defaults to `TOKIO_MAX_BLOCKING_THREADS`, if set; otherwise, tokio's default (i.e. `512`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn runtime_max_blocking_threads(&self) -> Option<usize> {
+    ///         Some(16)
+    ///     }
+    /// }
+    /// ```
+    fn runtime_max_blocking_threads(&self) -> Option<usize> {
+        env_usize("TOKIO_MAX_BLOCKING_THREADS")
+    }
+
+    /// whether the io driver is enabled (requires tokio's `net`/`process`/`signal` features to matter)
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn runtime_enable_io(&self) -> bool { false }
+    /// }
+    /// ```
+    fn runtime_enable_io(&self) -> bool {
+        true
+    }
+
+    /// whether the time driver is enabled (requires tokio's `time` feature to matter)
+    ///
+    /// Default behavior is enabled ([`true`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn runtime_enable_time(&self) -> bool { false }
+    /// }
+    /// ```
+    fn runtime_enable_time(&self) -> bool {
+        true
+    }
+
+    /// build the (multi-threaded) runtime from the above config
+    ///
+    /// Override this to build a different runtime altogether (e.g. current thread).
+    ///
+    /// # Errors
+    /// * failure building the runtime
+    ///
+    /// # Examples
+    /// ```
+    /// # #[derive(clap::Parser)]
+    /// # struct Args {}
+    /// impl entrypoint::RuntimeConfig for Args {
+    ///     fn tokio_runtime(&self) -> entrypoint::anyhow::Result<entrypoint::tokio::runtime::Runtime> {
+    ///         Ok(entrypoint::tokio::runtime::Builder::new_current_thread()
+    ///             .enable_all()
+    ///             .build()?)
+    ///     }
+    /// }
+    /// ```
+    fn tokio_runtime(&self) -> anyhow::Result<tokio::runtime::Runtime> {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        if let Some(threads) = self.runtime_worker_threads() {
+            builder.worker_threads(threads);
+        }
+        if let Some(name) = self.runtime_thread_name() {
+            builder.thread_name(name);
+        }
+        if let Some(threads) = self.runtime_max_blocking_threads() {
+            builder.max_blocking_threads(threads);
+        }
+        if self.runtime_enable_io() {
+            builder.enable_io();
+        }
+        if self.runtime_enable_time() {
+            builder.enable_time();
+        }
+
+        builder.build().context("failed to build tokio runtime")
+    }
+}
+
+/// environment variable as a (non-zero) count, if set
+///
+/// Invalid values are logged & ignored.
+#[cfg(feature = "tokio")]
+fn env_usize(var: &str) -> Option<usize> {
+    let value = std::env::var(var).ok()?;
+    match value.trim().parse::<std::num::NonZeroUsize>() {
+        Ok(count) => Some(count.get()),
+        Err(e) => {
+            warn!("ignoring invalid {var} ({value:?}): {e}");
+            None
+        }
     }
 }
-impl<T: DotEnvParserConfig> DotEnvParser for T {}
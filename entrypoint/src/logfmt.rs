@@ -0,0 +1,164 @@
+//! [logfmt](https://brandur.org/logfmt) event formatting
+
+use crate::timer::{Timer, ZonedTimer};
+use std::fmt::Write;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::fmt::{
+    format::{FormatEvent, FormatFields, Writer},
+    time::FormatTime,
+    FmtContext, FormattedFields,
+};
+use tracing_subscriber::registry::LookupSpan;
+
+/// [`FormatEvent`] writing one `key=value` line per event
+///
+/// e.g. `ts=2024-01-01T12:34:56.789012Z level=info target=app span=request id=7 msg="hello world"`
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct Logfmt {
+    pub(crate) timer: ZonedTimer,
+    pub(crate) with_target: bool,
+    pub(crate) with_file: bool,
+    pub(crate) with_line_number: bool,
+    pub(crate) with_thread_ids: bool,
+    pub(crate) with_thread_names: bool,
+}
+
+/// write ` key=value`, quoting/escaping the value as needed
+fn pair(writer: &mut impl Write, key: &str, value: &str) -> std::fmt::Result {
+    write!(writer, " {key}=")?;
+
+    if !value.is_empty()
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '=' | '"'))
+    {
+        return writer.write_str(value);
+    }
+
+    writer.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => writer.write_str("\\\"")?,
+            '\\' => writer.write_str("\\\\")?,
+            '\n' => writer.write_str("\\n")?,
+            '\r' => writer.write_str("\\r")?,
+            '\t' => writer.write_str("\\t")?,
+            c => writer.write_char(c)?,
+        }
+    }
+    writer.write_char('"')
+}
+
+/// event field [`Visit`]or writing logfmt pairs (`message` is written as `msg`)
+struct Visitor<'a> {
+    line: &'a mut String,
+    result: std::fmt::Result,
+}
+
+impl Visitor<'_> {
+    fn record(&mut self, field: &Field, value: &str) {
+        let key = match field.name() {
+            "message" => "msg",
+            name => name.strip_prefix("r#").unwrap_or(name),
+        };
+
+        if self.result.is_ok() {
+            self.result = pair(self.line, key, value);
+        }
+    }
+}
+
+impl Visit for Visitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value);
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.record(field, &value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.record(field, &format!("{value:?}"));
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for Logfmt
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> std::fmt::Result {
+        let meta = event.metadata();
+        let mut line = String::new();
+
+        if self.timer.timer != Timer::None {
+            let mut ts = String::new();
+            self.timer.format_time(&mut Writer::new(&mut ts))?;
+            pair(&mut line, "ts", &ts)?;
+        }
+
+        pair(
+            &mut line,
+            "level",
+            &meta.level().as_str().to_ascii_lowercase(),
+        )?;
+
+        if self.with_target {
+            pair(&mut line, "target", meta.target())?;
+        }
+
+        if let (true, Some(file)) = (self.with_file, meta.file()) {
+            pair(&mut line, "file", file)?;
+        }
+
+        if let (true, Some(number)) = (self.with_line_number, meta.line()) {
+            pair(&mut line, "line", &number.to_string())?;
+        }
+
+        if self.with_thread_names {
+            pair(
+                &mut line,
+                "thread",
+                std::thread::current().name().unwrap_or_default(),
+            )?;
+        }
+
+        if self.with_thread_ids {
+            pair(
+                &mut line,
+                "thread_id",
+                &format!("{:?}", std::thread::current().id()),
+            )?;
+        }
+
+        if let Some(scope) = ctx.event_scope() {
+            let mut names = Vec::new();
+
+            for span in scope.from_root() {
+                names.push(span.name());
+
+                if let Some(fields) = span.extensions().get::<FormattedFields<N>>() {
+                    if !fields.is_empty() {
+                        write!(line, " {}", fields.fields)?;
+                    }
+                }
+            }
+
+            pair(&mut line, "span", &names.join(":"))?;
+        }
+
+        let mut visitor = Visitor {
+            line: &mut line,
+            result: Ok(()),
+        };
+        event.record(&mut visitor);
+        visitor.result?;
+
+        writeln!(writer, "{}", line.trim_start())
+    }
+}
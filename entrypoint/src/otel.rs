@@ -0,0 +1,99 @@
+//! OpenTelemetry (OTLP) trace export
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::{EnvResourceDetector, TelemetryResourceDetector};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+
+/// tracer provider installed by [`layer`]; shut down by [`shutdown`]
+static PROVIDER: std::sync::Mutex<Option<SdkTracerProvider>> = std::sync::Mutex::new(None);
+
+/// whether an OTLP endpoint is configured via the standard env vars
+fn configured() -> bool {
+    [
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    ]
+    .iter()
+    .any(|var| env_var(var).is_some())
+}
+
+/// non-empty env var value
+fn env_var(var: &str) -> Option<String> {
+    std::env::var(var).ok().filter(|value| !value.is_empty())
+}
+
+/// exporter [`Resource`]
+///
+/// In order of precedence:
+/// 1. `OTEL_SERVICE_NAME` (i.e. `service.name`)
+/// 2. `OTEL_RESOURCE_ATTRIBUTES` (e.g. `service.version=1.2.3,deployment.environment=prod`)
+/// 3. the `command` name & version (i.e. `service.name` & `service.version`)
+fn resource(command: &clap::Command) -> Resource {
+    let mut fallback = vec![KeyValue::new("service.name", command.get_name().to_owned())];
+    if let Some(version) = command.get_version() {
+        fallback.push(KeyValue::new("service.version", version.to_owned()));
+    }
+
+    // later attributes take precedence
+    let mut resource = Resource::builder_empty()
+        .with_attributes(fallback)
+        .with_detector(Box::new(TelemetryResourceDetector))
+        .with_detector(Box::new(EnvResourceDetector::new()));
+    if let Some(service_name) = env_var("OTEL_SERVICE_NAME") {
+        resource = resource.with_service_name(service_name);
+    }
+
+    resource.build()
+}
+
+/// [`tracing_opentelemetry`] layer exporting spans over OTLP (HTTP)
+///
+/// The exporter is configured from the standard `OTEL_EXPORTER_OTLP_*` env vars.
+/// The resource is described by [`resource`].
+/// Returns [`None`] if no OTLP endpoint is configured.
+pub(crate) fn layer<S>(command: &clap::Command) -> Option<impl tracing_subscriber::Layer<S>>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    if !configured() {
+        return None;
+    }
+
+    let exporter = match opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            eprintln!("OTLP exporter setup failed: {e}");
+            return None;
+        }
+    };
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource(command))
+        .build();
+    let tracer = provider.tracer(command.get_name().to_owned());
+
+    *PROVIDER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(provider);
+
+    Some(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// flush & shut down the tracer provider (if any)
+pub(crate) fn shutdown() {
+    let provider = PROVIDER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+
+    if let Some(provider) = provider {
+        if let Err(e) = provider.shutdown() {
+            eprintln!("OTLP tracer provider shutdown failed: {e}");
+        }
+    }
+}
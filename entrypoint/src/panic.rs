@@ -0,0 +1,50 @@
+//! routing of panics through [`tracing`]
+
+/// install (once) a panic hook logging panics as `ERROR` events
+///
+/// The replaced hook (e.g. the default one, printing to stderr) only runs when no subscriber has been set,
+/// so panics aren't lost. Install this before hook chaining integrations (e.g. Sentry), so they still run.
+pub(crate) fn install() {
+    static INSTALLED: std::sync::Once = std::sync::Once::new();
+
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            if !tracing::dispatcher::has_been_set() {
+                previous(info);
+                return;
+            }
+
+            let message = message(info);
+            let location = location(info);
+            let thread = std::thread::current();
+            let thread = thread.name().unwrap_or("<unnamed>");
+
+            tracing::error!(
+                target: "panic",
+                thread,
+                location,
+                backtrace = %std::backtrace::Backtrace::force_capture(),
+                "thread '{thread}' panicked at {location}: {message}"
+            );
+        }));
+    });
+}
+
+/// the panic message (i.e. a `&str` or `String` payload)
+pub(crate) fn message<'a>(info: &'a std::panic::PanicHookInfo<'_>) -> &'a str {
+    let payload = info.payload();
+
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>")
+}
+
+/// the panic location (i.e. `file:line:column`)
+pub(crate) fn location(info: &std::panic::PanicHookInfo<'_>) -> String {
+    info.location()
+        .map_or_else(|| String::from("<unknown>"), ToString::to_string)
+}
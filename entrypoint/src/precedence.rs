@@ -0,0 +1,76 @@
+//! precedence of CLI args, process environment, dotenv files & defaults
+
+use crate::provenance::EnvOrigin;
+
+/// how each `env =` bound arg was resolved, as parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+static RESOLVED: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+/// precedence of the configuration layers, highest first
+///
+/// Refer to [`DotEnvParserConfig::precedence_policy`](crate::DotEnvParserConfig::precedence_policy).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PrecedencePolicy {
+    /// CLI args > process environment > dotenv files > defaults
+    #[default]
+    CliEnvDotEnv,
+    /// CLI args > dotenv files > process environment > defaults
+    CliDotEnvEnv,
+    /// CLI args > process environment > defaults
+    ///
+    /// Dotenv files still populate the environment, but don't affect args (i.e. `#[arg(env)]` fields).
+    CliEnv,
+}
+
+impl std::fmt::Display for PrecedencePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CliEnvDotEnv => write!(f, "CLI > environment > dotenv > defaults"),
+            Self::CliDotEnvEnv => write!(f, "CLI > dotenv > environment > defaults"),
+            Self::CliEnv => write!(f, "CLI > environment > defaults"),
+        }
+    }
+}
+
+fn resolved() -> std::sync::MutexGuard<'static, Vec<String>> {
+    RESOLVED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// record how each `env =` bound arg of `command` was resolved, from parsed `matches`
+pub(crate) fn set(command: &clap::Command, matches: &clap::ArgMatches) {
+    *resolved() = command
+        .get_arguments()
+        .filter_map(|arg| {
+            let env = arg.get_env()?.to_string_lossy();
+            let source = match matches.value_source(arg.get_id().as_str())? {
+                clap::parser::ValueSource::CommandLine => String::from("command line"),
+                clap::parser::ValueSource::EnvVariable => {
+                    let origin = crate::env_origin(&env).unwrap_or(EnvOrigin::Environment);
+                    format!("{env} ({origin})")
+                }
+                _ => String::from("default"),
+            };
+
+            Some(format!("{} resolved from {source}", arg.get_id()))
+        })
+        .collect();
+}
+
+/// log (at the `DEBUG` level) how each `env =` bound arg was resolved, per `policy`
+pub(crate) fn log(policy: PrecedencePolicy) {
+    tracing::debug!("precedence policy: {policy}");
+    for resolution in resolved().iter() {
+        tracing::debug!("{resolution}");
+    }
+}
+
+/// whether `key` was defined in the process environment before any dotenv processing
+pub(crate) fn is_original(key: &str, isolated: bool) -> bool {
+    if isolated {
+        std::env::var_os(key).is_some() // i.e. never mutated
+    } else {
+        crate::env_origin(key) == Some(EnvOrigin::Environment)
+    }
+}
@@ -0,0 +1,51 @@
+//! [Prometheus](https://prometheus.io) metrics exporter
+
+/// env var w/ the exporter listen address (e.g. `0.0.0.0:9000`); the exporter is disabled unless set
+pub(crate) const ADDR_VAR: &str = "METRICS_ADDR";
+
+/// gauge; seconds spent in setup (i.e. dotenv, logging, etc.) before the entrypoint function ran
+pub(crate) const STARTUP_SECONDS: &str = "entrypoint_startup_seconds";
+
+/// counter; entrypoint function results, labeled `result="ok|error"`
+pub(crate) const RESULT_TOTAL: &str = "entrypoint_result_total";
+
+/// install the global recorder, serving `/metrics` on [`ADDR_VAR`]
+///
+/// Returns whether the exporter is running.
+pub(crate) fn install() -> bool {
+    let Some(addr) = std::env::var(ADDR_VAR).ok().filter(|addr| !addr.is_empty()) else {
+        return false;
+    };
+
+    let result = addr
+        .parse::<std::net::SocketAddr>()
+        .map_err(|e| anyhow::anyhow!("{ADDR_VAR}: {e}"))
+        .and_then(|addr| {
+            metrics_exporter_prometheus::PrometheusBuilder::new()
+                .with_http_listener(addr)
+                .install()
+                .map_err(anyhow::Error::from)
+        });
+
+    match result {
+        Ok(()) => {
+            tracing::info!("serving prometheus metrics on {addr}");
+            true
+        }
+        Err(e) => {
+            tracing::warn!("prometheus metrics unavailable ({e:#})");
+            false
+        }
+    }
+}
+
+/// record the builtin startup metric
+pub(crate) fn startup(started: std::time::Instant) {
+    ::metrics::gauge!(STARTUP_SECONDS).set(started.elapsed().as_secs_f64());
+}
+
+/// record the builtin result metric
+pub(crate) fn result<T>(result: &anyhow::Result<T>) {
+    let result = if result.is_ok() { "ok" } else { "error" };
+    ::metrics::counter!(RESULT_TOTAL, "result" => result).increment(1);
+}
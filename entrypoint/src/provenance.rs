@@ -0,0 +1,111 @@
+//! provenance (i.e. origin) tracking of environment variables
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// origin of each variable, as of the last [`DotEnvParser::process_dotenv_files`](crate::DotEnvParser::process_dotenv_files)
+static ORIGINS: std::sync::Mutex<BTreeMap<String, EnvOrigin>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+/// where an environment variable's value came from
+///
+/// Refer to [`env_origin`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnvOrigin {
+    /// already defined in the environment (i.e. before any dotenv processing)
+    Environment,
+    /// a dotenv file (e.g. `.env`, `.env.{environment}`, or an additional file)
+    File(PathBuf),
+    /// the decrypted `.env.vault` file
+    Vault,
+    /// an [`EnvSource`](crate::EnvSource), by name
+    Source(String),
+    /// the [`DotEnvParserConfig::builtin_dotenv`](crate::DotEnvParserConfig::builtin_dotenv) content
+    Builtin,
+}
+
+impl std::fmt::Display for EnvOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Environment => write!(f, "environment"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Vault => write!(f, ".env.vault"),
+            Self::Source(name) => write!(f, "{name}"),
+            Self::Builtin => write!(f, "builtin"),
+        }
+    }
+}
+
+/// origin of the `key` variable's current value
+///
+/// Origins are recorded during [`dotenv`](crate::DotEnvParserConfig) processing.
+/// [`None`] if the variable isn't set (or was set afterwards, e.g. by the application).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// # struct Args {}
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> anyhow::Result<()> {
+///     if let Some(origin) = entrypoint::env_origin("LOG_LEVEL") {
+///         info!("LOG_LEVEL was set by {origin}");
+///     }
+/// #   Ok(())
+/// }
+/// ```
+#[must_use]
+pub fn env_origin(key: &str) -> Option<EnvOrigin> {
+    origins().get(key).cloned()
+}
+
+/// origins of all (recorded) variables, by key
+///
+/// Refer to [`env_origin`].
+#[must_use]
+pub fn env_origins() -> BTreeMap<String, EnvOrigin> {
+    origins().clone()
+}
+
+fn origins() -> std::sync::MutexGuard<'static, BTreeMap<String, EnvOrigin>> {
+    ORIGINS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// the current environment (as UTF-8 strings)
+fn snapshot() -> BTreeMap<String, String> {
+    std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .collect()
+}
+
+/// sync the recorded origins w/ the environment, before processing
+///
+/// Unset variables are forgotten; unrecorded (i.e. preexisting) variables are attributed to the [`EnvOrigin::Environment`].
+pub(crate) fn init() {
+    let env = snapshot();
+    let mut origins = origins();
+
+    origins.retain(|key, _| env.contains_key(key));
+    for key in env.into_keys() {
+        origins.entry(key).or_insert(EnvOrigin::Environment);
+    }
+}
+
+/// run `process`, attributing any variables it sets/changes to `origin`
+pub(crate) fn track<T>(origin: &EnvOrigin, process: impl FnOnce() -> T) -> T {
+    let before = snapshot();
+    let result = process();
+
+    let mut origins = origins();
+    for (key, value) in snapshot() {
+        if before.get(&key) != Some(&value) {
+            tracing::debug!("{key} set by {origin}");
+            origins.insert(key, origin.clone());
+        }
+    }
+
+    result
+}
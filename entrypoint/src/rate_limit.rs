@@ -0,0 +1,144 @@
+//! per-callsite event rate limiting
+
+use tracing::callsite::Identifier;
+use tracing_subscriber::layer::{Context, Filter};
+
+/// rate limiting window (and summary period)
+const WINDOW: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// target of "suppressed events" summaries; never rate limited
+pub(crate) const TARGET: &str = "entrypoint::rate_limit";
+
+/// event counts for one callsite (of one [`RateLimit`]) in the current window
+struct Window {
+    meta: &'static tracing::Metadata<'static>,
+    start: std::time::Instant,
+    events: u32,
+    suppressed: u64,
+}
+
+impl Window {
+    /// count an event; whether it's under `max` in the current window
+    fn allow(&mut self, now: std::time::Instant, max: u32) -> bool {
+        if now.duration_since(self.start) >= WINDOW {
+            self.start = now;
+            self.events = 0;
+        }
+
+        if self.events < max {
+            self.events += 1;
+            true
+        } else {
+            self.suppressed += 1;
+            false
+        }
+    }
+}
+
+/// windows of every [`RateLimit`], keyed by ([`RateLimit::id`], callsite)
+type Windows = std::collections::HashMap<(usize, Identifier), Window>;
+
+/// see [`Windows`]
+static WINDOWS: std::sync::LazyLock<std::sync::Mutex<Windows>> =
+    std::sync::LazyLock::new(std::sync::Mutex::default);
+
+/// lock [`WINDOWS`], ignoring poisoning
+fn windows() -> std::sync::MutexGuard<'static, Windows> {
+    WINDOWS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// per-layer [`Filter`] allowing at most `max` events per second per callsite
+///
+/// A background thread periodically logs a `WARN` summary of suppressed events (per callsite).
+/// Summaries are logged outside of event dispatch, so they pass through every layer as usual.
+pub(crate) struct RateLimit {
+    id: usize,
+    max: u32,
+}
+
+impl RateLimit {
+    /// allow at most `max` events per second per callsite
+    pub(crate) fn new(max: u32) -> Self {
+        static IDS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        static SUMMARIES: std::sync::Once = std::sync::Once::new();
+
+        SUMMARIES.call_once(|| {
+            let spawned = std::thread::Builder::new()
+                .name(String::from(TARGET))
+                .spawn(|| loop {
+                    std::thread::sleep(WINDOW);
+                    summarize();
+                });
+
+            if let Err(e) = spawned {
+                eprintln!("rate limit summaries unavailable ({e})");
+            }
+        });
+
+        Self {
+            id: IDS.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+            max,
+        }
+    }
+
+    /// whether the event is allowed (i.e. under `max` in the current window)
+    fn allow(&self, meta: &'static tracing::Metadata<'static>) -> bool {
+        let now = std::time::Instant::now();
+
+        windows()
+            .entry((self.id, meta.callsite()))
+            .or_insert(Window {
+                meta,
+                start: now,
+                events: 0,
+                suppressed: 0,
+            })
+            .allow(now, self.max)
+    }
+}
+
+/// log (and reset) suppressed event counts
+///
+/// Counts from several [`RateLimit`]s (i.e. several layers) for the same callsite are reported once.
+fn summarize() {
+    let mut summaries: Vec<(&'static tracing::Metadata<'static>, u64)> = Vec::new();
+
+    for ((_, callsite), window) in windows().iter_mut() {
+        let suppressed = std::mem::take(&mut window.suppressed);
+        if suppressed == 0 {
+            continue;
+        }
+
+        match summaries
+            .iter_mut()
+            .find(|(meta, _)| meta.callsite() == *callsite)
+        {
+            Some((_, max)) => *max = (*max).max(suppressed),
+            None => summaries.push((window.meta, suppressed)),
+        }
+    }
+
+    for (meta, suppressed) in summaries {
+        tracing::warn!(
+            target: TARGET,
+            suppressed,
+            callsite.target = meta.target(),
+            callsite.file = meta.file(),
+            callsite.line = meta.line(),
+            "suppressed {suppressed} events"
+        );
+    }
+}
+
+impl<S: tracing::Subscriber> Filter<S> for RateLimit {
+    fn enabled(&self, _meta: &tracing::Metadata<'_>, _cx: &Context<'_, S>) -> bool {
+        true
+    }
+
+    fn event_enabled(&self, event: &tracing::Event<'_>, _cx: &Context<'_, S>) -> bool {
+        let meta = event.metadata();
+        meta.target() == TARGET || self.allow(meta)
+    }
+}
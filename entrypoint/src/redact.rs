@@ -0,0 +1,135 @@
+//! secret redaction for formatted log output
+
+use std::io::Write;
+use tracing_subscriber::fmt::{writer::BoxMakeWriter, MakeWriter};
+
+/// replacement for redacted values
+const REDACTED: &str = "[REDACTED]";
+
+/// values shorter than this aren't redacted (they'd mangle unrelated output)
+const MIN_LEN: usize = 4;
+
+/// key patterns that are always considered sensitive (e.g. by [`sensitive`])
+const SENSITIVE: [&str; 4] = ["*_SECRET", "*_TOKEN", "*_KEY", "*PASSWORD*"];
+
+/// whether `key` matches the (case-insensitive) `*` wildcard `pattern`
+fn matches(pattern: &str, key: &str) -> bool {
+    let pattern = pattern.to_ascii_uppercase();
+    let key = key.to_ascii_uppercase();
+    let mut parts = pattern.split('*');
+
+    let Some(first) = parts.next() else {
+        return false;
+    };
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts = parts.peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.is_empty()
+}
+
+/// whether `key` matches any of the [`SENSITIVE`] or supplied patterns
+fn sensitive(key: &str, patterns: &[String]) -> bool {
+    SENSITIVE.iter().any(|pattern| matches(pattern, key))
+        || patterns.iter().any(|pattern| matches(pattern, key))
+}
+
+/// `value`, or `[REDACTED]` if `key` is [`sensitive`]
+pub(crate) fn mask<'a>(key: &str, value: &'a str, patterns: &[String]) -> &'a str {
+    if sensitive(key, patterns) {
+        REDACTED
+    } else {
+        value
+    }
+}
+
+/// values of the env vars whose keys match any of the supplied patterns
+pub(crate) fn secrets(patterns: &[String]) -> Vec<String> {
+    let mut secrets: Vec<String> = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .filter(|(key, value)| {
+            value.len() >= MIN_LEN && patterns.iter().any(|pattern| matches(pattern, key))
+        })
+        .map(|(_, value)| value)
+        .collect();
+
+    // longest first, so overlapping secrets are fully redacted
+    secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+    secrets.dedup();
+    secrets
+}
+
+/// wrap the supplied writer, replacing any of the `secrets` w/ `[REDACTED]`
+pub(crate) fn writer(writer: BoxMakeWriter, secrets: Vec<String>) -> BoxMakeWriter {
+    BoxMakeWriter::new(Redacted {
+        writer,
+        secrets: secrets.into(),
+    })
+}
+
+/// [`MakeWriter`] redacting secrets from each write
+struct Redacted {
+    writer: BoxMakeWriter,
+    secrets: std::sync::Arc<[String]>,
+}
+
+impl<'a> MakeWriter<'a> for Redacted {
+    type Writer = RedactedWriter<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactedWriter {
+            writer: self.writer.make_writer(),
+            secrets: &self.secrets,
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        RedactedWriter {
+            writer: self.writer.make_writer_for(meta),
+            secrets: &self.secrets,
+        }
+    }
+}
+
+/// [`Write`]r redacting secrets
+///
+/// Formatted events are written in a single call, so secrets aren't split across writes.
+struct RedactedWriter<'a> {
+    writer: Box<dyn Write + 'a>,
+    secrets: &'a [String],
+}
+
+impl Write for RedactedWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let text = String::from_utf8_lossy(buf);
+        if !self
+            .secrets
+            .iter()
+            .any(|secret| text.contains(secret.as_str()))
+        {
+            return self.writer.write(buf);
+        }
+
+        let redacted = self.secrets.iter().fold(text.into_owned(), |text, secret| {
+            text.replace(secret.as_str(), REDACTED)
+        });
+        self.writer.write_all(redacted.as_bytes())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,140 @@
+//! retry policy for the entrypoint function (i.e. transient startup failures)
+
+use std::time::Duration;
+
+/// when (and how often) to re-run a failed entrypoint function
+///
+/// Refer to [`EntrypointConfig::retry_policy`](crate::EntrypointConfig::retry_policy) & [`Entrypoint::entrypoint_with_retry`](crate::Entrypoint::entrypoint_with_retry).
+/// The delay before each retry grows exponentially (i.e. `backoff * multiplier^(attempt - 1)`), up to `max_backoff`.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new(5)
+///     .backoff(Duration::from_millis(500))
+///     .retry_if(|e| e.downcast_ref::<std::io::Error>().is_some());
+///
+/// assert_eq!(policy.max_attempts(), 5);
+/// assert_eq!(policy.delay(1), Duration::from_millis(500));
+/// assert_eq!(policy.delay(3), Duration::from_secs(2));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+    multiplier: u32,
+    max_backoff: Duration,
+    retryable: fn(&anyhow::Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    /// a single attempt (i.e. no retries)
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl RetryPolicy {
+    /// up to `max_attempts` attempts (in total), retrying any error
+    ///
+    /// Backoff defaults to 1 second, doubling each retry, up to 1 minute.
+    #[must_use]
+    pub const fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: Duration::from_secs(1),
+            multiplier: 2,
+            max_backoff: Duration::from_mins(1),
+            retryable: |_| true,
+        }
+    }
+
+    /// delay before the first retry
+    #[must_use]
+    pub const fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// factor the delay grows by each retry (i.e. `1` is a constant delay)
+    #[must_use]
+    pub const fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// upper bound on the delay
+    #[must_use]
+    pub const fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// only retry errors matching `retryable` (e.g. [downcast](anyhow::Error::downcast_ref) to a transient error)
+    #[must_use]
+    pub const fn retry_if(mut self, retryable: fn(&anyhow::Error) -> bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// maximum attempts (in total)
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// delay after the (failed) `attempt`, before the next (i.e. `1` is the delay before the first retry)
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.multiplier
+            .checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    /// whether `error` should be retried
+    #[must_use]
+    pub fn is_retryable(&self, error: &anyhow::Error) -> bool {
+        (self.retryable)(error)
+    }
+}
+
+/// run `function` (w/ a clone of `args` each attempt) until it succeeds or `policy` gives up
+///
+/// Each failed attempt that will be retried is logged.
+/// With the `shutdown` [feature](crate#feature-flags), no retry is attempted once a shutdown is requested.
+pub(crate) fn run<A: Clone, T>(
+    policy: &RetryPolicy,
+    args: &A,
+    mut function: impl FnMut(A) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let mut attempt = 1;
+
+    loop {
+        match function(args.clone()) {
+            Err(e) if attempt < policy.max_attempts && policy.is_retryable(&e) && !stopping() => {
+                let delay = policy.delay(attempt);
+                tracing::warn!(
+                    "entrypoint function failed (attempt {attempt}/{}): {e:#}; retrying in {delay:?}",
+                    policy.max_attempts
+                );
+
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// whether a shutdown was requested (i.e. don't retry)
+#[cfg(feature = "shutdown")]
+fn stopping() -> bool {
+    crate::shutdown_requested()
+}
+
+#[cfg(not(feature = "shutdown"))]
+const fn stopping() -> bool {
+    false
+}
@@ -0,0 +1,127 @@
+//! in-memory ring buffer of recent (i.e. all, regardless of level) events; dumped on error
+
+use std::io::Write;
+use tracing_subscriber::fmt::{writer::BoxMakeWriter, MakeWriter};
+
+/// ring of the most recently installed [`writer`]; dumped by [`dump`]
+static RING: std::sync::Mutex<Option<std::sync::Arc<Ring>>> = std::sync::Mutex::new(None);
+
+/// last `capacity` formatted events
+struct Ring {
+    capacity: usize,
+    events: std::sync::Mutex<std::collections::VecDeque<Vec<u8>>>,
+    writer: BoxMakeWriter,
+}
+
+impl Ring {
+    fn events(&self) -> std::sync::MutexGuard<'_, std::collections::VecDeque<Vec<u8>>> {
+        self.events
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn push(&self, event: Vec<u8>) {
+        let mut events = self.events();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// write (and discard) buffered events to the main writer
+    fn dump(&self) {
+        let events = std::mem::take(&mut *self.events());
+        if events.is_empty() {
+            return;
+        }
+
+        let mut writer = self.writer.make_writer();
+        let _ = writeln!(writer, "--- last {} buffered event(s) ---", events.len());
+        for event in &events {
+            let _ = writer.write_all(event);
+        }
+        let _ = writeln!(writer, "--- end of buffered event(s) ---");
+    }
+}
+
+/// [`MakeWriter`] buffering each formatted event in the ring
+///
+/// The ring is dumped to `writer` after an `ERROR` event is buffered.
+pub(crate) fn writer(capacity: usize, writer: BoxMakeWriter) -> RingWriter {
+    let ring = std::sync::Arc::new(Ring {
+        capacity: capacity.max(1),
+        events: std::sync::Mutex::default(),
+        writer,
+    });
+
+    *RING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(ring.clone());
+
+    RingWriter(ring)
+}
+
+/// dump the buffered events (if any) to the main writer
+pub(crate) fn dump() {
+    let ring = RING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+
+    if let Some(ring) = ring {
+        ring.dump();
+    }
+}
+
+/// see [`writer`]
+pub(crate) struct RingWriter(std::sync::Arc<Ring>);
+
+impl<'a> MakeWriter<'a> for RingWriter {
+    type Writer = RingEvent<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RingEvent {
+            ring: &self.0,
+            error: false,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        RingEvent {
+            ring: &self.0,
+            error: *meta.level() == tracing::Level::ERROR,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+/// a single formatted event; pushed to the ring on drop
+pub(crate) struct RingEvent<'a> {
+    ring: &'a Ring,
+    error: bool,
+    buffer: Vec<u8>,
+}
+
+impl Write for RingEvent<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for RingEvent<'_> {
+    fn drop(&mut self) {
+        if !self.buffer.is_empty() {
+            self.ring.push(std::mem::take(&mut self.buffer));
+        }
+
+        if self.error {
+            self.ring.dump();
+        }
+    }
+}
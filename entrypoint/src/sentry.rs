@@ -0,0 +1,57 @@
+//! [Sentry](https://sentry.io) error reporting
+
+/// client guard installed by [`layer`]; flushed by [`shutdown`]
+static GUARD: std::sync::Mutex<Option<::sentry::ClientInitGuard>> = std::sync::Mutex::new(None);
+
+/// initialize the Sentry client and return a [`sentry_tracing`](::sentry::integrations::tracing) layer
+///
+/// The client is configured from the `SENTRY_DSN` (and other `SENTRY_*`) env vars.
+/// `release` is used unless `SENTRY_RELEASE` is set.
+/// Returns [`None`] if no DSN is configured.
+pub(crate) fn layer<S>(release: Option<String>) -> Option<impl tracing_subscriber::Layer<S>>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    if std::env::var("SENTRY_DSN").map_or(true, |dsn| dsn.is_empty()) {
+        return None;
+    }
+
+    let guard = ::sentry::init(::sentry::ClientOptions {
+        release: release
+            .filter(|_| std::env::var("SENTRY_RELEASE").is_err())
+            .map(Into::into),
+        ..Default::default()
+    });
+
+    if !guard.is_enabled() {
+        eprintln!("sentry client setup failed; check SENTRY_DSN");
+        return None;
+    }
+
+    *GUARD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(guard);
+
+    Some(::sentry::integrations::tracing::layer())
+}
+
+/// report an error returned from the entrypoint function (if the client is initialized)
+pub(crate) fn capture(error: &anyhow::Error) {
+    if GUARD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+    {
+        ::sentry::integrations::anyhow::capture_anyhow(error);
+    }
+}
+
+/// flush pending events & shut down the client (if any)
+pub(crate) fn shutdown() {
+    let guard = GUARD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+
+    drop(guard);
+}
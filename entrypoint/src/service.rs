@@ -0,0 +1,214 @@
+//! Windows service (i.e. Service Control Manager) integration
+//!
+//! When not started by the Service Control Manager, the process runs as a console app (& all of these are no-ops).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// whether the service is paused (refer to [`service_paused`])
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// whether the Service Control Manager paused the service
+///
+/// Pause/continue requests are accepted (& reported as completed) immediately; poll this from long running loops to
+/// actually pause work. Always [`false`] when running as a console app.
+///
+/// # Examples
+/// ```
+/// # fn work() {}
+/// while !entrypoint::shutdown_requested() {
+///     if entrypoint::service_paused() {
+///         std::thread::sleep(std::time::Duration::from_millis(100));
+///         continue;
+///     }
+///
+///     work();
+/// #   break;
+/// }
+/// ```
+#[must_use]
+pub fn service_paused() -> bool {
+    PAUSED.load(Ordering::SeqCst)
+}
+
+#[cfg(windows)]
+mod scm {
+    use super::{Ordering, PAUSED};
+    use std::time::Duration;
+    use windows_service::service::{
+        ServiceControl, ServiceControlAccept, ServiceExitCode, ServiceState, ServiceStatus,
+        ServiceType,
+    };
+    use windows_service::service_control_handler::{
+        self, ServiceControlHandlerResult, ServiceStatusHandle,
+    };
+
+    /// how long the Service Control Manager should wait for the next status update
+    const WAIT_HINT: Duration = Duration::from_secs(30);
+
+    /// service name (i.e. as passed to the dispatcher)
+    static NAME: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+    /// whether the control handler was registered, sent from [`service_main`] to [`start`]
+    static REGISTERED: std::sync::Mutex<Option<std::sync::mpsc::Sender<bool>>> =
+        std::sync::Mutex::new(None);
+
+    /// status handle, once registered (i.e. running as a service)
+    static STATUS: std::sync::OnceLock<ServiceStatusHandle> = std::sync::OnceLock::new();
+
+    /// connect to the Service Control Manager (in a new thread); whether running as a service
+    pub(super) fn start(name: &str) -> bool {
+        let (registered, wait) = std::sync::mpsc::channel();
+        if let Ok(mut sender) = REGISTERED.lock() {
+            *sender = Some(registered.clone());
+        }
+        let _ = NAME.set(name.to_owned());
+
+        let spawned = std::thread::Builder::new()
+            .name(String::from("entrypoint-windows-service"))
+            .spawn(move || {
+                let name = NAME.get().map_or("", String::as_str);
+                // blocks until the service stops; fails fast when not started by the Service Control Manager
+                if let Err(e) = windows_service::service_dispatcher::start(name, service_main) {
+                    tracing::debug!("not started as a windows service: {e}");
+                    let _ = registered.send(false);
+                }
+            });
+
+        if let Err(e) = spawned {
+            tracing::warn!(
+                "failed to spawn windows service thread ({e}); running as a console app"
+            );
+            return false;
+        }
+
+        wait.recv().unwrap_or(false)
+    }
+
+    /// `ServiceMain` (called by the dispatcher, on its own thread); the service arguments are ignored
+    extern "system" fn service_main(_argc: u32, _argv: *mut *mut u16) {
+        let registered = register()
+            .inspect_err(|e| {
+                tracing::warn!("failed to register windows service control handler: {e}")
+            })
+            .is_ok();
+
+        if let Some(sender) = REGISTERED.lock().ok().and_then(|mut sender| sender.take()) {
+            let _ = sender.send(registered);
+        }
+    }
+
+    /// register the control handler & report `START_PENDING`
+    fn register() -> windows_service::Result<()> {
+        let name = NAME.get().map_or("", String::as_str);
+        let handle = service_control_handler::register(name, |control| match control {
+            ServiceControl::Stop | ServiceControl::Shutdown | ServiceControl::Preshutdown => {
+                report(ServiceState::StopPending, ServiceExitCode::NO_ERROR);
+                crate::shutdown::request(crate::ShutdownReason::ServiceStop);
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Pause => {
+                PAUSED.store(true, Ordering::SeqCst);
+                tracing::info!("windows service paused");
+                report(ServiceState::Paused, ServiceExitCode::NO_ERROR);
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Continue => {
+                PAUSED.store(false, Ordering::SeqCst);
+                tracing::info!("windows service continued");
+                report(ServiceState::Running, ServiceExitCode::NO_ERROR);
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+            _ => ServiceControlHandlerResult::NotImplemented,
+        })?;
+
+        let _ = STATUS.set(handle);
+        tracing::info!("running as windows service {name}");
+        report(ServiceState::StartPending, ServiceExitCode::NO_ERROR);
+        Ok(())
+    }
+
+    /// report `state` to the Service Control Manager, if running as a service
+    pub(super) fn report(state: ServiceState, exit_code: ServiceExitCode) {
+        let Some(handle) = STATUS.get() else {
+            return;
+        };
+
+        let controls_accepted = match state {
+            ServiceState::Running | ServiceState::Paused => {
+                ServiceControlAccept::STOP
+                    | ServiceControlAccept::SHUTDOWN
+                    | ServiceControlAccept::PAUSE_CONTINUE
+            }
+            _ => ServiceControlAccept::empty(),
+        };
+        let pending = matches!(
+            state,
+            ServiceState::StartPending | ServiceState::StopPending
+        );
+
+        let status = ServiceStatus {
+            service_type: ServiceType::OWN_PROCESS,
+            current_state: state,
+            controls_accepted,
+            exit_code,
+            checkpoint: u32::from(pending),
+            wait_hint: if pending { WAIT_HINT } else { Duration::ZERO },
+            process_id: None,
+        };
+
+        if let Err(e) = handle.set_service_status(status) {
+            tracing::warn!("failed to report windows service status ({state:?}): {e}");
+        }
+    }
+}
+
+/// connect to the Service Control Manager as service `name`
+///
+/// Waits for the control handler to be registered (i.e. `START_PENDING` is reported), if started as a service.
+/// Not started by the Service Control Manager (e.g. run from a console), this returns right away.
+#[cfg(windows)]
+pub(crate) fn start(name: &str) {
+    if !scm::start(name) {
+        tracing::debug!("running as a console app");
+    }
+}
+
+/// report `RUNNING` (setup completed); stop, shutdown, & pause/continue are accepted from now on
+#[cfg(windows)]
+pub(crate) fn running() {
+    use windows_service::service::{ServiceExitCode, ServiceState};
+    scm::report(ServiceState::Running, ServiceExitCode::NO_ERROR);
+}
+
+/// report `STOP_PENDING` (i.e. the entrypoint function returned)
+#[cfg(windows)]
+pub(crate) fn stopping() {
+    use windows_service::service::{ServiceExitCode, ServiceState};
+    scm::report(ServiceState::StopPending, ServiceExitCode::NO_ERROR);
+}
+
+/// report `STOPPED`, w/ a service specific exit code of `1` on failure
+#[cfg(windows)]
+pub(crate) fn stopped(success: bool) {
+    use windows_service::service::{ServiceExitCode, ServiceState};
+    let exit_code = if success {
+        ServiceExitCode::NO_ERROR
+    } else {
+        ServiceExitCode::ServiceSpecific(1)
+    };
+    scm::report(ServiceState::Stopped, exit_code);
+}
+
+/// windows services aren't available on this platform (i.e. always a console app)
+#[cfg(not(windows))]
+pub(crate) const fn start(_name: &str) {}
+
+#[cfg(not(windows))]
+pub(crate) const fn running() {}
+
+#[cfg(not(windows))]
+pub(crate) const fn stopping() {}
+
+#[cfg(not(windows))]
+pub(crate) const fn stopped(_success: bool) {}
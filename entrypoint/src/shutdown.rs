@@ -0,0 +1,362 @@
+//! graceful shutdown on `SIGINT` (i.e. ctrl-c) & `SIGTERM`
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// how often the (timed) wait checks for completion
+const POLL: Duration = Duration::from_millis(10);
+
+/// why a shutdown was requested (refer to [`shutdown_reason`])
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShutdownReason {
+    /// `SIGINT` (i.e. ctrl-c)
+    Interrupt,
+    /// `SIGTERM` (e.g. `docker stop`, `kill`, `systemctl stop`)
+    Terminate,
+    /// another signal mapped to [`SignalAction::Shutdown`](crate::SignalAction::Shutdown)
+    #[cfg(feature = "signals")]
+    Signal(crate::Signal),
+    /// stop/shutdown request from the Windows Service Control Manager
+    #[cfg(feature = "windows-service")]
+    ServiceStop,
+}
+
+impl ShutdownReason {
+    /// conventional exit code (i.e. `128 + signal`) used when the shutdown times out
+    #[must_use]
+    pub const fn exit_code(self) -> i32 {
+        match self {
+            Self::Interrupt => 130,
+            Self::Terminate => 143,
+            #[cfg(feature = "signals")]
+            Self::Signal(signal) => signal.exit_code(),
+            #[cfg(feature = "windows-service")]
+            Self::ServiceStop => 143, // i.e. as SIGTERM
+        }
+    }
+}
+
+impl std::fmt::Display for ShutdownReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interrupt => write!(f, "SIGINT (ctrl-c)"),
+            Self::Terminate => write!(f, "SIGTERM"),
+            #[cfg(feature = "signals")]
+            Self::Signal(signal) => write!(f, "{signal}"),
+            #[cfg(feature = "windows-service")]
+            Self::ServiceStop => write!(f, "windows service stop"),
+        }
+    }
+}
+
+#[cfg(feature = "signals")]
+impl From<crate::Signal> for ShutdownReason {
+    fn from(signal: crate::Signal) -> Self {
+        match signal {
+            crate::Signal::Interrupt => Self::Interrupt,
+            crate::Signal::Terminate => Self::Terminate,
+            signal => Self::Signal(signal),
+        }
+    }
+}
+
+/// first shutdown signal received, if any
+static REASON: std::sync::OnceLock<ShutdownReason> = std::sync::OnceLock::new();
+
+/// whether the entrypoint function has returned
+static DONE: AtomicBool = AtomicBool::new(false);
+
+/// wakes [`ShutdownToken::wait`]ers once a shutdown is [requested](request)
+static REQUESTED: (std::sync::Mutex<()>, std::sync::Condvar) =
+    (std::sync::Mutex::new(()), std::sync::Condvar::new());
+
+/// cancelled once a shutdown is [requested](request) (refer to [`cancellation_token`])
+#[cfg(feature = "tokio")]
+static CANCELLATION: std::sync::OnceLock<tokio_util::sync::CancellationToken> =
+    std::sync::OnceLock::new();
+
+/// hook run on the first shutdown [request](request) (i.e. [`EntrypointConfig::on_shutdown`](crate::EntrypointConfig::on_shutdown))
+type Hook = Box<dyn FnOnce() + Send>;
+
+/// [`Hook`] (taken by the first request) & timeout, once configured
+static HOOK: std::sync::OnceLock<(std::sync::Mutex<Option<Hook>>, Duration)> =
+    std::sync::OnceLock::new();
+
+/// whether a shutdown has been requested (i.e. a `SIGINT`/`SIGTERM` was received)
+///
+/// Poll this from long running loops to stop gracefully (refer to [`EntrypointConfig::shutdown_signals`](crate::EntrypointConfig::shutdown_signals)).
+///
+/// # Examples
+/// ```
+/// # fn work() {}
+/// while !entrypoint::shutdown_requested() {
+///     work();
+/// #   break;
+/// }
+/// ```
+#[must_use]
+pub fn shutdown_requested() -> bool {
+    REASON.get().is_some()
+}
+
+/// why a shutdown was requested, if it was (refer to [`shutdown_requested`])
+#[must_use]
+pub fn shutdown_reason() -> Option<ShutdownReason> {
+    REASON.get().copied()
+}
+
+/// a [`CancellationToken`](tokio_util::sync::CancellationToken), cancelled once a shutdown is requested (i.e. a `SIGINT`/`SIGTERM` was received)
+///
+/// Requires the `tokio` [feature](crate#feature-flags) (which enables `shutdown`). Await [`cancelled`](tokio_util::sync::CancellationToken::cancelled)
+/// (e.g. in a `tokio::select!`, or a server's graceful shutdown future) to stop async tasks gracefully.
+/// Also available as [`Context::cancellation`](crate::Context::cancellation).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// # struct Args {}
+/// # impl RuntimeConfig for Args {}
+/// # async fn serve() -> anyhow::Result<()> { Ok(()) }
+/// #[entrypoint::entrypoint(tokio)]
+/// async fn main(args: Args) -> anyhow::Result<()> {
+///     let cancelled = entrypoint::cancellation_token();
+///
+///     tokio::select! {
+///         result = serve() => result,
+///         () = cancelled.cancelled() => {
+///             info!("shutting down");
+///             Ok(())
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+#[must_use]
+pub fn cancellation_token() -> tokio_util::sync::CancellationToken {
+    CANCELLATION.get_or_init(Default::default).clone()
+}
+
+/// handle to observe (or wait for) a shutdown request
+///
+/// Refer to [`shutdown_requested`] & [`Context::shutdown`](crate::Context::shutdown).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # use std::time::Duration;
+/// let shutdown = ShutdownToken::default();
+///
+/// // e.g. in a worker thread
+/// while shutdown.wait_timeout(Duration::from_millis(10)).is_none() {
+///     // periodic work
+/// #   break;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ShutdownToken {
+    _private: (),
+}
+
+impl ShutdownToken {
+    /// whether a shutdown has been requested (refer to [`shutdown_requested`])
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        shutdown_requested()
+    }
+
+    /// why a shutdown was requested, if it was (refer to [`shutdown_reason`])
+    #[must_use]
+    pub fn reason(&self) -> Option<ShutdownReason> {
+        shutdown_reason()
+    }
+
+    /// block until a shutdown is requested
+    pub fn wait(&self) -> ShutdownReason {
+        let (lock, requested) = &REQUESTED;
+        let mut guard = lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        loop {
+            if let Some(reason) = shutdown_reason() {
+                return reason;
+            }
+            guard = requested
+                .wait(guard)
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+    }
+
+    /// block until a shutdown is requested, or the `timeout` elapses (i.e. [`None`])
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<ShutdownReason> {
+        let (lock, requested) = &REQUESTED;
+        let _guard = requested
+            .wait_timeout_while(
+                lock.lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+                timeout,
+                |()| REASON.get().is_none(),
+            )
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        shutdown_reason()
+    }
+}
+
+/// set the `hook` run (& the `timeout` allowed) once a shutdown is [requested](request)
+pub(crate) fn configure(hook: impl FnOnce() + Send + 'static, timeout: Duration) {
+    let _ = HOOK.set((std::sync::Mutex::new(Some(Box::new(hook))), timeout));
+}
+
+/// listen for `SIGINT`/`SIGTERM`, [requesting](request) a shutdown on either
+///
+/// On windows, only ctrl-c is available. With the `signals` [feature](crate#feature-flags), the signals are
+/// mapped to [`SignalAction::Shutdown`](crate::SignalAction::Shutdown) instead.
+pub(crate) fn listen() {
+    #[cfg(feature = "signals")]
+    crate::signals::install(
+        crate::SignalConfig::new()
+            .on(crate::Signal::Interrupt, crate::SignalAction::Shutdown)
+            .on(crate::Signal::Terminate, crate::SignalAction::Shutdown),
+    );
+
+    #[cfg(all(unix, not(feature = "signals")))]
+    {
+        use signal_hook::consts::{SIGINT, SIGTERM};
+
+        let mut signals = match signal_hook::iterator::Signals::new([SIGINT, SIGTERM]) {
+            Ok(signals) => signals,
+            Err(e) => {
+                tracing::warn!("failed to register shutdown signal handlers: {e}");
+                return;
+            }
+        };
+
+        let spawned = std::thread::Builder::new()
+            .name(String::from("entrypoint-shutdown"))
+            .spawn(move || {
+                for signal in signals.forever() {
+                    request(if signal == SIGTERM {
+                        ShutdownReason::Terminate
+                    } else {
+                        ShutdownReason::Interrupt
+                    });
+                }
+            });
+
+        if let Err(e) = spawned {
+            tracing::warn!("failed to spawn shutdown signal thread: {e}");
+        }
+    }
+
+    #[cfg(all(windows, not(feature = "signals")))]
+    {
+        // the windows CRT only offers signal handlers, so the flag is polled
+        let interrupted = std::sync::Arc::new(AtomicBool::new(false));
+        if let Err(e) = signal_hook::flag::register(
+            signal_hook::consts::SIGINT,
+            std::sync::Arc::clone(&interrupted),
+        ) {
+            tracing::warn!("failed to register the ctrl-c handler: {e}");
+            return;
+        }
+
+        let spawned = std::thread::Builder::new()
+            .name(String::from("entrypoint-shutdown"))
+            .spawn(move || loop {
+                if interrupted.swap(false, Ordering::SeqCst) {
+                    request(ShutdownReason::Interrupt);
+                }
+
+                std::thread::sleep(POLL);
+            });
+
+        if let Err(e) = spawned {
+            tracing::warn!("failed to spawn shutdown signal thread: {e}");
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    tracing::warn!("shutdown signal handling is unavailable on this platform");
+}
+
+/// start a graceful shutdown
+///
+/// The reason is logged & the configured hook is run (in a new thread).
+/// The entrypoint function then has until the timeout to return (refer to [`complete`]).
+/// The process exits early on timeout, on a second request, or if the function already returned.
+pub(crate) fn request(reason: ShutdownReason) {
+    // only the first request sets the reason (i.e. concurrent requests can't both proceed)
+    if REASON.set(reason).is_err() {
+        tracing::warn!("received {reason} during shutdown; exiting now");
+        exit(shutdown_reason().unwrap_or(reason));
+    }
+    {
+        // w/ the lock held, so waiters can't miss the wakeup
+        let (lock, requested) = &REQUESTED;
+        let _guard = lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        requested.notify_all();
+    }
+    #[cfg(feature = "tokio")]
+    cancellation_token().cancel();
+    #[cfg(feature = "systemd")]
+    crate::systemd::stopping();
+
+    if DONE.load(Ordering::SeqCst) {
+        // already returned; nothing to wait for
+        exit(reason);
+    }
+
+    let (hook, timeout) = HOOK
+        .get()
+        .map_or((None, Duration::from_secs(10)), |(hook, timeout)| {
+            (
+                hook.lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .take(),
+                *timeout,
+            )
+        });
+    tracing::info!("received {reason}; shutting down (timeout: {timeout:?})");
+
+    let spawned = std::thread::Builder::new()
+        .name(String::from("entrypoint-shutdown"))
+        .spawn(move || {
+            if let Some(hook) = hook {
+                hook();
+            }
+
+            let deadline = std::time::Instant::now() + timeout;
+            while !DONE.load(Ordering::SeqCst) {
+                if std::time::Instant::now() >= deadline {
+                    tracing::warn!("shutdown timed out after {timeout:?}; exiting now");
+                    exit(reason);
+                }
+
+                std::thread::sleep(POLL);
+            }
+        });
+
+    if let Err(e) = spawned {
+        tracing::warn!("failed to spawn shutdown thread ({e}); exiting now");
+        exit(reason);
+    }
+}
+
+/// record that the entrypoint function returned (i.e. the shutdown, if any, completed in time)
+pub(crate) fn complete() {
+    DONE.store(true, Ordering::SeqCst);
+
+    if let Some(reason) = shutdown_reason() {
+        tracing::info!("shutdown complete ({reason})");
+    }
+}
+
+/// flush output & exit w/ the [`ShutdownReason::exit_code`]
+fn exit(reason: ShutdownReason) -> ! {
+    drop(crate::FlushGuard::new());
+    std::process::exit(reason.exit_code());
+}
@@ -0,0 +1,371 @@
+//! signal handling (i.e. mapping signals to actions)
+
+use tracing_subscriber::filter::LevelFilter;
+
+/// signals that can be mapped to a [`SignalAction`] (refer to [`SignalConfig`])
+///
+/// On unix, all but `CtrlBreak` are available; on windows, only `Interrupt` & `CtrlBreak` are.
+/// Unavailable signals are skipped (w/ a debug event).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// `SIGINT` (i.e. ctrl-c)
+    Interrupt,
+    /// `SIGTERM`
+    Terminate,
+    /// `SIGHUP`
+    Hangup,
+    /// `SIGUSR1`
+    User1,
+    /// `SIGUSR2`
+    User2,
+    /// windows ctrl-break
+    CtrlBreak,
+}
+
+impl Signal {
+    /// conventional exit code (i.e. `128 + signal`) when terminated by this signal
+    #[must_use]
+    pub const fn exit_code(self) -> i32 {
+        128 + match self.number() {
+            Some(number) => number,
+            None => 2, // i.e. SIGINT
+        }
+    }
+
+    /// platform signal number, if available
+    #[cfg(unix)]
+    const fn number(self) -> Option<i32> {
+        use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM, SIGUSR1, SIGUSR2};
+
+        match self {
+            Self::Interrupt => Some(SIGINT),
+            Self::Terminate => Some(SIGTERM),
+            Self::Hangup => Some(SIGHUP),
+            Self::User1 => Some(SIGUSR1),
+            Self::User2 => Some(SIGUSR2),
+            Self::CtrlBreak => None,
+        }
+    }
+
+    #[cfg(not(unix))]
+    const fn number(self) -> Option<i32> {
+        match self {
+            Self::Interrupt => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interrupt => write!(f, "SIGINT"),
+            Self::Terminate => write!(f, "SIGTERM"),
+            Self::Hangup => write!(f, "SIGHUP"),
+            Self::User1 => write!(f, "SIGUSR1"),
+            Self::User2 => write!(f, "SIGUSR2"),
+            Self::CtrlBreak => write!(f, "CTRL_BREAK"),
+        }
+    }
+}
+
+/// what to do when a [`Signal`] is received (refer to [`SignalConfig`])
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug)]
+pub enum SignalAction {
+    /// start a graceful shutdown (refer to [`EntrypointConfig::shutdown_signals`](crate::EntrypointConfig::shutdown_signals))
+    #[cfg(feature = "shutdown")]
+    Shutdown,
+    /// re-process the dotenv files from scratch (refer to [`DotEnvParserConfig::dotenv_on_reload`](crate::DotEnvParserConfig::dotenv_on_reload))
+    ReloadConfig,
+    /// re-open the [`LoggerConfig::default_log_file`](crate::LoggerConfig::default_log_file) (e.g. after `logrotate` moves it)
+    ReopenLogs,
+    /// raise the log level one step (e.g. `INFO` -> `DEBUG`)
+    RaiseLogLevel,
+    /// lower the log level one step (e.g. `INFO` -> `WARN`)
+    LowerLogLevel,
+    /// restore the [`LoggerConfig::default_env_filter`](crate::LoggerConfig::default_env_filter)
+    /// (or [`LoggerConfig::default_log_level`](crate::LoggerConfig::default_log_level))
+    ResetLogLevel,
+    /// run the supplied function (on the signal handling thread)
+    Callback(fn(Signal)),
+}
+
+/// mapping of [`Signal`]s to [`SignalAction`]s
+///
+/// Refer to [`EntrypointConfig::signal_config`](crate::EntrypointConfig::signal_config).
+/// A signal may be mapped to several actions; they're run in the order mapped.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// let config = SignalConfig::new()
+///     .on(Signal::Hangup, SignalAction::ReloadConfig)
+///     .on(Signal::Hangup, SignalAction::ReopenLogs)
+///     .on(Signal::User1, SignalAction::Callback(|signal| info!("received {signal}")));
+///
+/// assert_eq!(config.signals(), [Signal::Hangup, Signal::User1]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SignalConfig {
+    actions: Vec<(Signal, SignalAction)>,
+}
+
+impl SignalConfig {
+    /// empty config (i.e. no signals handled)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// map `signal` to (an additional) `action`
+    #[must_use]
+    pub fn on(mut self, signal: Signal, action: SignalAction) -> Self {
+        self.actions.push((signal, action));
+        self
+    }
+
+    /// mapped signals & actions, in the order mapped
+    #[must_use]
+    pub fn actions(&self) -> &[(Signal, SignalAction)] {
+        &self.actions
+    }
+
+    /// mapped signals (deduplicated), in the order first mapped
+    #[must_use]
+    pub fn signals(&self) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        for (signal, _) in &self.actions {
+            if !signals.contains(signal) {
+                signals.push(*signal);
+            }
+        }
+        signals
+    }
+}
+
+/// every installed mapping
+static ACTIONS: std::sync::Mutex<Vec<(Signal, SignalAction)>> = std::sync::Mutex::new(Vec::new());
+
+/// signals w/ a listener
+static LISTENING: std::sync::Mutex<Vec<Signal>> = std::sync::Mutex::new(Vec::new());
+
+/// [`SignalAction::ReloadConfig`] implementation, once configured
+static RELOAD: std::sync::OnceLock<fn()> = std::sync::OnceLock::new();
+
+/// [`SignalAction::ResetLogLevel`] directives
+static DEFAULT_FILTER: std::sync::Mutex<Option<String>> = std::sync::Mutex::new(None);
+
+/// set the [`SignalAction::ReloadConfig`] implementation
+pub(crate) fn configure(reload: fn()) {
+    let _ = RELOAD.set(reload);
+}
+
+/// set the directives restored by [`SignalAction::ResetLogLevel`]
+pub(crate) fn default_filter(filter: String) {
+    *DEFAULT_FILTER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(filter);
+}
+
+/// add the supplied mapping, listening for any newly mapped signals
+///
+/// Each signal is listened for once; a received signal runs every action mapped to it (across installs).
+pub(crate) fn install(config: SignalConfig) {
+    let signals: Vec<Signal> = {
+        let mut listening = LISTENING
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let signals = config
+            .signals()
+            .into_iter()
+            .filter(|signal| !listening.contains(signal))
+            .collect::<Vec<_>>();
+        listening.extend(&signals);
+        signals
+    };
+
+    ACTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .extend(config.actions);
+
+    if !signals.is_empty() {
+        listen(signals);
+    }
+}
+
+/// run every action mapped to the received `signal`
+fn dispatch(signal: Signal) {
+    let actions: Vec<SignalAction> = ACTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+        .filter(|(mapped, _)| *mapped == signal)
+        .map(|(_, action)| *action)
+        .collect();
+
+    #[cfg(feature = "shutdown")]
+    let mut shutdown = false;
+    for action in actions {
+        tracing::debug!("{signal}: {action:?}");
+
+        match action {
+            // once, even if mapped more than once (a second request exits immediately)
+            #[cfg(feature = "shutdown")]
+            SignalAction::Shutdown if !shutdown => {
+                shutdown = true;
+                crate::shutdown::request(signal.into());
+            }
+            #[cfg(feature = "shutdown")]
+            SignalAction::Shutdown => {}
+            SignalAction::ReloadConfig => {
+                if let Some(reload) = RELOAD.get() {
+                    reload();
+                } else {
+                    tracing::warn!("{signal}: dotenv reload unavailable");
+                }
+            }
+            SignalAction::ReopenLogs => match crate::writer::reopen_log_files() {
+                Ok(count) => tracing::info!("{signal}: re-opened {count} log file(s)"),
+                Err(e) => tracing::warn!("{signal}: failed to re-open log file(s): {e:#}"),
+            },
+            SignalAction::RaiseLogLevel => set_level(|level| crate::verbosity::adjust(level, 1)),
+            SignalAction::LowerLogLevel => set_level(|level| crate::verbosity::adjust(level, -1)),
+            SignalAction::ResetLogLevel => {
+                let filter = DEFAULT_FILTER
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .clone();
+                if let (Some(handle), Some(filter)) = (crate::logger_handle(), filter) {
+                    log_level(handle.set_filter(&filter), &handle);
+                }
+            }
+            SignalAction::Callback(callback) => callback(signal),
+        }
+    }
+}
+
+/// adjust the [`logger_handle`](crate::logger_handle) level
+fn set_level(adjust: impl FnOnce(LevelFilter) -> LevelFilter) {
+    if let Some(handle) = crate::logger_handle() {
+        let level = handle.level().unwrap_or(LevelFilter::OFF);
+        log_level(handle.set_level(adjust(level)), &handle);
+    }
+}
+
+/// log the outcome of a level change
+fn log_level(result: anyhow::Result<()>, handle: &crate::LoggerHandle) {
+    match result {
+        Ok(()) => tracing::info!("log level: {}", handle.level().unwrap_or(LevelFilter::OFF)),
+        Err(e) => tracing::warn!("failed to change log level: {e}"),
+    }
+}
+
+/// listen for the supplied signals (i.e. [`dispatch`] them)
+#[cfg(unix)]
+fn listen(signals: Vec<Signal>) {
+    let (signals, unavailable): (Vec<_>, Vec<_>) = signals
+        .into_iter()
+        .partition(|signal| signal.number().is_some());
+    for signal in unavailable {
+        tracing::debug!("{signal} is unavailable on this platform");
+    }
+
+    let numbers: Vec<i32> = signals
+        .iter()
+        .filter_map(|signal| signal.number())
+        .collect();
+    let mut iterator = match signal_hook::iterator::Signals::new(&numbers) {
+        Ok(iterator) => iterator,
+        Err(e) => {
+            tracing::warn!("failed to register signal handlers: {e}");
+            return;
+        }
+    };
+
+    let spawned = std::thread::Builder::new()
+        .name(String::from("entrypoint-signals"))
+        .spawn(move || {
+            for number in iterator.forever() {
+                if let Some(signal) = signals
+                    .iter()
+                    .find(|signal| signal.number() == Some(number))
+                {
+                    dispatch(*signal);
+                }
+            }
+        });
+
+    if let Err(e) = spawned {
+        tracing::warn!("failed to spawn signal thread: {e}");
+    }
+}
+
+/// `SIGBREAK` (i.e. ctrl-break) in the windows CRT, which signal-hook doesn't define
+#[cfg(windows)]
+const SIGBREAK: std::ffi::c_int = 21;
+
+/// how often the (windows) signal flags are checked
+#[cfg(windows)]
+const POLL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// listen for the supplied signals (i.e. [`dispatch`] them)
+///
+/// The windows CRT only offers signal handlers, so a flag is registered for each signal & polled.
+#[cfg(windows)]
+fn listen(signals: Vec<Signal>) {
+    let flags: Vec<_> = signals
+        .into_iter()
+        .filter_map(|signal| {
+            let number = match signal {
+                Signal::Interrupt => signal_hook::consts::SIGINT,
+                Signal::CtrlBreak => SIGBREAK,
+                _ => {
+                    tracing::debug!("{signal} is unavailable on this platform");
+                    return None;
+                }
+            };
+
+            let flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+            match signal_hook::flag::register(number, std::sync::Arc::clone(&flag)) {
+                Ok(_) => Some((signal, flag)),
+                Err(e) => {
+                    tracing::warn!("failed to listen for {signal}: {e}");
+                    None
+                }
+            }
+        })
+        .collect();
+
+    if flags.is_empty() {
+        return;
+    }
+
+    let spawned = std::thread::Builder::new()
+        .name(String::from("entrypoint-signals"))
+        .spawn(move || loop {
+            for (signal, flag) in &flags {
+                if flag.swap(false, std::sync::atomic::Ordering::SeqCst) {
+                    dispatch(*signal);
+                }
+            }
+
+            std::thread::sleep(POLL);
+        });
+
+    if let Err(e) = spawned {
+        tracing::warn!("failed to spawn signal thread: {e}");
+    }
+}
+
+/// signals aren't available on this platform
+#[cfg(not(any(unix, windows)))]
+fn listen(signals: Vec<Signal>) {
+    for signal in signals {
+        tracing::debug!("{signal} is unavailable on this platform");
+    }
+}
@@ -0,0 +1,81 @@
+//! [`tracing_error`] span traces for [`anyhow`] errors
+
+use tracing_error::SpanTrace;
+
+/// error wrapper carrying the [`SpanTrace`] captured where it was created
+///
+/// Displays (and chains) exactly like the wrapped error.
+struct SpanTraced {
+    error: anyhow::Error,
+    span_trace: SpanTrace,
+}
+
+impl std::fmt::Display for SpanTraced {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::fmt::Debug for SpanTraced {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for SpanTraced {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// capture the current [`SpanTrace`] alongside an error
+///
+/// Requires the [`tracing_error::ErrorLayer`] (refer to [`LoggerConfig::default_log_span_trace`](crate::LoggerConfig::default_log_span_trace)).
+/// [`Entrypoint::entrypoint`](crate::Entrypoint::entrypoint) logs the captured span trace of a returned error.
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[instrument]
+/// fn load(path: &str) -> anyhow::Result<String> {
+///     std::fs::read_to_string(path).with_span_trace()
+/// }
+///
+/// let error = load("/does/not/exist").unwrap_err();
+/// assert!(entrypoint::span_trace(&error).is_some());
+/// ```
+pub trait SpanTraceExt<T> {
+    /// convert the error to an [`anyhow::Error`], capturing the current [`SpanTrace`] (if not already captured)
+    ///
+    /// The wrapped error displays/chains identically, though it can no longer be
+    /// [downcast](anyhow::Error::downcast_ref) to its original type.
+    ///
+    /// # Errors
+    /// * `self` is an error
+    fn with_span_trace(self) -> anyhow::Result<T>;
+}
+
+impl<T, E: Into<anyhow::Error>> SpanTraceExt<T> for Result<T, E> {
+    fn with_span_trace(self) -> anyhow::Result<T> {
+        self.map_err(|error| {
+            let error = error.into();
+            if span_trace(&error).is_some() {
+                return error;
+            }
+
+            anyhow::Error::new(SpanTraced {
+                error,
+                span_trace: SpanTrace::capture(),
+            })
+        })
+    }
+}
+
+/// the [`SpanTrace`] captured via [`SpanTraceExt::with_span_trace`] (if any)
+#[must_use]
+pub fn span_trace(error: &anyhow::Error) -> Option<&SpanTrace> {
+    error
+        .chain()
+        .find_map(|error| error.downcast_ref::<SpanTraced>())
+        .map(|traced| &traced.span_trace)
+}
@@ -0,0 +1,149 @@
+//! buffering of startup (i.e. pre-[`log_init`](crate::Logger::log_init)) events
+
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+/// most fields a [`tracing`] event can have
+const MAX_FIELDS: usize = 32;
+
+/// recorded field value (keeps the original type, where possible)
+enum Value {
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Bool(bool),
+    Str(String),
+    /// [`Debug`](std::fmt::Debug) formatted value; replayed as-is
+    Debug(tracing::field::DisplayValue<String>),
+}
+
+impl Value {
+    /// as a [`tracing::Value`]
+    fn as_value(&self) -> &dyn tracing::Value {
+        match self {
+            Self::I64(value) => value,
+            Self::U64(value) => value,
+            Self::F64(value) => value,
+            Self::Bool(value) => value,
+            Self::Str(value) => value,
+            Self::Debug(value) => value,
+        }
+    }
+}
+
+/// recorded event
+struct Recorded {
+    meta: &'static tracing::Metadata<'static>,
+    fields: Vec<(Field, Value)>,
+}
+
+impl Visit for Recorded {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.push((field.clone(), Value::I64(value)));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.push((field.clone(), Value::U64(value)));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.fields.push((field.clone(), Value::F64(value)));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.push((field.clone(), Value::Bool(value)));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields
+            .push((field.clone(), Value::Str(value.to_owned())));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields.push((
+            field.clone(),
+            Value::Debug(tracing::field::display(format!("{value:?}"))),
+        ));
+    }
+}
+
+impl Recorded {
+    /// dispatch (again) to the current subscriber, as a root event
+    ///
+    /// Events are re-timestamped by the subscriber.
+    fn replay(&self) {
+        let Some((first, _)) = self.fields.first() else {
+            return;
+        };
+
+        // unused slots are skipped (i.e. `None` values aren't recorded)
+        let mut values: [(&Field, Option<&dyn tracing::Value>); MAX_FIELDS] =
+            [(first, None); MAX_FIELDS];
+        for (slot, (field, value)) in values.iter_mut().zip(&self.fields) {
+            *slot = (field, Some(value.as_value()));
+        }
+        let values = self.meta.fields().value_set(&values);
+
+        tracing::dispatcher::get_default(|dispatch| {
+            if dispatch.enabled(self.meta) {
+                dispatch.event(&tracing::Event::new_child_of(None, self.meta, &values));
+            }
+        });
+    }
+}
+
+/// [`tracing_subscriber::Layer`] recording every event, to be [replayed](Buffer::replay) later
+///
+/// Clones share the same buffer.
+#[derive(Clone, Default)]
+pub(crate) struct Buffer {
+    events: std::sync::Arc<std::sync::Mutex<Vec<Recorded>>>,
+}
+
+impl Buffer {
+    /// replay (and discard) buffered events
+    ///
+    /// Events are replayed through the global subscriber if one has been set
+    /// (i.e. the real layer stack), otherwise to stderr (e.g. setup failed before [`log_init`](crate::Logger::log_init)).
+    pub(crate) fn replay(&self) {
+        // the buffering subscriber (which enabled everything) is gone; recompute the max level
+        tracing::callsite::rebuild_interest_cache();
+
+        let events = std::mem::take(
+            &mut *self
+                .events
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+
+        if tracing::dispatcher::has_been_set() {
+            events.iter().for_each(Recorded::replay);
+            return;
+        }
+
+        let fallback = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::Layer::default()
+                .with_writer(std::io::stderr)
+                .with_filter(tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL),
+        );
+        tracing::subscriber::with_default(fallback, || {
+            events.iter().for_each(Recorded::replay);
+        });
+    }
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for Buffer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut recorded = Recorded {
+            meta: event.metadata(),
+            fields: Vec::new(),
+        };
+        event.record(&mut recorded);
+
+        self.events
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(recorded);
+    }
+}
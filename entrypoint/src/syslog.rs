@@ -0,0 +1,175 @@
+//! RFC 5424 syslog writer
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// env var w/ the syslog facility (name or code); defaults to `user`
+pub(crate) const FACILITY_VAR: &str = "SYSLOG_FACILITY";
+
+/// env var w/ the syslog app-name; defaults to the [`clap::Command`] name
+pub(crate) const APP_NAME_VAR: &str = "SYSLOG_APP_NAME";
+
+/// where to send syslog messages
+///
+/// Refer to [`LoggerConfig::default_log_syslog`](crate::LoggerConfig::default_log_syslog).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Syslog {
+    /// UDP datagrams to the supplied `host:port` (e.g. `127.0.0.1:514`)
+    Udp(String),
+    /// datagrams to the supplied unix socket (e.g. `/dev/log`)
+    #[cfg(unix)]
+    Unix(std::path::PathBuf),
+}
+
+impl Default for Syslog {
+    /// `/dev/log` on unix, otherwise UDP to `127.0.0.1:514`
+    fn default() -> Self {
+        #[cfg(unix)]
+        return Self::Unix(std::path::PathBuf::from("/dev/log"));
+
+        #[cfg(not(unix))]
+        return Self::Udp(String::from("127.0.0.1:514"));
+    }
+}
+
+#[derive(Debug)]
+enum Socket {
+    Udp(std::net::UdpSocket),
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixDatagram),
+}
+
+impl Socket {
+    fn connect(syslog: &Syslog) -> std::io::Result<Self> {
+        match syslog {
+            Syslog::Udp(addr) => {
+                let socket = std::net::UdpSocket::bind(("0.0.0.0", 0))?;
+                socket.connect(addr)?;
+                Ok(Self::Udp(socket))
+            }
+            #[cfg(unix)]
+            Syslog::Unix(path) => {
+                let socket = std::os::unix::net::UnixDatagram::unbound()?;
+                socket.connect(path)?;
+                Ok(Self::Unix(socket))
+            }
+        }
+    }
+
+    fn send(&self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Udp(socket) => socket.send(buf),
+            #[cfg(unix)]
+            Self::Unix(socket) => socket.send(buf),
+        }
+    }
+}
+
+/// syslog facility code for the supplied name (or numeric code)
+fn facility(name: &str) -> Option<u8> {
+    const NAMES: [&str; 24] = [
+        "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron",
+        "authpriv", "ftp", "ntp", "audit", "alert", "clock", "local0", "local1", "local2",
+        "local3", "local4", "local5", "local6", "local7",
+    ];
+
+    let name = name.trim().to_ascii_lowercase();
+    name.parse()
+        .ok()
+        .or_else(|| NAMES.iter().position(|n| *n == name)?.try_into().ok())
+        .filter(|code| *code < 24)
+}
+
+/// syslog severity code for a [`tracing::Level`]
+const fn severity(level: tracing::Level) -> u8 {
+    match level {
+        tracing::Level::ERROR => 3,
+        tracing::Level::WARN => 4,
+        tracing::Level::INFO => 6,
+        tracing::Level::DEBUG | tracing::Level::TRACE => 7,
+    }
+}
+
+/// [`MakeWriter`] sending one RFC 5424 message per event
+#[derive(Debug)]
+pub(crate) struct SyslogWriter {
+    socket: Socket,
+    facility: u8,
+    header: String,
+}
+
+impl SyslogWriter {
+    /// connect to the supplied [`Syslog`] target
+    ///
+    /// The facility & app-name are read from [`FACILITY_VAR`] & [`APP_NAME_VAR`].
+    pub(crate) fn new(syslog: &Syslog, app_name: &str) -> std::io::Result<Self> {
+        let facility = std::env::var(FACILITY_VAR)
+            .ok()
+            .map_or(Some(1), |name| facility(&name))
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("unknown {FACILITY_VAR}"),
+                )
+            })?;
+
+        let app_name = std::env::var(APP_NAME_VAR).unwrap_or_else(|_| app_name.to_owned());
+        let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| String::from("-"));
+
+        Ok(Self {
+            socket: Socket::connect(syslog)?,
+            facility,
+            // VERSION TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA
+            header: format!("1 - {hostname} {app_name} {} - -", std::process::id()),
+        })
+    }
+
+    /// an empty message w/ the header for the supplied level
+    fn message(&self, level: tracing::Level) -> SyslogMessage<'_> {
+        let pri = self.facility * 8 + severity(level);
+
+        SyslogMessage {
+            writer: self,
+            message: format!("<{pri}>{} ", self.header).into_bytes(),
+        }
+    }
+}
+
+/// a single syslog message; sent when dropped
+pub(crate) struct SyslogMessage<'a> {
+    writer: &'a SyslogWriter,
+    message: Vec<u8>,
+}
+
+impl std::io::Write for SyslogMessage<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.message.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for SyslogMessage<'_> {
+    fn drop(&mut self) {
+        while self.message.last().is_some_and(u8::is_ascii_whitespace) {
+            self.message.pop();
+        }
+
+        // best effort; there's nowhere left to report failures
+        let _ = self.writer.socket.send(&self.message);
+    }
+}
+
+impl<'a> MakeWriter<'a> for SyslogWriter {
+    type Writer = SyslogMessage<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.message(tracing::Level::INFO)
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        self.message(*meta.level())
+    }
+}
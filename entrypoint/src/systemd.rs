@@ -0,0 +1,87 @@
+//! systemd service notifications (i.e. `sd_notify`) for `Type=notify` units
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// whether `STOPPING=1` was sent (i.e. stop pinging the watchdog)
+static STOPPING: AtomicBool = AtomicBool::new(false);
+
+/// send `READY=1` (setup completed); also start pinging the watchdog, if `WATCHDOG_USEC` is set
+///
+/// Does nothing unless run under systemd (i.e. `NOTIFY_SOCKET` is set).
+pub(crate) fn ready() {
+    if !notify("READY=1") {
+        return;
+    }
+    tracing::debug!("systemd: READY=1");
+
+    if let Some(interval) = watchdog_interval() {
+        let spawned = std::thread::Builder::new()
+            .name(String::from("entrypoint-systemd-watchdog"))
+            .spawn(move || {
+                while !STOPPING.load(Ordering::SeqCst) && notify("WATCHDOG=1") {
+                    std::thread::sleep(interval);
+                }
+            });
+
+        if let Err(e) = spawned {
+            tracing::warn!("failed to spawn systemd watchdog thread: {e}");
+        }
+    }
+}
+
+/// send `STOPPING=1` (i.e. shutting down), once
+pub(crate) fn stopping() {
+    if !STOPPING.swap(true, Ordering::SeqCst) && notify("STOPPING=1") {
+        tracing::debug!("systemd: STOPPING=1");
+    }
+}
+
+/// half the `WATCHDOG_USEC` timeout (as recommended), if set for this process
+fn watchdog_interval() -> Option<std::time::Duration> {
+    if let Ok(pid) = std::env::var("WATCHDOG_PID") {
+        if pid.trim().parse::<u32>().ok() != Some(std::process::id()) {
+            return None; // meant for another process
+        }
+    }
+
+    std::env::var("WATCHDOG_USEC")
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|usec| *usec > 0)
+        .map(|usec| std::time::Duration::from_micros(usec / 2))
+}
+
+/// send `state` to the `NOTIFY_SOCKET`; whether it was sent
+#[cfg(unix)]
+fn notify(state: &str) -> bool {
+    let Some(socket) = std::env::var_os("NOTIFY_SOCKET") else {
+        return false;
+    };
+
+    let sent = std::os::unix::net::UnixDatagram::unbound().and_then(|datagram| {
+        match socket.as_encoded_bytes().strip_prefix(b"@") {
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            Some(name) => {
+                #[cfg(target_os = "android")]
+                use std::os::android::net::SocketAddrExt;
+                #[cfg(target_os = "linux")]
+                use std::os::linux::net::SocketAddrExt;
+
+                let address = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+                datagram.send_to_addr(state.as_bytes(), &address)
+            }
+            _ => datagram.send_to(state.as_bytes(), &socket),
+        }
+    });
+
+    sent.inspect_err(|e| tracing::warn!("failed to notify systemd ({state}): {e}"))
+        .is_ok()
+}
+
+/// systemd isn't available on this platform
+#[cfg(not(unix))]
+const fn notify(_state: &str) -> bool {
+    false
+}
@@ -0,0 +1,174 @@
+//! helpers for testing log output
+//!
+//! # Examples
+//! ```
+//! # use entrypoint::prelude::*;
+//! # use entrypoint::testing::CaptureWriter;
+//! #[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug)]
+//! #[log_writer(CaptureWriter::global)]
+//! struct Args {}
+//!
+//! #[entrypoint::entrypoint]
+//! fn main(args: Args) -> entrypoint::anyhow::Result<()> {
+//!     CaptureWriter::global().clear();
+//!
+//!     info!("hello");
+//!     CaptureWriter::global().assert_contains("hello");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// process wide [`CaptureWriter`] (refer to [`CaptureWriter::global`])
+static GLOBAL: std::sync::LazyLock<CaptureWriter> = std::sync::LazyLock::new(CaptureWriter::new);
+
+/// in-memory [`MakeWriter`] capturing formatted log output
+///
+/// Clones share the same buffer, so keep a clone to inspect what was written.
+///
+/// This can be easily set with convenience [`macros`](crate::macros::LoggerDefault#attributes) (i.e. `#[log_writer(entrypoint::testing::CaptureWriter::global)]`).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # use entrypoint::testing::CaptureWriter;
+/// # #[derive(clap::Parser)]
+/// # struct Args {}
+/// impl entrypoint::LoggerConfig for Args {
+///     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+///         CaptureWriter::global()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CaptureWriter {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl CaptureWriter {
+    /// new, empty [`CaptureWriter`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// process wide [`CaptureWriter`]
+    ///
+    /// Usable as a path in `#[log_writer(...)]` (i.e. `#[log_writer(entrypoint::testing::CaptureWriter::global)]`).
+    #[must_use]
+    pub fn global() -> Self {
+        GLOBAL.clone()
+    }
+
+    /// lock the buffer, ignoring poisoning (a panicking test shouldn't hide captured output)
+    fn lock(&self) -> MutexGuard<'_, Vec<u8>> {
+        self.buffer
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// copy of the raw captured bytes
+    #[must_use]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.lock().clone()
+    }
+
+    /// captured output (lossy UTF-8)
+    #[must_use]
+    pub fn snapshot(&self) -> String {
+        String::from_utf8_lossy(&self.lock()).into_owned()
+    }
+
+    /// captured output, one entry per line
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.snapshot().lines().map(String::from).collect()
+    }
+
+    /// whether nothing has been captured
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// discard captured output
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// return captured output and clear it
+    #[must_use]
+    pub fn take(&self) -> String {
+        let bytes = std::mem::take(&mut *self.lock());
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+
+    /// whether any captured line contains `needle`
+    #[must_use]
+    pub fn contains(&self, needle: &str) -> bool {
+        self.lines().iter().any(|line| line.contains(needle))
+    }
+
+    /// assert that some captured line contains `needle`
+    ///
+    /// # Panics
+    /// * no captured line contains `needle`
+    #[track_caller]
+    pub fn assert_contains(&self, needle: &str) {
+        assert!(
+            self.contains(needle),
+            "no captured line contains {needle:?}; captured:\n{}",
+            self.snapshot()
+        );
+    }
+
+    /// assert that no captured line contains `needle`
+    ///
+    /// # Panics
+    /// * a captured line contains `needle`
+    #[track_caller]
+    pub fn assert_not_contains(&self, needle: &str) {
+        assert!(
+            !self.contains(needle),
+            "a captured line contains {needle:?}; captured:\n{}",
+            self.snapshot()
+        );
+    }
+
+    /// assert the exact number of captured lines
+    ///
+    /// # Panics
+    /// * line count doesn't match `count`
+    #[track_caller]
+    pub fn assert_line_count(&self, count: usize) {
+        let lines = self.lines();
+        assert_eq!(
+            lines.len(),
+            count,
+            "unexpected captured line count; captured:\n{}",
+            lines.join("\n")
+        );
+    }
+}
+
+impl std::io::Write for CaptureWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CaptureWriter {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
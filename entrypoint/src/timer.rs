@@ -0,0 +1,101 @@
+//! log event timestamps
+
+use tracing_subscriber::fmt::{format::Writer, time::FormatTime};
+
+/// log event timestamp format
+///
+/// Refer to [`LoggerConfig::default_log_timer`](crate::LoggerConfig::default_log_timer).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Timer {
+    /// RFC 3339 w/ microseconds (e.g. `2024-01-01T12:34:56.789012Z`)
+    #[default]
+    Rfc3339,
+    /// fractional seconds since the unix epoch (e.g. `1704112496.789012`)
+    Epoch,
+    /// no timestamp (e.g. when the log collector adds its own)
+    None,
+    /// custom [`strftime`](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) format
+    Custom(String),
+}
+
+impl Timer {
+    /// format timestamps in `zone`
+    #[must_use]
+    pub const fn with_time_zone(self, zone: TimeZone) -> ZonedTimer {
+        ZonedTimer { timer: self, zone }
+    }
+}
+
+impl FormatTime for Timer {
+    fn format_time(&self, w: &mut Writer<'_>) -> std::fmt::Result {
+        self.clone().with_time_zone(TimeZone::Utc).format_time(w)
+    }
+}
+
+/// log event timestamp time zone
+///
+/// Refer to [`LoggerConfig::default_log_time_zone`](crate::LoggerConfig::default_log_time_zone).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeZone {
+    /// UTC (e.g. `2024-01-01T12:34:56.789012Z`)
+    #[default]
+    Utc,
+    /// the system's local time zone (e.g. `2024-01-01T07:34:56.789012-05:00`)
+    Local,
+    /// fixed offset, in seconds east of UTC (e.g. `Fixed(-5 * 3600)`)
+    ///
+    /// Out of range offsets (i.e. a day or more) fall back to UTC.
+    Fixed(i32),
+}
+
+/// [`Timer`] in a [`TimeZone`]
+///
+/// Refer to [`Timer::with_time_zone`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ZonedTimer {
+    /// timestamp format
+    pub timer: Timer,
+    /// timestamp time zone
+    pub zone: TimeZone,
+}
+
+impl ZonedTimer {
+    /// current time, in [`ZonedTimer::zone`]
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        let now = chrono::Utc::now();
+
+        match self.zone {
+            TimeZone::Utc => now.fixed_offset(),
+            TimeZone::Local => now.with_timezone(&chrono::Local).fixed_offset(),
+            TimeZone::Fixed(offset) => chrono::FixedOffset::east_opt(offset)
+                .map_or_else(|| now.fixed_offset(), |offset| now.with_timezone(&offset)),
+        }
+    }
+}
+
+impl FormatTime for ZonedTimer {
+    fn format_time(&self, w: &mut Writer<'_>) -> std::fmt::Result {
+        match (&self.timer, self.zone) {
+            (Timer::Rfc3339, TimeZone::Utc) => {
+                tracing_subscriber::fmt::time::SystemTime.format_time(w)
+            }
+            (Timer::Rfc3339, _) => write!(
+                w,
+                "{}",
+                self.now()
+                    .to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+            ),
+            (Timer::Epoch, _) => {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_err(|_| std::fmt::Error)?;
+                write!(w, "{}.{:06}", now.as_secs(), now.subsec_micros())
+            }
+            (Timer::None, _) => Ok(()),
+            (Timer::Custom(format), TimeZone::Utc) => {
+                write!(w, "{}", chrono::Utc::now().format(format))
+            }
+            (Timer::Custom(format), _) => write!(w, "{}", self.now().format(format)),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! update notifications for distributed binaries
+
+use crate::prelude::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// minimum time between network checks
+const CHECK_INTERVAL: Duration = Duration::from_hours(24);
+
+/// where to look up the latest released version
+///
+/// Refer to [`EntrypointConfig::update_source`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpdateSource {
+    /// latest stable version of the named crate on [crates.io](https://crates.io)
+    CratesIo(String),
+    /// latest release tag (leading `v` ignored) of a GitHub `owner/repo`
+    GitHubReleases(String),
+}
+
+impl UpdateSource {
+    fn url(&self) -> String {
+        match self {
+            Self::CratesIo(name) => format!("https://crates.io/api/v1/crates/{name}"),
+            Self::GitHubReleases(repo) => {
+                format!("https://api.github.com/repos/{repo}/releases/latest")
+            }
+        }
+    }
+
+    /// fetch the latest version from the network
+    async fn fetch(&self, user_agent: &str) -> anyhow::Result<semver::Version> {
+        let json: serde_json::Value = reqwest::Client::builder()
+            .user_agent(user_agent)
+            .timeout(Duration::from_secs(10))
+            .build()?
+            .get(self.url())
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let version = match self {
+            Self::CratesIo(_) => json["crate"]["max_stable_version"].as_str(),
+            Self::GitHubReleases(_) => json["tag_name"].as_str(),
+        }
+        .ok_or_else(|| anyhow::anyhow!("unexpected response from {}", self.url()))?;
+
+        Ok(semver::Version::parse(version.trim_start_matches('v'))?)
+    }
+}
+
+/// result of the last network check, cached in the per-user cache directory
+///
+/// Stored as `<unix seconds> <version>`, or just `<unix seconds>` if the check failed
+/// (so an unreachable source is also retried at most once per [`CHECK_INTERVAL`]).
+struct Cache(std::path::PathBuf);
+
+impl Cache {
+    fn new(name: &str) -> Option<Self> {
+        crate::config_dir::cache_dir(name).map(|dir| Self(dir.join("update-check")))
+    }
+
+    /// the last check, if within [`CHECK_INTERVAL`]; the version is [`None`] if that check failed
+    async fn read(&self) -> Option<Option<semver::Version>> {
+        let contents = tokio::fs::read_to_string(&self.0).await.ok()?;
+        let mut fields = contents.split_whitespace();
+
+        let checked = UNIX_EPOCH + Duration::from_secs(fields.next()?.parse().ok()?);
+        (SystemTime::now().duration_since(checked).ok()? < CHECK_INTERVAL).then(|| {
+            fields
+                .next()
+                .and_then(|version| semver::Version::parse(version).ok())
+        })
+    }
+
+    /// record a check
+    ///
+    /// Written to a new (never pre-existing) temp file that's renamed over the cache file,
+    /// so an existing file or symlink at either path is never followed/truncated.
+    async fn write(&self, version: Option<&semver::Version>) -> anyhow::Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let dir = self
+            .0
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("invalid cache path {}", self.0.display()))?;
+        let mut builder = tokio::fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        builder.mode(0o700);
+        builder.create(dir).await?;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let contents =
+            version.map_or_else(|| now.to_string(), |version| format!("{now} {version}"));
+
+        let temp = self.0.with_extension(format!("{}.tmp", std::process::id()));
+        let result = async {
+            let mut file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&temp)
+                .await?;
+            file.write_all(contents.as_bytes()).await?;
+            file.sync_all().await?;
+            tokio::fs::rename(&temp, &self.0).await
+        }
+        .await;
+
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&temp).await;
+        }
+        Ok(result?)
+    }
+}
+
+/// look up the latest version (or use the cached one) & log a notice if it's newer
+async fn check(name: &str, current: &str, source: &UpdateSource) -> anyhow::Result<()> {
+    let current = semver::Version::parse(current.trim_start_matches('v'))?;
+    let cache = Cache::new(name).ok_or_else(|| anyhow::anyhow!("no cache directory"))?;
+
+    let latest = if let Some(latest) = cache.read().await {
+        latest
+    } else {
+        let latest = source.fetch(&format!("{name}/{current}")).await;
+        // still log the notice; the check is just repeated next time
+        if let Err(e) = cache.write(latest.as_ref().ok()).await {
+            debug!("failed to cache the update check: {e:#}");
+        }
+        Some(latest?)
+    };
+
+    if let Some(latest) = latest.filter(|latest| *latest > current) {
+        info!("{name} {latest} is available (running {current})");
+    }
+
+    Ok(())
+}
+
+/// check for a newer version in the background; never blocks the caller
+///
+/// The (async) check is driven by its own current-thread runtime on a background thread,
+/// so it doesn't depend on (or occupy) the entrypoint function's runtime, if any.
+/// A single notice is logged if a newer version is available.
+pub(crate) fn spawn(name: String, current: String, source: UpdateSource) {
+    let _ = std::thread::Builder::new()
+        .name(String::from("entrypoint-update-check"))
+        .spawn(move || {
+            let result = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| runtime.block_on(check(&name, &current, &source)));
+
+            if let Err(e) = result {
+                debug!("update check failed: {e:#}");
+            }
+        });
+}
@@ -0,0 +1,101 @@
+//! encrypted dotenv files (i.e. the [dotenv-vault](https://www.dotenv.org/docs/security/env-vault) `.env.vault` format)
+
+use aes_gcm::aead::{Aead, KeyInit};
+use anyhow::Context;
+use base64::Engine;
+use std::path::{Path, PathBuf};
+
+/// AES-GCM nonce length (prefixed to each ciphertext)
+const NONCE_LEN: usize = 12;
+
+/// [`decrypt`] the `.env.vault` file at `path` (if found)
+///
+/// # Errors
+/// * the file can't be decrypted
+pub(crate) fn open(path: Option<PathBuf>, keys: &str) -> anyhow::Result<Option<String>> {
+    let Some(path) = path else {
+        tracing::warn!("DOTENV_KEY is set, but no .env.vault file found; falling back to .env");
+        return Ok(None);
+    };
+
+    decrypt(&path, keys)
+        .map(Some)
+        .inspect_err(|_| tracing::error!("failed to decrypt {}", path.display()))
+        .with_context(|| format!("failed to decrypt {}", path.display()))
+}
+
+/// decrypt the environment selected by `keys` from the `.env.vault` file at `path`
+///
+/// `keys` is a comma separated list of `dotenv://:key_<hex>@dotenv.org/vault/.env.vault?environment=<name>` URIs
+/// (i.e. a `DOTENV_KEY` value); each is tried in order, to support key rotation.
+///
+/// # Errors
+/// * the file can't be read/parsed
+/// * no key decrypts its environment; the error is from the last key tried
+fn decrypt(path: &Path, keys: &str) -> anyhow::Result<String> {
+    let vault = dotenvy::from_path_iter(path)?.collect::<Result<Vec<_>, _>>()?;
+
+    let mut error = anyhow::anyhow!("no DOTENV_KEY supplied");
+    for key in keys.split(',').map(str::trim).filter(|key| !key.is_empty()) {
+        match decrypt_with(&vault, key) {
+            Ok(content) => return Ok(content),
+            Err(e) => error = e,
+        }
+    }
+
+    Err(error)
+}
+
+/// decrypt the environment (from parsed `vault` variables) selected by a single `key` URI
+fn decrypt_with(vault: &[(String, String)], key: &str) -> anyhow::Result<String> {
+    let (secret, environment) = parse_key(key)?;
+    let name = format!("DOTENV_VAULT_{}", environment.to_ascii_uppercase());
+
+    let ciphertext = vault
+        .iter()
+        .find_map(|(key, value)| (*key == name).then_some(value))
+        .ok_or_else(|| anyhow::anyhow!("{name} not found in .env.vault"))?;
+    let ciphertext = base64::engine::general_purpose::STANDARD.decode(ciphertext)?;
+    anyhow::ensure!(ciphertext.len() > NONCE_LEN, "{name} is malformed");
+    let (nonce, ciphertext) = ciphertext.split_at(NONCE_LEN);
+
+    let plaintext = aes_gcm::Aes256Gcm::new_from_slice(&secret)
+        .map_err(|_| anyhow::anyhow!("DOTENV_KEY must be a 64 character hex key"))?
+        .decrypt(aes_gcm::Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow::anyhow!("failed to decrypt {name}; incorrect DOTENV_KEY?"))?;
+
+    Ok(String::from_utf8(plaintext)?)
+}
+
+/// (decoded secret, environment name) from a `dotenv://:key_<hex>@...?environment=<name>` URI
+fn parse_key(key: &str) -> anyhow::Result<(Vec<u8>, &str)> {
+    let malformed = || {
+        anyhow::anyhow!("malformed DOTENV_KEY (expected dotenv://:key_<hex>@dotenv.org/vault/.env.vault?environment=<name>)")
+    };
+
+    let rest = key.strip_prefix("dotenv://").ok_or_else(malformed)?;
+    let (userinfo, rest) = rest.split_once('@').ok_or_else(malformed)?;
+    let (_, password) = userinfo.split_once(':').ok_or_else(malformed)?;
+    let hex = password.strip_prefix("key_").ok_or_else(malformed)?;
+
+    let environment = rest
+        .split_once('?')
+        .and_then(|(_, query)| {
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("environment="))
+        })
+        .filter(|environment| !environment.is_empty())
+        .ok_or_else(malformed)?;
+
+    let secret = (0..hex.len())
+        .step_by(2)
+        .map(|index| {
+            hex.get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(malformed)?;
+
+    Ok((secret, environment))
+}
@@ -0,0 +1,101 @@
+//! `-v`/`-q` verbosity flags
+
+use tracing_subscriber::filter::LevelFilter;
+
+/// arg id for `-v`/`--verbose`
+const VERBOSE: &str = "entrypoint_verbose";
+
+/// arg id for `-q`/`--quiet`
+const QUIET: &str = "entrypoint_quiet";
+
+/// net verbosity (`-v` count minus `-q` count) parsed by the most recent [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+static OFFSET: std::sync::Mutex<i16> = std::sync::Mutex::new(0);
+
+/// repeatable `-v`/`-q` flags to raise/lower the log level
+///
+/// Each `-v` raises the [`LevelFilter`] one step (e.g. `INFO` -> `DEBUG` -> `TRACE`);
+/// each `-q` lowers it one step (e.g. `INFO` -> `WARN` -> `ERROR` -> `OFF`).
+///
+/// When flattened into the [`clap::Parser`] struct, the parsed counts are applied automatically
+/// by the default (and [`macros`](crate::macros::LoggerDefault)) [`LoggerConfig::default_log_level`](crate::LoggerConfig::default_log_level).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// struct Args {
+///     #[command(flatten)]
+///     verbosity: Verbosity,
+/// }
+/// ```
+#[derive(clap::Args, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Verbosity {
+    /// Increase log verbosity (repeatable)
+    #[arg(id = VERBOSE, short = 'v', long = "verbose", action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Decrease log verbosity (repeatable)
+    #[arg(id = QUIET, short = 'q', long = "quiet", action = clap::ArgAction::Count, global = true)]
+    pub quiet: u8,
+}
+
+impl Verbosity {
+    /// `default` raised/lowered per these flag counts
+    ///
+    /// # Examples
+    /// ```
+    /// # use entrypoint::prelude::*;
+    /// let verbosity = Verbosity { verbose: 2, quiet: 0 };
+    /// assert_eq!(verbosity.log_level(LevelFilter::INFO), LevelFilter::TRACE);
+    ///
+    /// let verbosity = Verbosity { verbose: 0, quiet: 5 };
+    /// assert_eq!(verbosity.log_level(LevelFilter::INFO), LevelFilter::OFF);
+    /// ```
+    #[must_use]
+    pub fn log_level(&self, default: LevelFilter) -> LevelFilter {
+        adjust(default, i16::from(self.verbose) - i16::from(self.quiet))
+    }
+
+    /// `default` raised/lowered per the flags parsed by [`Entrypoint::parse_args`](crate::Entrypoint::parse_args)
+    ///
+    /// Returns `default` unchanged if [`Verbosity`] isn't part of the parsed CLI.
+    #[must_use]
+    pub fn parsed_log_level(default: LevelFilter) -> LevelFilter {
+        let offset = *OFFSET
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        adjust(default, offset)
+    }
+}
+
+/// record the net verbosity from parsed matches (if [`Verbosity`] is present)
+pub(crate) fn set(matches: &clap::ArgMatches) {
+    let count = |id| matches.try_get_one::<u8>(id).ok().flatten().copied();
+
+    if let (Some(verbose), Some(quiet)) = (count(VERBOSE), count(QUIET)) {
+        *OFFSET
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) =
+            i16::from(verbose) - i16::from(quiet);
+    }
+}
+
+/// step `level` up (positive) or down (negative) by `offset`, saturating at `OFF`/`TRACE`
+pub(crate) fn adjust(level: LevelFilter, offset: i16) -> LevelFilter {
+    const LEVELS: [LevelFilter; 6] = [
+        LevelFilter::OFF,
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+    ];
+
+    let index = LEVELS.iter().position(|l| *l == level).unwrap_or_default();
+    let index = i16::try_from(index)
+        .unwrap_or_default()
+        .saturating_add(offset);
+
+    LEVELS[usize::try_from(index.clamp(0, 5)).unwrap_or_default()]
+}
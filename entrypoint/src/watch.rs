@@ -0,0 +1,71 @@
+//! hot-reload of dotenv files on change
+
+use crate::prelude::*;
+use notify::Watcher;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// quiet period to coalesce the burst of events from a single save
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// dotenv files processed so far (i.e. the files to watch)
+static FILES: std::sync::Mutex<Vec<PathBuf>> = std::sync::Mutex::new(Vec::new());
+
+/// record a processed dotenv file
+pub(crate) fn track(path: &Path) {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut files = FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if !files.contains(&path) {
+        files.push(path);
+    }
+}
+
+/// watch the processed dotenv files; `reload` is run after any of them change
+///
+/// # Errors
+/// * the watcher can't be created
+pub(crate) fn spawn(reload: fn() -> anyhow::Result<()>) -> anyhow::Result<()> {
+    let files = FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+
+    // watch the directories, since editors commonly replace (i.e. rename over) files on save
+    let mut dirs: Vec<&Path> = files.iter().filter_map(|file| file.parent()).collect();
+    dirs.sort();
+    dirs.dedup();
+    for dir in dirs {
+        watcher.watch(dir, notify::RecursiveMode::NonRecursive)?;
+    }
+
+    let changed = move |event: notify::Result<notify::Event>| {
+        event.is_ok_and(|event| {
+            !event.kind.is_access() && event.paths.iter().any(|path| files.contains(path))
+        })
+    };
+
+    std::thread::Builder::new()
+        .name(String::from("entrypoint-watch"))
+        .spawn(move || {
+            let _watcher = watcher; // keep watching for the life of the thread
+
+            while let Ok(event) = receiver.recv() {
+                if !changed(event) {
+                    continue;
+                }
+                while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+
+                if let Err(e) = reload() {
+                    error!("failed to reload dotenv file(s): {e:#}");
+                }
+            }
+        })?;
+
+    Ok(())
+}
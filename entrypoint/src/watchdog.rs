@@ -0,0 +1,52 @@
+//! overall execution timeout (i.e. deadline) for the entrypoint function
+
+use std::time::Duration;
+
+/// exit code when the deadline passes (i.e. as GNU `timeout`)
+const EXIT_CODE: i32 = 124;
+
+/// aborts the process if not dropped before the deadline
+///
+/// When the deadline passes, a timeout `ERROR` event is logged, output is flushed (refer to [`FlushGuard`](crate::FlushGuard)),
+/// and the process exits w/ code `124` (i.e. as GNU `timeout`).
+///
+/// [`Entrypoint::entrypoint`](crate::Entrypoint::entrypoint) holds one across the entrypoint function when
+/// [`EntrypointConfig::execution_timeout`](crate::EntrypointConfig::execution_timeout) is set
+/// (or the [`macros::entrypoint`](crate::macros::entrypoint) `timeout` argument is used).
+///
+/// # Examples
+/// ```
+/// # fn work() {}
+/// let _watchdog = entrypoint::Watchdog::start(std::time::Duration::from_secs(30));
+/// work(); // aborted if it takes longer than 30 seconds
+/// ```
+#[must_use = "the deadline is disarmed when the watchdog is dropped"]
+#[derive(Debug)]
+pub struct Watchdog {
+    _disarm: std::sync::mpsc::Sender<()>,
+}
+
+impl Watchdog {
+    /// new watchdog; aborts the process after `timeout`, unless dropped first
+    pub fn start(timeout: Duration) -> Self {
+        let (disarm, disarmed) = std::sync::mpsc::channel::<()>();
+
+        let spawned = std::thread::Builder::new()
+            .name(String::from("entrypoint-watchdog"))
+            .spawn(move || {
+                // the sender is only ever dropped (i.e. disconnected), never sent on
+                if disarmed.recv_timeout(timeout) == Err(std::sync::mpsc::RecvTimeoutError::Timeout)
+                {
+                    tracing::error!("entrypoint function timed out after {timeout:?}; aborting");
+                    drop(crate::FlushGuard::new());
+                    std::process::exit(EXIT_CODE);
+                }
+            });
+
+        if let Err(e) = spawned {
+            tracing::warn!("failed to spawn watchdog thread ({e}); no timeout enforced");
+        }
+
+        Self { _disarm: disarm }
+    }
+}
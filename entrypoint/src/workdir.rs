@@ -0,0 +1,96 @@
+//! working directory & umask setup
+
+/// working directory & umask to set before the entrypoint function runs
+///
+/// Refer to [`EntrypointConfig::workdir_config`](crate::EntrypointConfig::workdir_config).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// let config = WorkdirConfig::new()
+///     .chdir("/var/lib/my-app")
+///     .create(true)
+///     .umask(0o027);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WorkdirConfig {
+    dir: Option<std::path::PathBuf>,
+    create: bool,
+    umask: Option<u32>,
+}
+
+impl WorkdirConfig {
+    /// empty config (i.e. the working directory & umask are inherited)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            dir: None,
+            create: false,
+            umask: None,
+        }
+    }
+
+    /// change to `dir` (a leading `~` is expanded to the user's home directory)
+    #[must_use]
+    pub fn chdir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// whether to create the [`chdir`](WorkdirConfig::chdir) directory (& any missing parents), if missing
+    #[must_use]
+    pub const fn create(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// set the file mode creation mask (e.g. `0o027`); unix only
+    #[must_use]
+    pub const fn umask(mut self, umask: u32) -> Self {
+        self.umask = Some(umask);
+        self
+    }
+
+    /// set the umask, then change (& create, if configured) the working directory
+    ///
+    /// # Errors
+    /// * the directory can't be created
+    /// * the directory can't be changed to
+    pub(crate) fn apply(&self) -> anyhow::Result<()> {
+        use anyhow::Context;
+
+        if let Some(umask) = self.umask {
+            set_umask(umask);
+        }
+
+        if let Some(dir) = &self.dir {
+            let dir = crate::writer::expand_home(dir);
+            if self.create {
+                std::fs::create_dir_all(&dir).with_context(|| {
+                    format!("failed to create working directory {}", dir.display())
+                })?;
+            }
+
+            std::env::set_current_dir(&dir).with_context(|| {
+                format!("failed to change working directory to {}", dir.display())
+            })?;
+            tracing::info!("working directory: {}", dir.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// set the process umask
+#[cfg(unix)]
+fn set_umask(umask: u32) {
+    let mode = rustix::fs::Mode::from_bits_truncate(umask as rustix::fs::RawMode);
+    let previous = rustix::process::umask(mode);
+    tracing::info!("umask: {:04o} (was {:04o})", mode.bits(), previous.bits());
+}
+
+/// umask isn't available on this platform
+#[cfg(not(unix))]
+fn set_umask(umask: u32) {
+    tracing::warn!("umask ({umask:04o}) is unavailable on this platform");
+}
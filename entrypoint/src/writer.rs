@@ -0,0 +1,514 @@
+//! log writers
+
+use std::io::Write;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::fmt::{
+    writer::{BoxMakeWriter, EitherWriter},
+    MakeWriter,
+};
+
+/// [`WorkerGuard`]s for [`non_blocking`] writers, held until [`flush_non_blocking`]
+static GUARDS: std::sync::Mutex<Vec<WorkerGuard>> = std::sync::Mutex::new(Vec::new());
+
+/// log file rotation policy
+///
+/// Refer to [`LoggerConfig::default_log_rotation`](crate::LoggerConfig::default_log_rotation).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rotation {
+    /// single, unbounded file
+    #[default]
+    Never,
+    /// new file every hour; file name is suffixed w/ `.YYYY-MM-DD-HH`
+    Hourly,
+    /// new file every day; file name is suffixed w/ `.YYYY-MM-DD`
+    Daily,
+    /// new file once the current one would exceed this many bytes; the previous file is kept as `<file>.1`
+    ///
+    /// A single event larger than this is appended to the current file (which is then rotated by the next event),
+    /// so it doesn't also push out the previous file.
+    Size(u64),
+}
+
+/// [`MakeWriter`] routing `WARN`/`ERROR` events to stderr and everything else to stdout
+///
+/// Keeps stdout clean for program output (e.g. CLI data) while diagnostics go to stderr.
+///
+/// This can be easily set with convenience [`macros`](crate::macros::LoggerDefault#attributes) (i.e. `#[log_writer(split)]`).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser)]
+/// # struct Args {}
+/// impl entrypoint::LoggerConfig for Args {
+///     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+///         entrypoint::SplitWriter
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SplitWriter;
+
+impl<'a> MakeWriter<'a> for SplitWriter {
+    type Writer = EitherWriter<std::io::Stderr, std::io::Stdout>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EitherWriter::B(std::io::stdout())
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        if *meta.level() <= tracing::Level::WARN {
+            EitherWriter::A(std::io::stderr())
+        } else {
+            EitherWriter::B(std::io::stdout())
+        }
+    }
+}
+
+/// [`MakeWriter`](tracing_subscriber::fmt::MakeWriter) for [`Rotation::Size`]
+///
+/// Wrap in an [`Arc`](std::sync::Arc) for a [`MakeWriter`](tracing_subscriber::fmt::MakeWriter) impl.
+#[derive(Debug)]
+pub(crate) struct SizeRotatingFile {
+    path: std::path::PathBuf,
+    max_bytes: u64,
+    state: std::sync::Mutex<SizeRotatingState>,
+}
+
+#[derive(Debug)]
+struct SizeRotatingState {
+    file: std::fs::File,
+    bytes: u64,
+}
+
+impl SizeRotatingFile {
+    /// open (append) the supplied file, creating parent directories as needed
+    pub(crate) fn new(path: &std::path::Path, max_bytes: u64) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = Self::open(path)?;
+        let bytes = file.metadata()?.len();
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            max_bytes,
+            state: std::sync::Mutex::new(SizeRotatingState { file, bytes }),
+        })
+    }
+
+    fn open(path: &std::path::Path) -> std::io::Result<std::fs::File> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+    }
+
+    /// path of the previous (rotated) file
+    fn rotated_path(&self) -> std::path::PathBuf {
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        rotated.into()
+    }
+}
+
+impl Write for &SizeRotatingFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| std::io::Error::other("log file lock poisoned"))?;
+
+        // nothing to rotate if the file is empty; an oversized write is appended instead (refer to `Rotation::Size`)
+        let len = buf.len() as u64;
+        if state.bytes > 0 && len <= self.max_bytes && state.bytes + len > self.max_bytes {
+            state.file.flush()?;
+            std::fs::rename(&self.path, self.rotated_path())?;
+            state.file = SizeRotatingFile::open(&self.path)?;
+            state.bytes = 0;
+        }
+
+        // all at once, so an event is never split across files
+        state.file.write_all(buf)?;
+        state.bytes += len;
+        drop(state);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.state
+            .lock()
+            .map_err(|_| std::io::Error::other("log file lock poisoned"))?
+            .file
+            .flush()
+    }
+}
+
+/// expand a leading `~` to the user's home directory (`HOME`, or `USERPROFILE` on windows)
+///
+/// Paths from CLI args/env vars aren't expanded by a shell (e.g. `--log-file=~/app.log`, or `.env` values).
+pub(crate) fn expand_home(file: &std::path::Path) -> std::path::PathBuf {
+    let mut components = file.components();
+
+    match components.next() {
+        Some(std::path::Component::Normal(first)) if first == "~" => ["HOME", "USERPROFILE"]
+            .into_iter()
+            .find_map(std::env::var_os)
+            .map_or_else(
+                || file.to_path_buf(),
+                |home| std::path::PathBuf::from(home).join(components.as_path()),
+            ),
+        _ => file.to_path_buf(),
+    }
+}
+
+/// [`MakeWriter`](tracing_subscriber::fmt::MakeWriter) for the supplied log file path & [`Rotation`] policy
+///
+/// A leading `~` is [expanded](expand_home) and missing parent directories are created.
+///
+/// # Errors
+/// * the parent directory can't be created
+/// * the file can't be opened
+pub(crate) fn log_file(
+    file: &std::path::Path,
+    rotation: Rotation,
+) -> anyhow::Result<BoxMakeWriter> {
+    let writer = open_log_file(file, rotation)?;
+
+    #[cfg(feature = "signals")]
+    let writer = reopenable(file, rotation, writer);
+
+    Ok(writer)
+}
+
+/// [`log_file`] writer opened ahead of the default log layer (refer to [`preopen_log_file`])
+static PREOPENED: std::sync::Mutex<Option<(std::path::PathBuf, Rotation, BoxMakeWriter)>> =
+    std::sync::Mutex::new(None);
+
+/// open a [`log_file`] writer, held for [`take_log_file`] until the returned guard is dropped
+///
+/// i.e. so log init fails w/ a clear error (rather than the default log layer panicking), w/o opening the file twice.
+///
+/// # Errors
+/// * refer to [`log_file`]
+pub(crate) fn preopen_log_file(
+    file: &std::path::Path,
+    rotation: Rotation,
+) -> anyhow::Result<PreopenedLogFile> {
+    let writer = log_file(file, rotation)?;
+
+    *PREOPENED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) =
+        Some((file.to_path_buf(), rotation, writer));
+
+    Ok(PreopenedLogFile)
+}
+
+/// drops the [preopened](preopen_log_file) writer, if it wasn't taken (e.g. by an overridden default log layer)
+pub(crate) struct PreopenedLogFile;
+
+impl Drop for PreopenedLogFile {
+    fn drop(&mut self) {
+        PREOPENED
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+    }
+}
+
+/// the [preopened](preopen_log_file) writer for the supplied log file path & [`Rotation`] policy, if any;
+/// otherwise, a newly opened [`log_file`] writer
+///
+/// # Errors
+/// * refer to [`log_file`]
+pub(crate) fn take_log_file(
+    file: &std::path::Path,
+    rotation: Rotation,
+) -> anyhow::Result<BoxMakeWriter> {
+    let mut preopened = PREOPENED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    match preopened.take() {
+        Some((path, preopened_rotation, writer))
+            if path == file && preopened_rotation == rotation =>
+        {
+            Ok(writer)
+        }
+        _ => log_file(file, rotation),
+    }
+}
+
+/// see [`log_file`]
+fn open_log_file(file: &std::path::Path, rotation: Rotation) -> anyhow::Result<BoxMakeWriter> {
+    use anyhow::Context;
+    use tracing_appender::rolling;
+
+    let file = expand_home(file);
+    let directory = file
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| std::path::Path::new("."));
+
+    std::fs::create_dir_all(directory)
+        .with_context(|| format!("failed to create log directory {}", directory.display()))?;
+
+    let open_error = || format!("failed to open log file {}", file.display());
+    let rotation = match rotation {
+        Rotation::Never => rolling::Rotation::NEVER,
+        Rotation::Hourly => rolling::Rotation::HOURLY,
+        Rotation::Daily => rolling::Rotation::DAILY,
+        Rotation::Size(max_bytes) => {
+            return Ok(BoxMakeWriter::new(std::sync::Arc::new(
+                SizeRotatingFile::new(&file, max_bytes).with_context(open_error)?,
+            )));
+        }
+    };
+
+    Ok(BoxMakeWriter::new(
+        rolling::RollingFileAppender::builder()
+            .rotation(rotation)
+            .filename_prefix(file.file_name().unwrap_or_default().to_string_lossy())
+            .build(directory)
+            .with_context(open_error)?,
+    ))
+}
+
+/// [`log_file`] writers (possibly dropped), re-opened by [`reopen_log_files`]
+#[cfg(feature = "signals")]
+static REOPENABLE: std::sync::Mutex<Vec<std::sync::Weak<ReopenableFile>>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// [`log_file`] writer that can be re-opened (e.g. after `logrotate` moves the file)
+#[cfg(feature = "signals")]
+struct ReopenableFile {
+    file: std::path::PathBuf,
+    rotation: Rotation,
+    writer: std::sync::RwLock<std::sync::Arc<BoxMakeWriter>>,
+}
+
+#[cfg(feature = "signals")]
+impl ReopenableFile {
+    fn writer(&self) -> std::sync::Arc<BoxMakeWriter> {
+        self.writer
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// wrap a [`log_file`] writer, so it's re-opened by [`reopen_log_files`]
+#[cfg(feature = "signals")]
+fn reopenable(file: &std::path::Path, rotation: Rotation, writer: BoxMakeWriter) -> BoxMakeWriter {
+    let reopenable = std::sync::Arc::new(ReopenableFile {
+        file: file.to_path_buf(),
+        rotation,
+        writer: std::sync::RwLock::new(std::sync::Arc::new(writer)),
+    });
+
+    REOPENABLE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(std::sync::Arc::downgrade(&reopenable));
+
+    BoxMakeWriter::new(Reopenable(reopenable))
+}
+
+/// re-open all (in use) [`log_file`] writers; returns the number re-opened
+///
+/// # Errors
+/// * a file can't be re-opened (it keeps writing to the previous one)
+#[cfg(feature = "signals")]
+pub(crate) fn reopen_log_files() -> anyhow::Result<usize> {
+    let files: Vec<_> = {
+        let mut reopenable = REOPENABLE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        reopenable.retain(|file| file.strong_count() > 0);
+        reopenable
+            .iter()
+            .filter_map(std::sync::Weak::upgrade)
+            .collect()
+    };
+
+    for file in &files {
+        let writer = open_log_file(&file.file, file.rotation)?;
+        *file
+            .writer
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = std::sync::Arc::new(writer);
+    }
+
+    Ok(files.len())
+}
+
+/// see [`reopenable`]
+#[cfg(feature = "signals")]
+struct Reopenable(std::sync::Arc<ReopenableFile>);
+
+#[cfg(feature = "signals")]
+impl<'a> MakeWriter<'a> for Reopenable {
+    type Writer = ReopenableWrite;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ReopenableWrite(self.0.writer())
+    }
+}
+
+/// [`Write`] adapter for the current [`ReopenableFile`] writer
+#[cfg(feature = "signals")]
+struct ReopenableWrite(std::sync::Arc<BoxMakeWriter>);
+
+#[cfg(feature = "signals")]
+impl Write for ReopenableWrite {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.make_writer().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.make_writer().flush()
+    }
+}
+
+/// wrap the supplied writer w/ [`tracing_appender::non_blocking`]
+///
+/// Each line is tagged w/ its event's level, so the worker can still route it (e.g. [`SplitWriter`]).
+/// The [`WorkerGuard`] is held until [`flush_non_blocking`] is called.
+pub(crate) fn non_blocking(writer: BoxMakeWriter) -> BoxMakeWriter {
+    let (writer, guard) = tracing_appender::non_blocking(MakeWriterWrite(writer));
+
+    GUARDS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(guard);
+
+    BoxMakeWriter::new(NonBlocking(writer))
+}
+
+/// drop all held [`WorkerGuard`]s, flushing any buffered lines
+pub(crate) fn flush_non_blocking() {
+    let guards = std::mem::take(
+        &mut *GUARDS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+
+    drop(guards);
+}
+
+/// levels w/ a stand-in [`LEVEL_METADATA`], in [tag](LevelTagged) order
+const LEVELS: [tracing::Level; 5] = [
+    tracing::Level::TRACE,
+    tracing::Level::DEBUG,
+    tracing::Level::INFO,
+    tracing::Level::WARN,
+    tracing::Level::ERROR,
+];
+
+/// callsite for a [`LEVEL_METADATA`] entry
+struct LevelCallsite(usize);
+
+impl tracing::callsite::Callsite for LevelCallsite {
+    fn set_interest(&self, _interest: tracing::subscriber::Interest) {}
+
+    fn metadata(&self) -> &tracing::Metadata<'_> {
+        &LEVEL_METADATA[self.0]
+    }
+}
+
+static LEVEL_CALLSITES: [LevelCallsite; 5] = [
+    LevelCallsite(0),
+    LevelCallsite(1),
+    LevelCallsite(2),
+    LevelCallsite(3),
+    LevelCallsite(4),
+];
+
+/// event metadata (i.e. just the level) handed to the writer by a [`non_blocking`] worker
+static LEVEL_METADATA: [tracing::Metadata<'static>; 5] = [
+    level_metadata(&LEVEL_CALLSITES[0]),
+    level_metadata(&LEVEL_CALLSITES[1]),
+    level_metadata(&LEVEL_CALLSITES[2]),
+    level_metadata(&LEVEL_CALLSITES[3]),
+    level_metadata(&LEVEL_CALLSITES[4]),
+];
+
+/// see [`LEVEL_METADATA`]
+const fn level_metadata(callsite: &'static LevelCallsite) -> tracing::Metadata<'static> {
+    tracing::Metadata::new(
+        "non_blocking",
+        module_path!(),
+        LEVELS[callsite.0],
+        None,
+        None,
+        None,
+        tracing::field::FieldSet::new(&[], tracing::callsite::Identifier(callsite)),
+        tracing::metadata::Kind::EVENT,
+    )
+}
+
+/// [`MakeWriter`] for a [`non_blocking`] worker, [tagging](LevelTagged) each line w/ its event's level
+struct NonBlocking(tracing_appender::non_blocking::NonBlocking);
+
+impl<'a> MakeWriter<'a> for NonBlocking {
+    type Writer = LevelTagged;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LevelTagged(self.0.clone(), 0)
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        let tag = LEVELS
+            .iter()
+            .position(|level| level == meta.level())
+            .map_or(0, |index| index + 1);
+
+        LevelTagged(self.0.clone(), u8::try_from(tag).unwrap_or_default())
+    }
+}
+
+/// prefixes each write w/ a tag byte (i.e. `0` for no metadata, otherwise `1 +` the index into [`LEVELS`])
+struct LevelTagged(tracing_appender::non_blocking::NonBlocking, u8);
+
+impl Write for LevelTagged {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut line = Vec::with_capacity(buf.len() + 1);
+        line.push(self.1);
+        line.extend_from_slice(buf);
+        self.0.write_all(&line)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// [`Write`] adapter for a [`BoxMakeWriter`], routing each [tagged](LevelTagged) line per its level
+struct MakeWriterWrite(BoxMakeWriter);
+
+impl Write for MakeWriterWrite {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let Some((&tag, line)) = buf.split_first() else {
+            return Ok(0);
+        };
+
+        match usize::from(tag)
+            .checked_sub(1)
+            .and_then(|index| LEVEL_METADATA.get(index))
+        {
+            Some(meta) => self.0.make_writer_for(meta).write_all(line)?,
+            None => self.0.make_writer().write_all(line)?,
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.make_writer().flush()
+    }
+}
@@ -0,0 +1,27 @@
+//! `#[entrypoint::entrypoint(async_std)]` runs the async function on async-std, after setup
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "async-std")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint(async_std)]
+#[test]
+async fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+
+    // logging is initialized before the runtime (threads) start
+    let enabled =
+        entrypoint::async_std::task::spawn(async { enabled!(entrypoint::Level::INFO) }).await;
+    assert!(enabled);
+
+    entrypoint::async_std::task::sleep(std::time::Duration::from_millis(1)).await;
+    Ok(())
+}
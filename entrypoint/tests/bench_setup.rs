@@ -0,0 +1,27 @@
+//! one-time, quiet setup for benchmark binaries
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn bench_setup() -> entrypoint::anyhow::Result<()> {
+    // safe to call repeatedly (e.g. once per bench group)
+    let _args = Args::bench_setup()?;
+    let _args = Args::bench_setup()?;
+
+    common::using_prod_env()?;
+
+    assert!(enabled!(entrypoint::Level::ERROR));
+    assert!(!enabled!(entrypoint::Level::WARN));
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! `EntrypointConfig::build_info` is described by `--build-info` (i.e. `Entrypoint::build_details`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[command(name = "build-info-test", version = "1.2.3")]
+struct Args {}
+
+impl EntrypointConfig for Args {
+    fn build_info() -> BuildInfo {
+        BuildInfo {
+            git_commit: Some("0123456789ab-dirty"),
+            rustc: Some("rustc 1.0.0"),
+            features: Some("default,extra"),
+            ..entrypoint::build_info!()
+        }
+    }
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+struct Derived {}
+
+#[test]
+fn build_info() -> entrypoint::anyhow::Result<()> {
+    let details: entrypoint::serde_json::Value =
+        entrypoint::serde_json::from_str(&Args::build_details())?;
+
+    assert_eq!(details["name"], "build-info-test");
+    assert_eq!(details["version"], "1.2.3");
+    assert_eq!(details["git_commit"], "0123456789ab-dirty");
+    assert_eq!(details["rustc"], "rustc 1.0.0");
+    assert_eq!(details["timestamp"], entrypoint::serde_json::Value::Null);
+    assert_eq!(
+        details["features"],
+        entrypoint::serde_json::json!(["default", "extra"])
+    );
+
+    // no build script (i.e. emit_build_info) for this crate
+    assert!(Derived::build_info().is_empty());
+
+    Ok(())
+}
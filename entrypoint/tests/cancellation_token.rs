@@ -0,0 +1,43 @@
+//! `CancellationToken` cancelled on `SIGTERM` (i.e. async graceful shutdown)
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "tokio"))]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl RuntimeConfig for Args {}
+
+#[entrypoint::entrypoint(tokio, context)]
+#[test]
+async fn entrypoint(_args: Args, ctx: entrypoint::Context) -> entrypoint::anyhow::Result<()> {
+    assert!(!ctx.cancellation.is_cancelled());
+
+    let server = tokio::spawn({
+        let cancelled = ctx.cancellation.clone();
+        async move {
+            tokio::select! {
+                () = tokio::time::sleep(std::time::Duration::from_secs(10)) => false,
+                () = cancelled.cancelled() => true,
+            }
+        }
+    });
+
+    signal_hook::low_level::raise(signal_hook::consts::SIGTERM)?;
+
+    let graceful = tokio::time::timeout(std::time::Duration::from_secs(5), server).await??;
+    assert!(graceful);
+    assert!(entrypoint::cancellation_token().is_cancelled());
+    assert_eq!(
+        entrypoint::shutdown_reason(),
+        Some(entrypoint::ShutdownReason::Terminate)
+    );
+
+    Ok(())
+}
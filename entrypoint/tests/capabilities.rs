@@ -0,0 +1,58 @@
+//! `--capabilities` JSON description
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[command(name = "capable", version = "1.2.3")]
+struct Args {
+    #[arg(long, env = "CAPABLE_TOKEN", required = true)]
+    token: String,
+
+    #[arg(long, env = "CAPABLE_OPTIONAL")]
+    optional: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(entrypoint::clap::Subcommand, Clone, Debug)]
+enum Command {
+    Serve,
+    Migrate,
+}
+
+#[test]
+fn capabilities() -> entrypoint::anyhow::Result<()> {
+    let capabilities: serde_json::Value = serde_json::from_str(&Args::capabilities())?;
+
+    assert_eq!(capabilities["name"], "capable");
+    assert_eq!(capabilities["version"], "1.2.3");
+
+    // whichever features the tests were built w/
+    let features = capabilities["features"]
+        .as_array()
+        .expect("features is an array");
+    for (feature, enabled) in [
+        ("macros", cfg!(feature = "macros")),
+        ("log", cfg!(feature = "log")),
+        ("shutdown", cfg!(feature = "shutdown")),
+        ("tokio", cfg!(feature = "tokio")),
+    ] {
+        assert_eq!(features.contains(&feature.into()), enabled, "{feature}");
+    }
+
+    assert_eq!(
+        capabilities["required_env_vars"],
+        serde_json::json!(["CAPABLE_TOKEN"])
+    );
+    assert_eq!(
+        capabilities["subcommands"],
+        serde_json::json!(["serve", "migrate"])
+    );
+
+    Ok(())
+}
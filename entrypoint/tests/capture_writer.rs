@@ -0,0 +1,42 @@
+//! `entrypoint::testing::CaptureWriter` captures log output
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    let captured = CaptureWriter::global();
+    captured.clear();
+    assert!(captured.is_empty());
+
+    info!("first message");
+    warn!("second message");
+
+    captured.assert_line_count(2);
+    captured.assert_contains("first message");
+    captured.assert_not_contains("third message");
+    assert!(captured.lines()[1].contains("second message"));
+    assert!(captured.snapshot().ends_with('\n'));
+
+    let taken = captured.take();
+    assert!(taken.contains("first message"));
+    assert!(captured.is_empty());
+
+    debug!("filtered out");
+    assert!(captured.is_empty());
+
+    let result = std::panic::catch_unwind(|| captured.assert_contains("missing"));
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! default clap styling & help template
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[command(name = "styled", version = "1.2.3")]
+struct Args {}
+
+#[test]
+fn cli_styles() {
+    let plain = format!("{:?}", entrypoint::clap::builder::Styles::plain());
+
+    std::env::remove_var("NO_COLOR");
+    assert_ne!(
+        format!("{:?}", Args::cli_styles()),
+        format!("Some({plain})")
+    );
+
+    std::env::set_var("NO_COLOR", "1");
+    assert_eq!(
+        format!("{:?}", Args::cli_styles()),
+        format!("Some({plain})")
+    );
+
+    let template = Args::cli_help_template().expect("default help template");
+    let help = <Args as entrypoint::clap::CommandFactory>::command()
+        .help_template(template)
+        .render_help()
+        .to_string();
+    assert!(help.starts_with("styled 1.2.3"));
+}
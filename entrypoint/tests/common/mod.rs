@@ -1,22 +1,21 @@
-#![allow(dead_code)]
+#![allow(
+    dead_code,
+    unreachable_pub,
+    clippy::unwrap_used,
+    clippy::missing_panics_doc
+)]
 
 use entrypoint::prelude::*;
-use std::sync::{Arc, Mutex};
+use entrypoint::testing::CaptureWriter;
 
-#[derive(entrypoint::clap::Parser, Debug)]
+#[derive(entrypoint::clap::Parser, EntrypointDefault, Clone, Debug)]
 #[command(author, version, about, long_about = None)]
 pub(crate) struct Args {}
 
 impl LoggerConfig for Args {
     // pull level from env::var
-    fn default_log_level(&self) -> entrypoint::tracing_subscriber::filter::LevelFilter {
-        <entrypoint::tracing::Level as std::str::FromStr>::from_str(
-            std::env::var("LOG_LEVEL")
-                .unwrap_or(String::from("info"))
-                .as_str(),
-        )
-        .unwrap()
-        .into()
+    fn default_log_level_env(&self) -> Option<String> {
+        Some(String::from("LOG_LEVEL"))
     }
 
     fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
@@ -25,44 +24,18 @@ impl LoggerConfig for Args {
 }
 
 ////////////////////////////////////////////////////////////////////////////////
-#[derive(Clone)]
-pub struct BufferWriter {
-    buffer: Arc<Mutex<Vec<u8>>>,
+#[must_use]
+pub fn global_writer() -> CaptureWriter {
+    CaptureWriter::global()
 }
 
-impl BufferWriter {
-    pub fn new() -> Self {
-        Self {
-            buffer: Arc::new(Mutex::new(Vec::<u8>::with_capacity(10))),
-        }
-    }
-
-    pub fn buffer(&self) -> Vec<u8> {
-        self.buffer.lock().unwrap().clone()
-    }
-
-    pub fn clear(&self) {
-        self.buffer.lock().unwrap().clear()
-    }
-}
-
-impl std::io::Write for BufferWriter {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.buffer.lock().unwrap().extend_from_slice(buf);
-        Ok(buf.len())
-    }
-
-    fn flush(&mut self) -> std::io::Result<()> {
-        Ok(())
-    }
-}
-
-lazy_static::lazy_static! {
-   pub static ref OUTPUT_BUFFER: BufferWriter = BufferWriter::new();
-}
-
-pub fn global_writer() -> BufferWriter {
-    OUTPUT_BUFFER.clone()
+////////////////////////////////////////////////////////////////////////////////
+/// a dotenv (or other) file in `tests/fixtures`
+#[must_use]
+pub(crate) fn fixture(name: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -102,12 +75,13 @@ pub(crate) fn using_both_yes_override() -> entrypoint::anyhow::Result<()> {
 }
 
 ////////////////////////////////////////////////////////////////////////////////
+#[allow(clippy::unnecessary_wraps)]
 pub(crate) fn verify_log_level<T: Logger>(
-    args: &T,
-    level: &tracing_subscriber::filter::LevelFilter,
+    _args: &T,
+    level: tracing_subscriber::filter::LevelFilter,
 ) -> entrypoint::anyhow::Result<()> {
     // not the best test: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.LevelFilter.html#method.current
-    assert!(*level <= entrypoint::tracing_subscriber::filter::LevelFilter::current());
+    assert!(level <= entrypoint::tracing_subscriber::filter::LevelFilter::current());
 
     Ok(())
 }
@@ -0,0 +1,29 @@
+//! `--completions <SHELL>` script (i.e. `Entrypoint::completions`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::clap_complete::Shell;
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[command(name = "complete-me")]
+struct Args {
+    /// an app arg
+    #[arg(long)]
+    widget: Option<String>,
+}
+
+#[test]
+fn completions() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        let script = Args::completions(shell);
+
+        assert!(script.contains("complete-me"), "{shell}: {script}");
+        assert!(script.contains("widget"), "{shell}: {script}");
+        // builtin args are included
+        assert!(script.contains("completions"), "{shell}: {script}");
+        assert!(script.contains("color"), "{shell}: {script}");
+    }
+}
@@ -0,0 +1,57 @@
+//! process .env & config files from the platform (i.e. XDG) config directory
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, not(target_os = "macos")))]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, EntrypointDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(name = "entrypoint-config-dir", author, version, about, long_about = None)]
+struct Args {}
+
+impl DotEnvParserConfig for Args {
+    fn dotenv_config_dir(&self) -> bool {
+        true
+    }
+}
+
+impl ConfigParserConfig for Args {
+    fn config_dir_file(&self) -> Option<String> {
+        Some(String::from("config.json"))
+    }
+
+    fn config_env_prefix(&self) -> Option<String> {
+        Some(String::from("CONFIG_DIR_"))
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Config {
+    name: String,
+    key: String,
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let home = std::env::temp_dir().join(format!("entrypoint-xdg-{}", std::process::id()));
+    let dir = home.join("entrypoint-config-dir");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join(".env"), "CONFIG_DIR_KEY=from_config_dir\n")?;
+    std::fs::write(dir.join("config.json"), r#"{"name": "from_config_dir"}"#)?;
+    std::env::set_var("XDG_CONFIG_HOME", &home);
+
+    assert_eq!(entrypoint::config_dir("entrypoint-config-dir"), Some(dir));
+
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint_with_config_files(
+        |_args, config: Config| {
+            assert_eq!(std::env::var("CONFIG_DIR_KEY")?, "from_config_dir"); // .env
+            assert_eq!(config.name, "from_config_dir"); // config.json
+            assert_eq!(config.key, "from_config_dir"); // env, per .env
+            Ok(())
+        },
+    );
+
+    std::fs::remove_dir_all(&home)?;
+    result
+}
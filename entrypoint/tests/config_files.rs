@@ -0,0 +1,85 @@
+//! merge layered config files & env vars into a typed config
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl ConfigParserConfig for Args {
+    fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+        let dir = std::env::temp_dir().join(format!("entrypoint-config-{}", std::process::id()));
+        vec![
+            Box::new(ConfigFile::Required(dir.join("default.json"))),
+            Box::new(ConfigFile::Required(dir.join("production.json"))),
+            Box::new(ConfigFile::Optional(dir.join("missing.json"))),
+        ]
+    }
+
+    fn config_env_prefix(&self) -> Option<String> {
+        Some(String::from("CONFIG_FILES_"))
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq)]
+enum Mode {
+    Fast,
+    Safe,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Database {
+    url: String,
+    pool: u32,
+    replicas: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Config {
+    name: String,
+    port: u16,
+    debug: Option<bool>,
+    mode: Mode,
+    database: Database,
+    #[serde(default)]
+    workers: u8,
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("entrypoint-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join("default.json"),
+        r#"{"name": "default", "port": 80, "mode": "Safe", "database": {"url": "postgres://localhost", "pool": 4, "replicas": ["a"]}}"#,
+    )?;
+    std::fs::write(
+        dir.join("production.json"),
+        r#"{"name": "production", "database": {"pool": 16}}"#,
+    )?;
+    std::env::set_var("CONFIG_FILES_PORT", "8080");
+    std::env::set_var("CONFIG_FILES_MODE", "Fast");
+    std::env::set_var("CONFIG_FILES_DATABASE__REPLICAS", "b,c");
+
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint_with_config_files(
+        |_args, config: Config| {
+            assert_eq!(config.name, "production"); // later file
+            assert_eq!(config.port, 8080); // env
+            assert_eq!(config.debug, None);
+            assert_eq!(config.mode, Mode::Fast); // env
+            assert_eq!(config.database.url, "postgres://localhost"); // merged table
+            assert_eq!(config.database.pool, 16);
+            assert_eq!(config.database.replicas, ["b", "c"]); // nested env
+            assert_eq!(config.workers, 0); // default
+            Ok(())
+        },
+    );
+
+    std::fs::remove_dir_all(&dir)?;
+    result
+}
@@ -0,0 +1,64 @@
+//! merge TOML config files (per the `toml` feature)
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "toml")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl ConfigParserConfig for Args {
+    fn config_sources(&self) -> Vec<Box<dyn ConfigSource>> {
+        vec![Box::new(ConfigFile::Required(std::env::temp_dir().join(
+            format!("entrypoint-config-{}.toml", std::process::id()),
+        )))]
+    }
+
+    fn config_env_prefix(&self) -> Option<String> {
+        Some(String::from("CONFIG_TOML_"))
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Database {
+    url: String,
+    pool: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Config {
+    port: u16,
+    database: Database,
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("entrypoint-config-{}.toml", std::process::id()));
+    std::fs::write(&path, CONTENT)?;
+    std::env::set_var("CONFIG_TOML_DATABASE__POOL", "16");
+
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint_with_config_files(
+        |_args, config: Config| {
+            assert_eq!(config.port, 8080);
+            assert_eq!(config.database.url, "postgres://localhost");
+            assert_eq!(config.database.pool, 16); // env
+            Ok(())
+        },
+    );
+
+    std::fs::remove_file(&path)?;
+    result
+}
+
+const CONTENT: &str = r#"
+port = 8080
+
+[database]
+url = "postgres://localhost"
+pool = 4
+"#;
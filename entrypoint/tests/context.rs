@@ -0,0 +1,35 @@
+//! setup context (i.e. logger handle, env provenance, & startup timings) handed to the entrypoint function
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint(context)]
+#[test]
+fn entrypoint(_args: Args, ctx: entrypoint::Context) -> entrypoint::anyhow::Result<()> {
+    // per .env
+    assert!(matches!(
+        ctx.env_origins.get("APP_ENV"),
+        Some(EnvOrigin::File(path)) if path.ends_with(".env")
+    ));
+
+    let logger = ctx.logger.expect("logging initialized");
+    logger.set_level(LevelFilter::TRACE)?;
+    assert!(enabled!(entrypoint::Level::TRACE));
+
+    let timings = ctx.timings;
+    assert!(timings.dotenv + timings.logging <= timings.setup);
+    assert!(timings.started.elapsed() >= timings.setup);
+
+    #[cfg(feature = "shutdown")]
+    assert!(!ctx.shutdown.is_requested());
+
+    Ok(())
+}
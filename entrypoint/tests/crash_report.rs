@@ -0,0 +1,55 @@
+//! `crash-report` feature writes a crash report file on panic
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "crash-report")]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author = "crash reports <crash@example.com>", version, about, long_about = None)]
+struct Args {}
+
+impl EntrypointConfig for Args {
+    fn crash_report(&self) -> bool {
+        true
+    }
+
+    fn crash_report_dir(&self) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("entrypoint-crash-{}", std::process::id()))
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(
+            args.crash_report_contact().as_deref(),
+            Some("crash reports <crash@example.com>")
+        );
+
+        let panicked = std::thread::Builder::new()
+            .name(String::from("worker"))
+            .spawn(|| panic!("boom {}", 42))?
+            .join();
+        assert!(panicked.is_err());
+
+        let dir = args.crash_report_dir();
+        let reports: Vec<_> = std::fs::read_dir(&dir)?.collect::<Result<_, _>>()?;
+        assert_eq!(reports.len(), 1);
+        let name = reports[0].file_name().to_string_lossy().into_owned();
+        assert!(name.starts_with("report-entrypoint-"));
+        assert_eq!(reports[0].path().extension(), Some("toml".as_ref()));
+
+        let report = std::fs::read_to_string(reports[0].path())?;
+        assert!(report.contains("name = \"entrypoint\"\n"));
+        assert!(report.contains(&format!("version = \"{}\"\n", env!("CARGO_PKG_VERSION"))));
+        assert!(report.contains(&format!("operating_system = \"{}", std::env::consts::OS)));
+        assert!(report.contains("thread = \"worker\"\n"));
+        assert!(report.contains("message = \"boom 42\"\n"));
+        assert!(report.contains("location = \"entrypoint/tests/crash_report.rs:"));
+        assert!(report.contains("backtrace = '''\n"));
+
+        Ok(std::fs::remove_dir_all(&dir)?)
+    })
+}
@@ -0,0 +1,81 @@
+//! `daemonize` detaches the process, writing a pid file & appending output to the log file
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "daemon"))]
+
+use entrypoint::prelude::*;
+
+/// set for the re-executed (i.e. child) test process; the directory for the pid & log files
+const CHILD: &str = "ENTRYPOINT_DAEMON_CHILD";
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, Clone, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn daemonize(&self) -> bool {
+        std::env::var_os(CHILD).is_some()
+    }
+
+    fn daemon_pid_file(&self) -> Option<std::path::PathBuf> {
+        std::env::var_os(CHILD).map(|dir| std::path::Path::new(&dir).join("daemon.pid"))
+    }
+}
+
+impl entrypoint::LoggerConfig for Args {
+    fn default_log_file(&self) -> Option<std::path::PathBuf> {
+        std::env::var_os(CHILD).map(|dir| std::path::Path::new(&dir).join("daemon.log"))
+    }
+}
+
+// i.e. `main`, run by the child test process
+#[entrypoint::entrypoint]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+    println!("daemon running as {}", std::process::id());
+    Ok(())
+}
+
+#[test]
+fn daemon() {
+    if std::env::var_os(CHILD).is_some() {
+        let _ = main();
+        return;
+    }
+
+    let dir = std::env::temp_dir().join(format!("entrypoint-daemon-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("temp dir");
+
+    let child = std::process::Command::new(std::env::current_exe().expect("test binary path"))
+        .env("RUST_TEST_NOCAPTURE", "1") // i.e. stdout isn't captured by the test harness
+        .env(CHILD, &dir)
+        .spawn()
+        .expect("re-executed test binary");
+    let child_pid = child.id();
+    let status = child.wait_with_output().expect("child exit").status;
+
+    // the original process exits immediately; the daemon carries on
+    assert!(status.success());
+
+    let log = dir.join("daemon.log");
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    let output = loop {
+        let output = std::fs::read_to_string(&log).unwrap_or_default();
+        if output.contains("daemon running as") || std::time::Instant::now() >= deadline {
+            break output;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+
+    let pid = std::fs::read_to_string(dir.join("daemon.pid")).expect("pid file");
+    let pid = pid.trim();
+    assert_ne!(pid, child_pid.to_string());
+    assert!(
+        output.contains(&format!("daemon running as {pid}")),
+        "{output}"
+    );
+    assert!(output.contains("Args"), "{output}");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -1,9 +1,13 @@
-//! derive macros + set log_level attribute
+//! derive macros + set `log_level` attribute
+
+#![allow(unused_crate_dependencies)]
 
 use entrypoint::prelude::*;
 mod common;
 
-#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Debug)]
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
 #[log_format(json)]
 #[log_level(entrypoint::tracing_subscriber::filter::LevelFilter::DEBUG)]
 #[log_writer(common::global_writer)]
@@ -19,14 +23,14 @@ fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
 
     common::verify_log_level(
         &args,
-        &entrypoint::tracing_subscriber::filter::LevelFilter::DEBUG,
+        entrypoint::tracing_subscriber::filter::LevelFilter::DEBUG,
     )?; // check log_level attribute, not default
 
-    common::OUTPUT_BUFFER.clear();
+    common::global_writer().clear();
 
     error!("error");
 
-    let _: serde_json::Value = serde_json::from_slice(&common::OUTPUT_BUFFER.buffer())
+    let _: serde_json::Value = serde_json::from_slice(&common::global_writer().bytes())
         .expect("output doesn't parse as JSON");
 
     Ok(())
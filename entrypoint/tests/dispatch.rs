@@ -0,0 +1,77 @@
+//! each subcommand is routed to its own handler function (i.e. `#[derive(Dispatch)]`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(long)]
+    fail: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(entrypoint::clap::Subcommand, Dispatch, Clone, Debug)]
+#[dispatch(Args)]
+enum Command {
+    /// named fields
+    ServeHttp {
+        #[arg(long)]
+        addr: String,
+    },
+    /// tuple fields
+    Copy(CopyArgs),
+    /// custom handler
+    #[dispatch(handlers::status)]
+    Status,
+}
+
+#[derive(entrypoint::clap::Args, Clone, Debug)]
+struct CopyArgs {
+    from: String,
+    to: String,
+}
+
+fn serve_http(args: &Args, addr: &str) -> entrypoint::anyhow::Result<()> {
+    entrypoint::anyhow::ensure!(!args.fail, "serve_http failed");
+    assert_eq!(addr, "0.0.0.0:8080");
+    Ok(())
+}
+
+fn copy(args: &Args, copy: &CopyArgs) -> entrypoint::anyhow::Result<()> {
+    entrypoint::anyhow::ensure!(!args.fail, "copy failed");
+    assert_eq!((copy.from.as_str(), copy.to.as_str()), ("a", "b"));
+    Ok(())
+}
+
+mod handlers {
+    pub(super) fn status(args: &super::Args) -> entrypoint::anyhow::Result<()> {
+        entrypoint::anyhow::ensure!(!args.fail, "status failed");
+        Ok(())
+    }
+}
+
+#[test]
+fn dispatch() {
+    for (argv, handler) in [
+        (
+            vec!["app", "serve-http", "--addr", "0.0.0.0:8080"],
+            "serve_http",
+        ),
+        (vec!["app", "copy", "a", "b"], "copy"),
+        (vec!["app", "status"], "status"),
+    ] {
+        let args = Args::parse_from(&argv);
+        assert!(args.command.dispatch(&args).is_ok(), "{argv:?}");
+
+        // w/ the shared args
+        let args = Args::parse_from(argv.iter().take(1).chain(&["--fail"]).chain(&argv[1..]));
+        let e = args.command.dispatch(&args).expect_err("--fail");
+        assert_eq!(e.to_string(), format!("{handler} failed"));
+    }
+}
@@ -0,0 +1,65 @@
+//! `--doctor` reports the configuration (i.e. dotenv files, required vars, log destination & filter), then exits
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+/// set for the re-executed (i.e. child) test process
+const CHILD: &str = "ENTRYPOINT_DOCTOR_CHILD";
+
+#[derive(entrypoint::clap::Parser, LoggerDefault, Clone, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl EntrypointConfig for Args {
+    // i.e. as if `--doctor` was passed (the child is run w/o args, as the test harness parses them too)
+    fn doctor(&self) -> bool {
+        true
+    }
+}
+
+impl DotEnvParserConfig for Args {
+    fn required_env_vars(&self) -> Vec<String> {
+        vec![
+            String::from("APP_ENV"), // per .env
+            String::from("DOCTOR_MISSING"),
+        ]
+    }
+}
+
+// i.e. `main`, run by the child test process
+#[entrypoint::entrypoint]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    unreachable!("doctor exits before the entrypoint function");
+}
+
+#[test]
+fn doctor() {
+    if std::env::var_os(CHILD).is_some() {
+        let _ = main();
+        unreachable!("doctor should have exited the process");
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().expect("test binary path"))
+        .env(CHILD, "1")
+        .env("RUST_TEST_NOCAPTURE", "1") // i.e. the report is printed to the (real) stdout
+        .env_remove("DOCTOR_MISSING")
+        .output()
+        .expect("re-executed test binary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1), "{stdout}");
+    assert!(
+        stdout.contains("dotenv files (in processing order):"),
+        "{stdout}"
+    );
+    assert!(stdout.contains(".env"), "{stdout}");
+    assert!(stdout.contains("ok    APP_ENV"), "{stdout}");
+    assert!(
+        stdout.contains("FAIL  DOCTOR_MISSING (missing)"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("log destination:"), "{stdout}");
+    assert!(stdout.contains("log filter:"), "{stdout}");
+    assert!(stdout.contains("1 problem found"), "{stdout}");
+}
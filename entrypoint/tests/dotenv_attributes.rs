@@ -0,0 +1,45 @@
+//! `#[dotenv_files]` & `#[dotenv_override]` attributes; use both .env and .dev, allow .dev to override
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[dotenv_files(".dev", optional(".missing_overlay"))]
+#[dotenv_override]
+#[log_writer(std::io::sink)]
+struct Args {}
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, Clone, Debug)]
+#[dotenv_files(".dev", "tests/fixtures/conf.d/*.env")]
+struct Required {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.dotenv_can_override());
+    assert_eq!(
+        args.dotenv_files(),
+        [
+            DotEnvFile::Required(std::path::PathBuf::from(".dev")),
+            DotEnvFile::Optional(std::path::PathBuf::from(".missing_overlay")),
+        ]
+    );
+
+    assert_eq!(std::env::var("APP_ENV")?, String::from("development"));
+    assert_eq!(std::env::var("TEST_KEY")?, String::from("NOT_A_SECRET_KEY"));
+
+    let required = Required::try_parse_from(["required"])?;
+    assert!(!required.dotenv_can_override());
+    assert_eq!(
+        required.additional_dotenv_files(),
+        Some(vec![
+            std::path::PathBuf::from(".dev"),
+            std::path::PathBuf::from("tests/fixtures/conf.d/*.env"),
+        ])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! fail on dotenv variables that aren't known to the application
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_audit(&self) -> DotEnvAudit {
+        DotEnvAudit::Deny
+    }
+
+    fn dotenv_known_vars(&self) -> Vec<String> {
+        vec![String::from("APP_ENV"), String::from("LOG_LEVEL")]
+    }
+
+    fn required_env_vars(&self) -> Vec<String> {
+        vec![String::from("PORT")]
+    }
+
+    fn env_schema(&self) -> Vec<EnvRule> {
+        vec![EnvRule::new("IP").parse::<std::net::IpAddr>()]
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("dotenv variables are unknown");
+        });
+
+    let e = result.expect_err("dotenv variables are unknown");
+    assert_eq!(
+        e.to_string(),
+        "unknown dotenv variable(s): SECRET_KEY" // per .env
+    );
+}
@@ -0,0 +1,23 @@
+//! use .env, then builtin content; builtin values never override
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        Some(Box::new(
+            "APP_ENV=builtin\nBUILTIN_KEY=from_builtin\n".as_bytes(),
+        ))
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    common::using_prod_env()?;
+    assert_eq!(std::env::var("BUILTIN_KEY")?, String::from("from_builtin"));
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! forward the resolved environment (incl. variables filtered by the prefix) to child processes
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture(".prefixed")])
+    }
+
+    fn dotenv_prefix(&self) -> Option<String> {
+        Some(String::from("MYAPP_"))
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert!(std::env::var("SHARED_DB").is_err()); // filtered by the prefix
+
+    let env = args.dotenv_child_env()?;
+    assert_eq!(env.get("SHARED_DB"), Some("postgres://shared"));
+    assert_eq!(env.get("MYAPP_PORT"), Some("8080"));
+    assert_eq!(env.get("APP_ENV"), Some("production")); // from .env
+    assert!(std::env::var("SHARED_DB").is_err()); // process environment isn't mutated
+
+    #[cfg(unix)]
+    {
+        let output = env
+            .command("/bin/sh")
+            .args(["-c", "printf %s \"$SHARED_DB\""])
+            .output()?;
+        assert_eq!(String::from_utf8(output.stdout)?, "postgres://shared");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! use .env, then .env.staging, .env.local & .env.staging.local; allow later files to override
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_can_override(&self) -> bool {
+        true
+    }
+
+    fn dotenv_environment(&self) -> Option<String> {
+        Some(String::from("staging"))
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    // the .env.{staging,local} files are in the fixtures; .env is found in a parent directory
+    std::env::set_current_dir(common::fixture(""))?;
+
+    <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(entrypoint)
+}
+
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    common::using_prod_env()?;
+
+    assert_eq!(std::env::var("STAGING_KEY")?, String::from("from_staging"));
+    assert_eq!(std::env::var("PORT")?, String::from("8080"));
+    assert_eq!(std::env::var("LOCAL_KEY")?, String::from("from_local"));
+    assert_eq!(
+        std::env::var("STAGING_SHARED")?,
+        String::from("from_staging_local")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! use .env, then .env.staging, .env.local & .env.staging.local; more specific files override w/o `dotenv_can_override`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_environment(&self) -> Option<String> {
+        Some(String::from("staging"))
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    // the .env.{staging,local} files are in the fixtures; .env is found in a parent directory
+    std::env::set_current_dir(common::fixture(""))?;
+    std::env::set_var("STAGING_KEY", "from_environment");
+
+    let args = <common::Args as entrypoint::Entrypoint>::parse_args();
+    assert!(!args.dotenv_can_override());
+    args.entrypoint(entrypoint)
+}
+
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    common::using_prod_env()?;
+
+    assert_eq!(std::env::var("PORT")?, String::from("8080")); // .env.staging over .env
+    assert_eq!(std::env::var("LOCAL_KEY")?, String::from("from_local"));
+    assert_eq!(
+        std::env::var("STAGING_SHARED")?,
+        String::from("from_staging_local")
+    );
+
+    // preexisting variables are still preserved
+    assert_eq!(
+        std::env::var("STAGING_KEY")?,
+        String::from("from_environment")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! expand wildcards in `additional_dotenv_files`; process matches in sorted order w/ override
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture("conf.d/*.env")])
+    }
+
+    fn dotenv_can_override(&self) -> bool {
+        true
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    common::using_prod_env()?;
+
+    assert_eq!(std::env::var("FRAGMENT_A")?, String::from("yes"));
+    assert_eq!(std::env::var("FRAGMENT_B")?, String::from("yes"));
+    assert_eq!(std::env::var("FRAGMENT")?, String::from("b")); // last wins
+    assert!(std::env::var("FRAGMENT_IGNORED").is_err());
+
+    Ok(())
+}
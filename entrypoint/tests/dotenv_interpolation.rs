@@ -0,0 +1,30 @@
+//! expand ${VAR} references in dotenv values; undefined variables are empty (i.e. lenient)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture(".interpolate")])
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    common::using_prod_env()?;
+
+    // IP per .env
+    assert_eq!(
+        std::env::var("INTERP_URL")?,
+        String::from("postgres://admin:@192.168.255.255/app")
+    );
+    assert_eq!(
+        std::env::var("INTERP_LITERAL")?,
+        String::from("${INTERP_UNDEFINED}")
+    );
+
+    Ok(())
+}
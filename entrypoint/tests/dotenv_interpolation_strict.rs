@@ -0,0 +1,28 @@
+//! referencing an undefined variable in a dotenv value is an error (i.e. strict)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture(".interpolate")])
+    }
+
+    fn dotenv_strict_interpolation(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("INTERP_PASS is undefined");
+        });
+
+    let e = format!("{:#}", result.expect_err("INTERP_PASS is undefined"));
+    assert!(e.ends_with(".interpolate: INTERP_PASS"), "{e}");
+    assert!(std::env::var("INTERP_URL").is_err());
+}
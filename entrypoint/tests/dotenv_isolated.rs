@@ -0,0 +1,37 @@
+//! isolated dotenv processing merges into an `EnvMap` w/o mutating the process environment
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_isolated(&self) -> bool {
+        true
+    }
+
+    fn required_env_vars(&self) -> Vec<String> {
+        vec![String::from("SECRET_KEY")] // only defined in the EnvMap
+    }
+
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        Some(Box::new(&b"ISOLATED_BUILTIN=yes\n"[..]))
+    }
+}
+
+#[entrypoint::entrypoint(env)]
+#[test]
+fn entrypoint(_args: common::Args, env: EnvMap) -> entrypoint::anyhow::Result<()> {
+    // from .env
+    assert_eq!(env.get("APP_ENV"), Some("production"));
+    assert_eq!(env.get("SECRET_KEY"), Some("BUT_NOT_REALLY"));
+    assert_eq!(env.get("ISOLATED_BUILTIN"), Some("yes"));
+    // preexisting
+    assert_eq!(env.get("CARGO_PKG_NAME"), Some("entrypoint"));
+
+    assert!(std::env::var("APP_ENV").is_err());
+    assert!(std::env::var("SECRET_KEY").is_err());
+    assert!(std::env::var("ISOLATED_BUILTIN").is_err());
+
+    Ok(())
+}
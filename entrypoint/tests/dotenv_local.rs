@@ -0,0 +1,45 @@
+//! use .env.local unless in the production environment (incl. w/ no environment set)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+#[derive(entrypoint::clap::Parser, Clone, Debug)]
+struct Args {
+    #[arg(long)]
+    environment: Option<String>,
+}
+
+impl DotEnvParserConfig for Args {
+    fn dotenv_environment(&self) -> Option<String> {
+        self.environment.clone()
+    }
+}
+
+#[test]
+fn dotenv_local() -> entrypoint::anyhow::Result<()> {
+    // .env.local is in the fixtures; .env is found in a parent directory
+    std::env::set_current_dir(common::fixture(""))?;
+
+    for (environment, loaded) in [
+        (None, true),
+        (Some("production"), false),
+        (Some("development"), true),
+    ] {
+        let args = Args {
+            environment: environment.map(String::from),
+        };
+        assert!(args.dotenv_local());
+
+        let env = args.dotenv_env_map()?;
+        assert_eq!(env.get("APP_ENV"), Some("production")); // from .env
+        assert_eq!(
+            env.get("LOCAL_KEY"),
+            loaded.then_some("from_local"),
+            "{environment:?}"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! `dotenv_required` attribute fails startup when .env is missing
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[dotenv_required]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() {
+    // i.e. not found in the current directory (nor its parents)
+    std::env::set_current_dir(std::env::temp_dir()).expect("temp dir");
+
+    let result: entrypoint::anyhow::Result<()> = <Args as entrypoint::Entrypoint>::parse_args()
+        .entrypoint(|_args| {
+            unreachable!(".env is missing");
+        });
+
+    assert_eq!(
+        result.expect_err(".env is missing").to_string(),
+        "required .env file not found"
+    );
+}
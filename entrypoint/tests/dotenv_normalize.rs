@@ -0,0 +1,63 @@
+//! normalize dotenv keys & detect (case) collisions
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+/// e.g. a mounted secret named `log-level`
+struct Mounted;
+
+impl EnvSource for Mounted {
+    fn name(&self) -> String {
+        String::from("mounted")
+    }
+
+    fn vars(&self) -> entrypoint::anyhow::Result<Vec<(String, String)>> {
+        Ok(vec![(String::from("log-level"), String::from("TRACE"))])
+    }
+}
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture(".mixed")])
+    }
+
+    fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+        vec![Box::new(Mounted)]
+    }
+
+    fn dotenv_normalize_keys(&self) -> bool {
+        true
+    }
+
+    fn dotenv_key_collisions(&self) -> DotEnvAudit {
+        DotEnvAudit::Deny
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("dotenv variables collide");
+        });
+
+    let e = result.expect_err("dotenv variables collide");
+    assert_eq!(
+        e.to_string(),
+        "colliding dotenv variable(s): DB_URL/db.url, LOG_LEVEL/log-level"
+    );
+
+    // first processed value wins (w/o override)
+    assert_eq!(
+        std::env::var("DB_URL").ok().as_deref(),
+        Some("postgres://first")
+    );
+    assert_eq!(
+        std::env::var("API_KEY").ok().as_deref(),
+        Some("NOT_A_REAL_KEY")
+    );
+    assert_eq!(std::env::var("LOG_LEVEL").ok().as_deref(), Some("WARN")); // from .env
+    assert!(std::env::var("db.url").is_err());
+}
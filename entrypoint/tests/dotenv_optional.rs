@@ -0,0 +1,27 @@
+//! missing optional dotenv files are skipped; present ones are processed in order
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_files(&self) -> Vec<DotEnvFile> {
+        vec![
+            DotEnvFile::Optional(std::path::PathBuf::from(".missing_overlay")),
+            DotEnvFile::Optional(std::path::PathBuf::from("missing.d/*.env")),
+            DotEnvFile::Required(std::path::PathBuf::from(".dev")),
+            DotEnvFile::Optional(common::fixture(".prefixed")),
+        ]
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(std::env::var("APP_ENV")?, String::from("production")); // .env (w/o override)
+    assert_eq!(std::env::var("TEST_KEY")?, String::from("NOT_A_SECRET_KEY")); // .dev
+    assert_eq!(std::env::var("MYAPP_PORT")?, String::from("8080")); // .prefixed
+
+    Ok(())
+}
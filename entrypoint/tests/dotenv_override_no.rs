@@ -1,5 +1,7 @@
 //! use both .env and .dev; DO NOT allow .dev to override
 
+#![allow(unused_crate_dependencies)]
+
 use entrypoint::prelude::*;
 mod common;
 
@@ -20,7 +22,7 @@ fn entrypoint(args: common::Args) -> entrypoint::anyhow::Result<()> {
 
     common::verify_log_level(
         &args,
-        &entrypoint::tracing_subscriber::filter::LevelFilter::WARN,
+        entrypoint::tracing_subscriber::filter::LevelFilter::WARN,
     )?;
 
     Ok(())
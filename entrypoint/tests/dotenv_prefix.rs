@@ -0,0 +1,39 @@
+//! only import dotenv variables w/ a prefix; stripping it
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![common::fixture(".prefixed")])
+    }
+
+    fn dotenv_prefix(&self) -> Option<String> {
+        Some(String::from("MYAPP_"))
+    }
+
+    fn dotenv_strip_prefix(&self) -> bool {
+        true
+    }
+
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        Some(Box::new(&b"MYAPP_BUILTIN=yes\nUNPREFIXED=no\n"[..]))
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(std::env::var("PORT")?, String::from("8080"));
+    assert_eq!(std::env::var("NAME")?, String::from("prefixed"));
+    assert_eq!(std::env::var("BUILTIN")?, String::from("yes"));
+
+    assert!(std::env::var("MYAPP_PORT").is_err());
+    assert!(std::env::var("SHARED_DB").is_err());
+    assert!(std::env::var("UNPREFIXED").is_err());
+    assert!(std::env::var("APP_ENV").is_err()); // from .env
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! record which source set each variable
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![std::path::PathBuf::from(".dev")])
+    }
+
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        Some(Box::new(&b"PROVENANCE_BUILTIN=yes\n"[..]))
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    let file = |origin: Option<EnvOrigin>| match origin {
+        Some(EnvOrigin::File(path)) => path.file_name().map(std::ffi::OsStr::to_owned),
+        _ => None,
+    };
+
+    assert_eq!(file(entrypoint::env_origin("APP_ENV")), Some(".env".into()));
+    assert_eq!(
+        file(entrypoint::env_origin("TEST_KEY")),
+        Some(".dev".into())
+    );
+    assert_eq!(
+        entrypoint::env_origin("PROVENANCE_BUILTIN"),
+        Some(EnvOrigin::Builtin)
+    );
+    assert_eq!(
+        entrypoint::env_origin("CARGO_PKG_NAME"),
+        Some(EnvOrigin::Environment)
+    );
+    assert_eq!(entrypoint::env_origin("PROVENANCE_UNSET"), None);
+    assert!(entrypoint::env_origins().contains_key("PORT"));
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! report every missing required env var in a single error
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn required_env_vars(&self) -> Vec<String> {
+        vec![
+            String::from("APP_ENV"), // per .env
+            String::from("REQUIRED_MISSING_1"),
+            String::from("REQUIRED_MISSING_2"),
+        ]
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("required env vars are missing");
+        });
+
+    let e = result.expect_err("required env vars are missing");
+    assert_eq!(
+        e.to_string(),
+        "missing required environment variable(s): REQUIRED_MISSING_1, REQUIRED_MISSING_2"
+    );
+}
@@ -0,0 +1,27 @@
+//! stop searching parent directories at the package directory; .env is in the workspace root
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![std::path::PathBuf::from(".dev")])
+    }
+
+    fn dotenv_search_boundary(&self) -> Option<std::path::PathBuf> {
+        Some(std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("additional_dotenv_files are required");
+        });
+
+    assert!(result.is_err());
+    assert!(std::env::var("APP_ENV").is_err());
+}
@@ -0,0 +1,22 @@
+//! don't search parent directories; .env is in the workspace root (i.e. not the current directory)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_search_parents(&self) -> bool {
+        false
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert!(std::path::Path::new("Cargo.toml").is_file()); // i.e. the package directory
+    assert!(std::env::var("APP_ENV").is_err());
+    assert!(std::env::var("SECRET_KEY").is_err());
+
+    Ok(())
+}
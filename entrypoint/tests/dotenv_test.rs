@@ -0,0 +1,37 @@
+//! prefer .env.test over .env when compiled for tests (per `DotEnvDefault`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("entrypoint-dotenv-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join(".env"),
+        "DOTENV_TEST_FILE=.env\nDOTENV_TEST_ENV_ONLY=1\n",
+    )?;
+    std::fs::write(dir.join(".env.test"), "DOTENV_TEST_FILE=.env.test\n")?;
+    std::fs::write(dir.join(".env.local"), "DOTENV_TEST_LOCAL=1\n")?;
+    std::env::set_current_dir(&dir)?;
+
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.dotenv_test());
+
+        assert_eq!(std::env::var("DOTENV_TEST_FILE")?, ".env.test");
+        assert!(std::env::var("DOTENV_TEST_ENV_ONLY").is_err());
+        assert!(std::env::var("DOTENV_TEST_LOCAL").is_err());
+        Ok(())
+    });
+
+    std::fs::remove_dir_all(&dir)?;
+    result
+}
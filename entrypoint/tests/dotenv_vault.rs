@@ -0,0 +1,33 @@
+//! decrypt & use .env.vault (in place of .env) when a key is supplied
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "vault")]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn dotenv_vault_key(&self) -> Option<String> {
+        // first (i.e. rotated out) key doesn't match; second does
+        Some(String::from(
+            "dotenv://:key_00000000000000000000000000000000000000000000000000000000000000ff@dotenv.org/vault/.env.vault?environment=development,\
+             dotenv://:key_e31ef0ce8c2e4e5ba4f7c55ec8a2bfcd6ad8d2f4a1d1f0f9e0c6c5f7f9d2b4a1@dotenv.org/vault/.env.vault?environment=production",
+        ))
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(
+        std::env::var("VAULT_SECRET")?,
+        String::from("from_vault_production")
+    );
+    assert_eq!(
+        std::env::var("VAULT_ENVIRONMENT")?,
+        String::from("production")
+    );
+    assert!(std::env::var("SECRET_KEY").is_err()); // plaintext .env is skipped
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! `watch` feature reloads dotenv files on change & reports the changed keys
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "watch")]
+
+use entrypoint::prelude::*;
+use std::sync::mpsc;
+use std::time::Duration;
+
+static CHANGED: std::sync::Mutex<Option<mpsc::Sender<Vec<String>>>> = std::sync::Mutex::new(None);
+
+#[derive(entrypoint::clap::Parser, EntrypointDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+struct Args {}
+
+impl DotEnvParserConfig for Args {
+    fn dotenv_search_parents(&self) -> bool {
+        false
+    }
+
+    fn dotenv_watch(&self) -> bool {
+        true
+    }
+
+    fn dotenv_on_reload(&self, changed: &[String]) {
+        if let Some(sender) = CHANGED.lock().expect("lock").as_ref() {
+            sender.send(changed.to_vec()).expect("send");
+        }
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("entrypoint-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join(".env"),
+        "WATCH_KEEP=same\nWATCH_EDIT=before\nWATCH_DROP=gone\n",
+    )?;
+    std::env::set_current_dir(&dir)?;
+
+    let (sender, receiver) = mpsc::channel();
+    *CHANGED.lock().expect("lock") = Some(sender);
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        assert_eq!(std::env::var("WATCH_EDIT")?, "before");
+
+        std::fs::write(
+            dir.join(".env"),
+            "WATCH_KEEP=same\nWATCH_EDIT=after\nWATCH_ADD=new\n",
+        )?;
+
+        let changed = receiver.recv_timeout(Duration::from_secs(10))?;
+        assert_eq!(changed, ["WATCH_ADD", "WATCH_DROP", "WATCH_EDIT"]);
+        assert_eq!(std::env::var("WATCH_EDIT")?, "after");
+        assert_eq!(std::env::var("WATCH_ADD")?, "new");
+        assert!(std::env::var("WATCH_DROP").is_err());
+        assert_eq!(std::env::var("WATCH_KEEP")?, "same");
+
+        Ok(std::fs::remove_dir_all(&dir)?)
+    })
+}
@@ -0,0 +1,36 @@
+//! `--dump-env` resolved environment w/ masked secrets
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[command(name = "dumper")]
+#[log_redact("*_PIN")]
+struct Args {}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn dump_env_flag() -> bool {
+        true
+    }
+}
+
+#[test]
+fn dump_env() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("DUMP_PLAIN", "visible");
+    std::env::set_var("DUMP_API_TOKEN", "hunter2");
+    std::env::set_var("DUMP_PIN", "1234");
+
+    let args = Args::try_parse_from(["dumper"])?.process_dotenv_files()?;
+    let dump = args.dump_env();
+    let lines: Vec<&str> = dump.lines().collect();
+
+    assert!(lines.contains(&"DUMP_PLAIN=visible"));
+    assert!(lines.contains(&"DUMP_API_TOKEN=[REDACTED]"));
+    assert!(lines.contains(&"DUMP_PIN=[REDACTED]"));
+    assert!(lines.contains(&"SECRET_KEY=[REDACTED]")); // from .env
+    assert!(lines.contains(&"APP_ENV=production"));
+    assert!(lines.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    Ok(())
+}
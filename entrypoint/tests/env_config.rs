@@ -0,0 +1,40 @@
+//! typed config extracted from the (dotenv populated) environment
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {}
+
+#[derive(serde::Deserialize, Debug)]
+struct Config {
+    app_env: String,
+    port: u16,
+    ip: std::net::IpAddr,
+    missing: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Invalid {
+    #[allow(dead_code)]
+    app_env: u8,
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args, config: Config) -> entrypoint::anyhow::Result<()> {
+    // from .env
+    assert_eq!(config.app_env, String::from("production"));
+    assert_eq!(config.port, 80);
+    assert_eq!(config.ip, std::net::IpAddr::from([192, 168, 255, 255]));
+
+    assert_eq!(config.missing, None);
+    assert!(config.tags.is_empty());
+
+    assert!(entrypoint::from_env::<Invalid>(None).is_err());
+
+    Ok(())
+}
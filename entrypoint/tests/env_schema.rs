@@ -0,0 +1,32 @@
+//! report every invalid env var (per the schema) in a single error
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+impl DotEnvParserConfig for common::Args {
+    fn env_schema(&self) -> Vec<EnvRule> {
+        vec![
+            EnvRule::new("PORT").parse::<u16>(), // per .env (80)
+            EnvRule::new("PORT").range(1024..=65535_u16),
+            EnvRule::new("APP_ENV").one_of(["dev", "staging", "prod"]), // per .env (production)
+            EnvRule::new("IP").parse::<std::net::IpAddr>(),             // per .env
+            EnvRule::new("SCHEMA_UNSET").parse::<u16>(),
+        ]
+    }
+}
+
+#[test]
+fn main() {
+    let result: entrypoint::anyhow::Result<()> =
+        <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+            unreachable!("env vars are invalid");
+        });
+
+    let e = result.expect_err("env vars are invalid");
+    assert_eq!(
+        e.to_string(),
+        "invalid environment variable(s):\n  PORT=\"80\": expected a value within 1024..=65535\n  APP_ENV=\"production\": expected one of dev, staging, prod"
+    );
+}
@@ -0,0 +1,45 @@
+//! load a (k8s style) mounted secret directory as env vars
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+fn secrets() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("entrypoint-secrets-{}", std::process::id()))
+}
+
+impl DotEnvParserConfig for common::Args {
+    fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+        vec![
+            Box::new(SecretDir::new(secrets())),
+            Box::new(SecretDir::new(secrets().join("missing")).optional()),
+        ]
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let dir = secrets();
+    std::fs::create_dir_all(dir.join("..2024_01_01"))?;
+    std::fs::write(dir.join("DB_PASSWORD"), "hunter2\n")?;
+    std::fs::write(dir.join("API_TOKEN"), "multi\nline")?;
+    std::fs::write(dir.join("PORT"), "8080")?; // per .env, w/o override
+    std::fs::write(dir.join("..data"), "ignored")?;
+
+    let result = <common::Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        assert_eq!(std::env::var("DB_PASSWORD")?, "hunter2");
+        assert_eq!(std::env::var("API_TOKEN")?, "multi\nline");
+        assert_eq!(std::env::var("PORT")?, "80");
+        assert!(std::env::var("..data").is_err());
+
+        assert_eq!(
+            entrypoint::env_origin("DB_PASSWORD"),
+            Some(EnvOrigin::Source(secrets().display().to_string()))
+        );
+        Ok(())
+    });
+
+    std::fs::remove_dir_all(&dir)?;
+    result
+}
@@ -0,0 +1,43 @@
+//! merge custom env sources after dotenv files, in order (w/o overriding)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+mod common;
+
+struct Static(Vec<(&'static str, &'static str)>);
+
+impl EnvSource for Static {
+    fn name(&self) -> String {
+        String::from("static")
+    }
+
+    fn vars(&self) -> entrypoint::anyhow::Result<Vec<(String, String)>> {
+        Ok(self
+            .0
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect())
+    }
+}
+
+impl DotEnvParserConfig for common::Args {
+    fn env_sources(&self) -> Vec<Box<dyn EnvSource>> {
+        vec![
+            Box::new(Static(vec![
+                ("SOURCE_KEY", "from_source"),
+                ("PORT", "8080"),
+            ])),
+            Box::new(Static(vec![("SOURCE_KEY", "from_second_source")])),
+        ]
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: common::Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(std::env::var("SOURCE_KEY")?, String::from("from_source"));
+    assert_eq!(std::env::var("PORT")?, String::from("80")); // from .env
+
+    Ok(())
+}
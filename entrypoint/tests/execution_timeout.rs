@@ -0,0 +1,39 @@
+//! entrypoint function is aborted (w/ exit code 124) after the `timeout`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+/// set for the re-executed (i.e. child) test process
+const CHILD: &str = "ENTRYPOINT_EXECUTION_TIMEOUT_CHILD";
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+// i.e. `main`, run by the child test process
+#[entrypoint::entrypoint(timeout = "50ms")]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+    std::thread::sleep(std::time::Duration::from_secs(10));
+    Ok(())
+}
+
+#[test]
+fn execution_timeout() {
+    if std::env::var_os(CHILD).is_some() {
+        let _ = main();
+        unreachable!("watchdog should have exited the process");
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().expect("test binary path"))
+        .env(CHILD, "1")
+        .output()
+        .expect("re-executed test binary");
+
+    assert_eq!(output.status.code(), Some(124));
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .contains("entrypoint function timed out after 50ms; aborting"));
+}
@@ -0,0 +1,22 @@
+//! entrypoint function may return any `Termination` (e.g. `ExitCode`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use std::process::ExitCode;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<ExitCode> {
+    info!("{args:?}");
+
+    // the generated `main` reports this (i.e. `Termination`) as the exit code
+    Ok(ExitCode::SUCCESS)
+}
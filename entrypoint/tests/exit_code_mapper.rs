@@ -0,0 +1,56 @@
+//! errors are mapped to an exit code (i.e. `EntrypointConfig::exit_code`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use std::process::ExitCode;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[derive(Debug)]
+enum AppError {
+    Usage,
+    Partial,
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn exit_code(error: &entrypoint::anyhow::Error) -> ExitCode {
+        match error.downcast_ref::<AppError>() {
+            Some(AppError::Usage) => ExitCode::from(2),
+            Some(AppError::Partial) => ExitCode::from(3),
+            None => ExitCode::FAILURE,
+        }
+    }
+}
+
+#[test]
+fn exit_code_mapper() {
+    assert_eq!(Args::exit(Ok(())), ExitCode::SUCCESS);
+    assert_eq!(Args::exit(Ok(ExitCode::from(4))), ExitCode::from(4));
+
+    assert_eq!(
+        Args::exit::<()>(Err(AppError::Usage.into())),
+        ExitCode::from(2)
+    );
+    assert_eq!(
+        Args::exit::<()>(Err(
+            entrypoint::anyhow::Error::from(AppError::Partial).context("while working")
+        )),
+        ExitCode::from(3)
+    );
+    assert_eq!(
+        Args::exit::<()>(Err(entrypoint::anyhow::anyhow!("unexpected"))),
+        ExitCode::FAILURE
+    );
+}
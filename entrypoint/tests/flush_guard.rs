@@ -0,0 +1,52 @@
+//! `FlushGuard` flushes non-blocking output, even if the entrypoint function panics
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+/// slow writer, so non-blocking lines are still buffered when the function panics
+#[derive(Clone)]
+struct Slow;
+
+impl std::io::Write for Slow {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        CaptureWriter::global().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn slow() -> Slow {
+    Slow
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(slow)]
+#[log_non_blocking]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() {
+    let panicked = std::panic::catch_unwind(|| {
+        <Args as entrypoint::Entrypoint>::parse_args().entrypoint(
+            |_args| -> entrypoint::anyhow::Result<()> {
+                for i in 0..3 {
+                    info!("flush marker {i}");
+                }
+                panic!("entrypoint function panicked");
+            },
+        )
+    });
+    assert!(panicked.is_err());
+
+    // flushed while unwinding
+    CaptureWriter::global().assert_contains("flush marker 2");
+}
@@ -0,0 +1,74 @@
+//! `gelf` feature sends GELF messages to `GELF_HOST`:`GELF_PORT`
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "gelf")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+/// receive the next (possibly chunked) GELF message
+fn recv(server: &std::net::UdpSocket) -> entrypoint::anyhow::Result<serde_json::Value> {
+    let mut buf = [0; 8192];
+    let mut chunks = std::collections::BTreeMap::new();
+
+    loop {
+        let len = server.recv(&mut buf)?;
+        let datagram = &buf[..len];
+
+        if !datagram.starts_with(&[0x1e, 0x0f]) {
+            return Ok(serde_json::from_slice(datagram)?);
+        }
+
+        chunks.insert(datagram[10], datagram[12..].to_vec());
+        if chunks.len() == usize::from(datagram[11]) {
+            return Ok(serde_json::from_slice(
+                &chunks.into_values().flatten().collect::<Vec<_>>(),
+            )?);
+        }
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let server = std::net::UdpSocket::bind("127.0.0.1:0")?;
+    server.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+    std::env::set_var("GELF_HOST", "127.0.0.1");
+    std::env::set_var("GELF_PORT", server.local_addr()?.port().to_string());
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        info_span!("request").in_scope(|| warn!(id = 7, answer = 42, "gelf marker"));
+
+        let message = loop {
+            let message = recv(&server)?;
+            if message["short_message"] == "gelf marker" {
+                break message;
+            }
+        };
+        assert_eq!(message["version"], "1.1");
+        assert_eq!(message["level"], 4);
+        assert_eq!(message["_app"], "entrypoint");
+        assert_eq!(message["_span"], "request");
+        assert_eq!(message["_id_"], 7);
+        assert_eq!(message["_answer"], 42);
+
+        // chunked
+        let large = "x".repeat(20_000);
+        info!(large, "gelf large");
+        let message = loop {
+            let message = recv(&server)?;
+            if message["short_message"] == "gelf large" {
+                break message;
+            }
+        };
+        assert_eq!(message["_large"], large.as_str());
+
+        Ok(())
+    })
+}
@@ -0,0 +1,58 @@
+//! `health` feature serves `/healthz` & `/readyz` on `HEALTH_ADDR`
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "health")]
+
+use entrypoint::prelude::*;
+use std::io::{Read, Write};
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+/// GET `path`, returning the status line
+fn probe(addr: std::net::SocketAddr, path: &str) -> entrypoint::anyhow::Result<String> {
+    let mut stream = std::net::TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+    write!(
+        stream,
+        "GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"
+    )?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response.lines().next().unwrap_or_default().to_owned())
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    std::env::set_var("HEALTH_ADDR", addr.to_string());
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        assert_eq!(probe(addr, "/healthz")?, "HTTP/1.1 200 OK");
+        assert_eq!(probe(addr, "/readyz")?, "HTTP/1.1 200 OK");
+        assert_eq!(probe(addr, "/other")?, "HTTP/1.1 404 Not Found");
+
+        entrypoint::set_ready(false);
+        assert_eq!(probe(addr, "/healthz")?, "HTTP/1.1 200 OK");
+        assert_eq!(probe(addr, "/readyz")?, "HTTP/1.1 503 Service Unavailable");
+        entrypoint::set_ready(true);
+
+        entrypoint::set_healthy(false);
+        assert_eq!(probe(addr, "/healthz")?, "HTTP/1.1 503 Service Unavailable");
+        assert_eq!(probe(addr, "/readyz")?, "HTTP/1.1 503 Service Unavailable");
+        entrypoint::set_healthy(true);
+
+        Ok(())
+    })?;
+
+    // the function returned; no longer ready
+    assert_eq!(probe(addr, "/healthz")?, "HTTP/1.1 200 OK");
+    assert_eq!(probe(addr, "/readyz")?, "HTTP/1.1 503 Service Unavailable");
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! setup w/o handing control to a closure (i.e. `Entrypoint::init`)
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+mod common;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::DEBUG)]
+#[log_writer(CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn init() -> entrypoint::anyhow::Result<()> {
+    let _flush = entrypoint::FlushGuard::new();
+    let _args = Args::parse_args().init()?;
+
+    common::using_prod_env()?;
+
+    // setup events were replayed through the configured writer
+    let lines = CaptureWriter::global().lines();
+    assert!(
+        lines.iter().any(|line| line.contains("dotenv")),
+        "{lines:?}"
+    );
+    assert!(enabled!(entrypoint::Level::DEBUG));
+
+    // the global subscriber is already registered
+    assert!(Args::parse_args().init().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! `default_log_ansi` auto-detection
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("NO_COLOR", "1");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(!args.default_log_ansi()); // --color=auto w/ NO_COLOR
+
+        info!("log_ansi marker");
+
+        let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+        assert!(captured.contains("log_ansi marker"));
+        assert!(!captured.contains('\x1b'));
+
+        Ok(())
+    })
+}
@@ -0,0 +1,37 @@
+//! `log_app_metadata` attribute attaches app metadata fields to events
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(json)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(CaptureWriter::global)]
+#[log_app_metadata]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_app_metadata());
+
+    let captured = CaptureWriter::global();
+    captured.clear();
+
+    info_span!("request").in_scope(|| info!("app metadata marker"));
+
+    let line: serde_json::Value = serde_json::from_str(&captured.lines()[0])?;
+    let app = &line["spans"][0];
+    assert_eq!(app["name"], "app");
+    assert_eq!(app["app.name"], "entrypoint");
+    assert_eq!(app["app.version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(app["pid"], std::process::id());
+    assert_eq!(line["spans"][1]["name"], "request");
+
+    Ok(())
+}
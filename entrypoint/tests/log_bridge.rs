@@ -0,0 +1,53 @@
+//! `log` feature forwards `log` crate records
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "log")]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    tracing_log::log::info!("log_bridge marker");
+    tracing_log::log::debug!("log_bridge filtered");
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    let line = captured
+        .lines()
+        .find(|line| line.contains("log_bridge marker"))
+        .expect("log record forwarded");
+    assert!(
+        line.contains("INFO log_bridge: log_bridge marker"),
+        "{line}"
+    );
+    assert!(!captured.contains("log_bridge filtered"), "{captured}");
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! `log_format(bunyan)` attribute writes bunyan JSON
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "bunyan")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(bunyan)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(entrypoint::testing::CaptureWriter::global)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_bunyan/app.log"))]
+#[command(name = "bunyan-app", author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_bunyan());
+    let file = args.default_log_file().expect("log_file attribute");
+
+    let marker = format!("log_bunyan marker {:?}", std::time::SystemTime::now());
+    info_span!("request", id = 7).in_scope(|| {
+        info!(answer = 42, "{marker}");
+    });
+
+    let find = |output: &str| -> entrypoint::anyhow::Result<serde_json::Value> {
+        let line = output
+            .lines()
+            .find(|line| line.contains(&marker))
+            .ok_or_else(|| entrypoint::anyhow::anyhow!("marker logged: {output}"))?;
+        Ok(serde_json::from_str(line)?)
+    };
+
+    for output in [
+        entrypoint::testing::CaptureWriter::global().snapshot(),
+        std::fs::read_to_string(&file)?,
+    ] {
+        let record = find(&output)?;
+        assert_eq!(record["v"], 0, "{record}");
+        assert_eq!(record["name"], "bunyan-app", "{record}");
+        assert_eq!(record["level"], 30, "{record}");
+        assert!(
+            record["msg"]
+                .as_str()
+                .is_some_and(|msg| msg.ends_with(&marker)),
+            "{record}"
+        );
+        assert_eq!(record["answer"], 42, "{record}");
+        assert_eq!(record["id"], 7, "{record}");
+        assert!(record["hostname"].is_string(), "{record}");
+        assert!(record["pid"].is_number(), "{record}");
+        assert!(record["time"].is_string(), "{record}");
+    }
+
+    Ok(())
+}
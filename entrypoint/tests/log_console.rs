@@ -0,0 +1,25 @@
+//! `log_writer(console)` attribute selects the browser console
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "wasm")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(console)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    // stdout on non-wasm32 targets; logging must still work
+    warn!("log_console marker (console.warn)");
+    info!("log_console marker (console.info)");
+    assert!(enabled!(entrypoint::Level::INFO));
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! `log_directives` attribute w/ static per-target directives
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::WARN)]
+#[log_directives("log_directives=debug,noisy_crate=error")]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(enabled!(entrypoint::Level::DEBUG));
+    assert!(!enabled!(entrypoint::Level::TRACE));
+    assert!(!enabled!(target: "noisy_crate", entrypoint::Level::WARN));
+    assert!(enabled!(target: "some_other_crate", entrypoint::Level::WARN)); // default directive
+    assert!(!enabled!(target: "some_other_crate", entrypoint::Level::INFO));
+    Ok(())
+}
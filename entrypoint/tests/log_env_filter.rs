@@ -0,0 +1,26 @@
+//! `log_env_filter` attribute w/ per-target directives
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::WARN)]
+#[log_env_filter("ENTRYPOINT_TEST_LOG")]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("ENTRYPOINT_TEST_LOG", "log_env_filter=trace");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        assert!(enabled!(entrypoint::Level::TRACE));
+        assert!(!enabled!(target: "some_other_crate", entrypoint::Level::INFO)); // default directive
+        assert!(enabled!(target: "some_other_crate", entrypoint::Level::WARN));
+        Ok(())
+    })
+}
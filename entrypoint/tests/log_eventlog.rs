@@ -0,0 +1,29 @@
+//! `log_writer(eventlog)` attribute selects the Windows Event Log
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "eventlog")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(eventlog)]
+#[command(name = "eventlog-test")]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(
+        args.default_log_eventlog().as_deref(),
+        Some("eventlog-test")
+    );
+
+    // ignored on non-Windows targets; logging must still work
+    info!("log_eventlog test");
+    assert!(enabled!(entrypoint::Level::INFO));
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! `log_file` attribute writes logs to file
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_file/app.log"))]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    let file = args.default_log_file().expect("log_file attribute");
+
+    let marker = format!("log_file marker {:?}", std::time::SystemTime::now());
+    info!("{marker}");
+
+    let contents = std::fs::read_to_string(&file)?;
+    assert!(contents.contains(&marker));
+    assert!(!contents.contains('\x1b')); // no ANSI escapes in files
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! `log_file` paths are expanded, parent directories created, and failures reported
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file("~/nested/logs/app.log")]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_file_path/not_a_dir/app.log"))]
+#[command(author, version, about, long_about = None)]
+struct Unopenable {}
+
+#[test]
+fn unopenable() -> entrypoint::anyhow::Result<()> {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("log_file_path");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("not_a_dir"), "")?;
+
+    let error = Unopenable::parse_from(["unopenable"])
+        .log_init(None)
+        .expect_err("parent is a file");
+
+    let message = format!("{error:#}");
+    assert!(
+        message.contains("failed to create log directory"),
+        "{message}"
+    );
+    assert!(message.contains("not_a_dir"), "{message}");
+
+    Ok(())
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let home = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("log_file_path_home");
+    let _ = std::fs::remove_dir_all(&home);
+    std::env::set_var("HOME", &home);
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        info!("log_file_path marker");
+
+        let contents = std::fs::read_to_string(home.join("nested/logs/app.log"))?;
+        assert!(contents.contains("log_file_path marker"));
+
+        Ok(())
+    })
+}
@@ -0,0 +1,32 @@
+//! `log_flame` attribute records flamegraph samples
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "flame")]
+
+use entrypoint::prelude::*;
+
+const FLAME: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/log_flame/tracing.folded");
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::WARN)]
+#[log_writer(std::io::sink)]
+#[log_flame(FLAME)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let _ = std::fs::remove_file(FLAME);
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        let span = info_span!("log_flame_span");
+        let _enter = span.enter();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        Ok(())
+    })?;
+
+    assert!(std::fs::read_to_string(FLAME)?.contains("log_flame_span"));
+    Ok(())
+}
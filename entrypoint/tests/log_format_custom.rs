@@ -0,0 +1,69 @@
+//! `log_format(custom = ...)` plugs in a user formatter
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+/// prefixes each event w/ the `#[command(name)]`
+struct Prefixed<F> {
+    prefix: String,
+    inner: F,
+}
+
+impl<S, N, F> FormatEvent<S, N> for Prefixed<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+    F: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &entrypoint::tracing_subscriber::fmt::FmtContext<'_, S, N>,
+        mut writer: entrypoint::tracing_subscriber::fmt::format::Writer<'_>,
+        event: &entrypoint::tracing::Event<'_>,
+    ) -> std::fmt::Result {
+        write!(writer, "[{}] ", self.prefix)?;
+        self.inner.format_event(ctx, writer, event)
+    }
+}
+
+fn prefixed<S, N>(args: &Args) -> impl FormatEvent<S, N> + Send + Sync + 'static
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+{
+    Prefixed {
+        prefix: <Args as entrypoint::clap::CommandFactory>::command()
+            .get_name()
+            .to_owned(),
+        inner: args.default_log_format_base().compact(),
+    }
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(custom = prefixed)]
+#[log_timestamp(none)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(CaptureWriter::global)]
+#[command(name = "custom-app", version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("log_format_custom marker");
+    debug!("log_format_custom filtered");
+
+    let line = CaptureWriter::global()
+        .lines()
+        .into_iter()
+        .find(|line| line.contains("log_format_custom marker"))
+        .expect("marker logged");
+    assert!(line.starts_with("[custom-app] "), "{line:?}");
+    CaptureWriter::global().assert_not_contains("log_format_custom filtered");
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! `log_writer(journald)` attribute selects the systemd journal
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "journald")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(journald)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_journald());
+
+    // journald may be unavailable (e.g. containers); logging must still work
+    info!("log_journald test");
+    assert!(enabled!(entrypoint::Level::INFO));
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! `log_json` attribute customizes json output
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(json)]
+#[log_json(
+    flatten_event,
+    with_span_list = false,
+    rename(message = "msg", level = "severity")
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_json_flatten_event());
+    assert!(args.default_log_json_with_current_span());
+    assert!(!args.default_log_json_with_span_list());
+
+    info_span!("outer", id = 7).in_scope(|| info!(answer = 42, "log_json marker"));
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    let line = captured
+        .lines()
+        .find(|line| line.contains("log_json marker"))
+        .expect("marker logged");
+    let event: serde_json::Value = serde_json::from_str(line)?;
+
+    assert_eq!(event["msg"], "log_json marker", "{line}");
+    assert_eq!(event["severity"], "INFO", "{line}");
+    assert_eq!(event["answer"], 42, "{line}"); // flattened
+    assert_eq!(event["span"]["name"], "outer", "{line}");
+    assert!(event.get("message").is_none(), "{line}");
+    assert!(event.get("fields").is_none(), "{line}");
+    assert!(event.get("spans").is_none(), "{line}");
+
+    Ok(())
+}
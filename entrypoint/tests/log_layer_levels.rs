@@ -0,0 +1,46 @@
+//! `log_writer_level` + `log_file_level` attributes filter each layer independently
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::WARN)]
+#[log_writer(entrypoint::testing::CaptureWriter::global)]
+#[log_writer_level(INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_layer_levels/app.log"))]
+#[log_file_level(entrypoint::LevelFilter::DEBUG)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(
+        args.default_log_writer_level(),
+        Some(entrypoint::LevelFilter::INFO)
+    );
+    assert_eq!(
+        args.default_log_file_level(),
+        Some(entrypoint::LevelFilter::DEBUG)
+    );
+    let file = args.default_log_file().expect("log_file attribute");
+
+    let marker = format!("log_layer_levels {:?}", std::time::SystemTime::now());
+    info!("{marker} info");
+    debug!("{marker} debug");
+    trace!("{marker} trace");
+
+    let captured = entrypoint::testing::CaptureWriter::global();
+    captured.assert_contains(&format!("{marker} info"));
+    captured.assert_not_contains(&format!("{marker} debug"));
+
+    let contents = std::fs::read_to_string(&file)?;
+    assert!(contents.contains(&format!("{marker} info")));
+    assert!(contents.contains(&format!("{marker} debug")));
+    assert!(!contents.contains(&format!("{marker} trace")));
+
+    Ok(())
+}
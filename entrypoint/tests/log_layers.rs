@@ -0,0 +1,50 @@
+//! `default_log_layers` registers additional layers alongside the default layer
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+static ERRORS: std::sync::LazyLock<CaptureWriter> = std::sync::LazyLock::new(CaptureWriter::new);
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, Clone, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl LoggerConfig for Args {
+    fn default_log_level(&self) -> LevelFilter {
+        LevelFilter::INFO
+    }
+
+    fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
+        CaptureWriter::global()
+    }
+
+    fn default_log_layers(
+        &self,
+    ) -> Vec<Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync + 'static>> {
+        vec![
+            self.default_log_layer(),
+            tracing_subscriber::fmt::Layer::default()
+                .with_writer(ERRORS.clone())
+                .with_filter(LevelFilter::ERROR)
+                .boxed(),
+        ]
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("info marker");
+    error!("error marker");
+
+    let default = CaptureWriter::global();
+    default.assert_contains("info marker");
+    default.assert_contains("error marker");
+
+    ERRORS.assert_not_contains("info marker");
+    ERRORS.assert_contains("error marker");
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! `#[log_level_env]` overrides `#[log_level]`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_level_env("APP_LOG_LEVEL")]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("APP_LOG_LEVEL", "debug");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(
+            args.default_log_level_env().as_deref(),
+            Some("APP_LOG_LEVEL")
+        );
+        assert_eq!(args.default_log_level(), LevelFilter::DEBUG);
+        assert!(enabled!(entrypoint::Level::DEBUG));
+        assert!(!enabled!(entrypoint::Level::TRACE));
+
+        // invalid values fall back to #[log_level]
+        std::env::set_var("APP_LOG_LEVEL", "loud");
+        assert_eq!(args.default_log_level(), LevelFilter::INFO);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,55 @@
+//! `log_format(logfmt)` writes key=value lines
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(logfmt)]
+#[log_timestamp(none)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    info_span!("outer", id = 7).in_scope(|| {
+        info_span!("inner").in_scope(|| info!(answer = 42, name = "a \"b\"", "log_logfmt marker"));
+    });
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    let line = captured
+        .lines()
+        .find(|line| line.contains("log_logfmt marker"))
+        .expect("marker logged");
+
+    assert_eq!(
+        line,
+        r#"level=info target=log_logfmt id=7 span=outer:inner msg="log_logfmt marker" answer=42 name="a \"b\"""#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! `log_non_blocking` attribute; buffered lines are flushed on exit
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_non_blocking/app.log"))]
+#[log_non_blocking]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let marker = format!("log_non_blocking marker {:?}", std::time::SystemTime::now());
+
+    let file = <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.default_log_non_blocking());
+        info!("{marker}");
+        Ok(args.default_log_file().expect("log_file attribute"))
+    })?;
+
+    assert!(std::fs::read_to_string(file)?.contains(&marker));
+
+    Ok(())
+}
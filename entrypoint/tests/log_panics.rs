@@ -0,0 +1,37 @@
+//! panics are logged through the subscriber
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let captured = CaptureWriter::global();
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.default_log_panics());
+        captured.clear();
+
+        let panicked = std::thread::Builder::new()
+            .name(String::from("worker"))
+            .spawn(|| panic!("boom {}", 42))?
+            .join();
+        assert!(panicked.is_err());
+
+        captured.assert_contains("ERROR panic: thread 'worker' panicked at");
+        captured.assert_contains("log_panics.rs");
+        captured.assert_contains(": boom 42");
+        captured.assert_contains("backtrace=");
+
+        Ok(())
+    })
+}
@@ -0,0 +1,48 @@
+//! `log_rate_limit` attribute suppresses repeated events
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(CaptureWriter::global)]
+#[log_rate_limit(3)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(args.default_log_rate_limit(), Some(3));
+
+    let captured = CaptureWriter::global();
+    captured.clear();
+
+    for i in 0..10 {
+        info!("noisy {i}");
+        debug!("filtered {i}"); // below the log level; doesn't count
+    }
+    info!("quiet");
+
+    captured.assert_line_count(4);
+    captured.assert_contains("noisy 2");
+    captured.assert_not_contains("noisy 3");
+    captured.assert_contains("quiet");
+
+    // periodic summary
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while !captured.contains("suppressed 7 events") {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "{}",
+            captured.snapshot()
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    Ok(())
+}
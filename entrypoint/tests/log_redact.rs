@@ -0,0 +1,66 @@
+//! `log_redact` attribute scrubs secret env values from log output
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_redact("*_SECRET", "password")]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("API_SECRET", "hunter2-api");
+    std::env::set_var("PASSWORD", "correct horse");
+    std::env::set_var("PUBLIC_VALUE", "visible-value");
+    std::env::set_var("SHORT_SECRET", "abc");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(args.default_log_redact(), ["*_SECRET", "password"]);
+
+        info!(
+            "log_redact marker {} {} {} {}",
+            std::env::var("API_SECRET")?,
+            std::env::var("PASSWORD")?,
+            std::env::var("PUBLIC_VALUE")?,
+            std::env::var("SHORT_SECRET")?,
+        );
+
+        let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+        let line = captured
+            .lines()
+            .find(|line| line.contains("log_redact marker"))
+            .expect("marker logged");
+
+        assert!(
+            line.ends_with("log_redact marker [REDACTED] [REDACTED] visible-value abc"),
+            "{line}"
+        );
+
+        Ok(())
+    })
+}
@@ -0,0 +1,47 @@
+//! `log_ring_buffer` attribute dumps recent events on error
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(CaptureWriter::global)]
+#[log_ring_buffer(3)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() {
+    let captured = CaptureWriter::global();
+
+    let result: entrypoint::anyhow::Result<()> = <Args as entrypoint::Entrypoint>::parse_args()
+        .entrypoint(|args| {
+            assert_eq!(args.default_log_ring_buffer(), Some(3));
+            captured.clear();
+
+            for i in 0..5 {
+                trace!("detail {i}");
+            }
+            captured.assert_not_contains("detail");
+
+            // dumps the last 3 buffered events (including this one)
+            error!("first failure");
+            captured.assert_not_contains("detail 2");
+            captured.assert_contains("detail 3");
+            captured.assert_contains("detail 4");
+            captured.assert_contains("--- last 3 buffered event(s) ---");
+            captured.clear();
+
+            // dumped when the function fails
+            debug!("before failure");
+            entrypoint::anyhow::bail!("second failure")
+        });
+
+    assert!(result.is_err());
+    captured.assert_contains("--- last 1 buffered event(s) ---");
+    captured.assert_contains("before failure");
+}
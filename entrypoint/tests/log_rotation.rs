@@ -0,0 +1,31 @@
+//! `log_rotation` attribute rotates log files by size
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_rotation/app.log"))]
+#[log_rotation(entrypoint::Rotation::Size(1024))]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    let file = args.default_log_file().expect("log_file attribute");
+    let rotated = file.with_extension("log.1");
+
+    for i in 0..100 {
+        info!("log_rotation filler line {i}");
+    }
+
+    assert!(rotated.exists());
+    assert!(std::fs::metadata(&file)?.len() <= 1024);
+    assert!(std::fs::metadata(&rotated)?.len() <= 1024);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! an event larger than `Rotation::Size` is appended, so it doesn't push out the previous file
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_rotation_oversized/app.log"))]
+#[log_rotation(entrypoint::Rotation::Size(256))]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    let file = args.default_log_file().expect("log_file attribute");
+    let rotated = file.with_extension("log.1");
+
+    info!("before oversized");
+    info!("oversized {}", "x".repeat(512));
+    info!("after oversized");
+
+    // the oversized event rotated (once) along w/ the event before it
+    let previous = std::fs::read_to_string(&rotated)?;
+    assert!(previous.contains("before oversized"));
+    assert!(previous.contains(&"x".repeat(512)));
+
+    let current = std::fs::read_to_string(&file)?;
+    assert!(current.contains("after oversized"));
+    assert!(!current.contains("before oversized"));
+
+    Ok(())
+}
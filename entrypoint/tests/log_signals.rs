@@ -0,0 +1,51 @@
+//! `#[log_signals]` runtime level changes
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "signals"))]
+
+use entrypoint::prelude::*;
+use signal_hook::consts::{SIGHUP, SIGUSR1, SIGUSR2};
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_signals]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+/// raise `signal` & wait for the handler thread to apply the expected level
+fn signal(signal: i32, expected: LevelFilter) -> entrypoint::anyhow::Result<()> {
+    signal_hook::low_level::raise(signal)?;
+
+    let handle = entrypoint::logger_handle().expect("missing logger handle");
+    for _ in 0..100 {
+        if handle.level() == Some(expected) {
+            return Ok(());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    entrypoint::anyhow::bail!("level {:?} != {expected}", handle.level())
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.default_log_signals());
+        assert!(!enabled!(entrypoint::Level::DEBUG));
+
+        signal(SIGUSR1, LevelFilter::DEBUG)?;
+        assert!(enabled!(entrypoint::Level::DEBUG));
+
+        signal(SIGUSR1, LevelFilter::TRACE)?;
+
+        signal(SIGHUP, LevelFilter::INFO)?;
+        assert!(!enabled!(entrypoint::Level::DEBUG));
+
+        signal(SIGUSR2, LevelFilter::WARN)?;
+        assert!(!enabled!(entrypoint::Level::INFO));
+
+        Ok(())
+    })
+}
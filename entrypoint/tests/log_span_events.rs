@@ -0,0 +1,55 @@
+//! `log_span_events` attribute logs span lifecycle events
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_span_events(new, close)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(
+        args.default_log_span_events(),
+        FmtSpan::NEW | FmtSpan::CLOSE
+    );
+
+    info_span!("log_span_events_span").in_scope(|| {});
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    let lines: Vec<_> = captured
+        .lines()
+        .filter(|line| line.contains("log_span_events_span"))
+        .collect();
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(lines[0].contains("new"));
+    assert!(lines[1].contains("close") && lines[1].contains("time.busy"));
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! `log_writer(split)` routes events by level
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::tracing_subscriber::fmt::writer::EitherWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(split)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+/// whether `SplitWriter` routes the (enabled) span's level to stderr
+fn stderr(span: &entrypoint::tracing::Span) -> bool {
+    let meta = span.metadata().expect("span enabled");
+    matches!(SplitWriter.make_writer_for(meta), EitherWriter::A(_))
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(stderr(&error_span!("error")));
+    assert!(stderr(&warn_span!("warn")));
+    assert!(!stderr(&info_span!("info")));
+
+    warn!("log_split marker (stderr)");
+    info!("log_split marker (stdout)");
+
+    Ok(())
+}
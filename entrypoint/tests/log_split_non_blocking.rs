@@ -0,0 +1,50 @@
+//! `log_writer(split)` w/ `log_non_blocking` still routes events by level
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+/// set for the re-executed (i.e. child) test process
+const CHILD: &str = "ENTRYPOINT_LOG_SPLIT_NON_BLOCKING_CHILD";
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(split)]
+#[log_non_blocking]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+// i.e. `main`, run by the child test process
+#[entrypoint::entrypoint]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_non_blocking());
+
+    warn!("log_split_non_blocking marker (stderr)");
+    info!("log_split_non_blocking marker (stdout)");
+
+    Ok(())
+}
+
+#[test]
+fn log_split_non_blocking() {
+    if std::env::var_os(CHILD).is_some() {
+        let _ = main();
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().expect("test binary path"))
+        .env(CHILD, "1")
+        .output()
+        .expect("re-executed test binary");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(stderr.contains("log_split_non_blocking marker (stderr)"));
+    assert!(!stderr.contains("log_split_non_blocking marker (stdout)"));
+    assert!(stdout.contains("log_split_non_blocking marker (stdout)"));
+    assert!(!stdout.contains("log_split_non_blocking marker (stderr)"));
+}
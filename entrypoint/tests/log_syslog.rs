@@ -0,0 +1,52 @@
+//! `default_log_syslog` sends RFC 5424 messages
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "syslog")]
+
+use entrypoint::prelude::*;
+
+static SERVER: std::sync::LazyLock<std::net::UdpSocket> = std::sync::LazyLock::new(|| {
+    std::net::UdpSocket::bind("127.0.0.1:0").expect("failed to bind test syslog server")
+});
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, Clone, Debug)]
+#[command(name = "syslog-test")]
+struct Args {}
+
+impl LoggerConfig for Args {
+    fn default_log_level(&self) -> LevelFilter {
+        LevelFilter::INFO
+    }
+
+    fn default_log_syslog(&self) -> Option<entrypoint::Syslog> {
+        Some(entrypoint::Syslog::Udp(
+            SERVER.local_addr().expect("no local addr").to_string(),
+        ))
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("SYSLOG_FACILITY", "local0");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        warn!("log_syslog marker");
+
+        let mut buf = [0; 1024];
+        SERVER.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+        let message = loop {
+            let len = SERVER.recv(&mut buf)?;
+            let message = String::from_utf8_lossy(&buf[..len]).into_owned();
+            if message.contains("marker") {
+                break message;
+            }
+        };
+
+        // facility local0 (16) * 8 + severity warning (4)
+        assert!(message.starts_with("<132>1 - "), "{message}");
+        assert!(message.contains(&format!(" syslog-test {} - - ", std::process::id())));
+        assert!(message.ends_with("log_syslog marker"), "{message}");
+
+        Ok(())
+    })
+}
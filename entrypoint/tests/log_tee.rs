@@ -0,0 +1,49 @@
+//! `log_writer` + `log_file` attributes tee logs to both
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(capture)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/log_tee/app.log"))]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_tee());
+    let file = args.default_log_file().expect("log_file attribute");
+
+    let marker = format!("log_tee marker {:?}", std::time::SystemTime::now());
+    info!("{marker}");
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    assert!(captured.contains(&marker));
+    assert!(std::fs::read_to_string(&file)?.contains(&marker));
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! `log_time_zone` attribute formats timestamps in a fixed offset/local time zone
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_time_zone(5 * 3600 + 1800)]
+#[log_writer(entrypoint::testing::CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("NO_COLOR", "1");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(
+            args.default_log_time_zone(),
+            entrypoint::TimeZone::Fixed(5 * 3600 + 1800)
+        );
+
+        info!("log_time_zone marker");
+
+        let captured = entrypoint::testing::CaptureWriter::global().lines();
+        let line = captured
+            .iter()
+            .find(|line| line.contains("log_time_zone marker"))
+            .expect("marker logged");
+        let timestamp = line.split_whitespace().next().expect("timestamp");
+        assert!(timestamp.ends_with("+05:30"), "{line}");
+
+        Ok(())
+    })
+}
+
+#[test]
+fn timer() {
+    use entrypoint::tracing_subscriber::fmt::{format::Writer, time::FormatTime};
+
+    let format = |timer: entrypoint::ZonedTimer| {
+        let mut formatted = String::new();
+        timer
+            .format_time(&mut Writer::new(&mut formatted))
+            .expect("formatted");
+        formatted
+    };
+
+    assert!(
+        format(entrypoint::Timer::Rfc3339.with_time_zone(entrypoint::TimeZone::Utc)).ends_with('Z')
+    );
+    assert!(format(
+        entrypoint::Timer::Rfc3339.with_time_zone(entrypoint::TimeZone::Fixed(-8 * 3600))
+    )
+    .ends_with("-08:00"));
+    assert!(format(
+        entrypoint::Timer::Custom(String::from("%z"))
+            .with_time_zone(entrypoint::TimeZone::Fixed(3600))
+    )
+    .ends_with("+0100"));
+    assert!(format(
+        entrypoint::Timer::Rfc3339.with_time_zone(entrypoint::TimeZone::Fixed(i32::MAX))
+    )
+    .ends_with('Z'));
+}
@@ -0,0 +1,53 @@
+//! `log_timestamp` attribute customizes/suppresses timestamps
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_timestamp(none)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("NO_COLOR", "1");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(args.default_log_timer(), entrypoint::Timer::None);
+
+        info!("log_timestamp marker");
+
+        let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+        let line = captured
+            .lines()
+            .find(|line| line.contains("log_timestamp marker"))
+            .expect("marker logged");
+        assert!(line.starts_with(" INFO "), "{line}");
+
+        Ok(())
+    })
+}
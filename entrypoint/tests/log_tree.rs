@@ -0,0 +1,41 @@
+//! `log_format(tree)` attribute renders nested spans as an indented tree
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "tree")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(tree)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(entrypoint::testing::CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(args.default_log_tree());
+
+    info_span!("outer", id = 7).in_scope(|| {
+        info_span!("inner").in_scope(|| {
+            info!("log_tree marker");
+        });
+    });
+
+    let lines = entrypoint::testing::CaptureWriter::global().lines();
+    let indent = |needle: &str| {
+        let line = lines
+            .iter()
+            .find(|line| line.contains(needle))
+            .unwrap_or_else(|| panic!("{needle} logged: {lines:#?}"));
+        line.chars().take_while(|c| !c.is_alphanumeric()).count()
+    };
+
+    assert!(indent("outer") < indent("inner"), "{lines:#?}");
+    assert!(indent("inner") < indent("log_tree marker"), "{lines:#?}");
+
+    Ok(())
+}
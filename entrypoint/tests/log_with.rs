@@ -0,0 +1,54 @@
+//! `log_with` attribute toggles logged fields
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+static CAPTURED: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+struct Capture;
+
+impl std::io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CAPTURED.lock().expect("poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const fn capture() -> Capture {
+    Capture
+}
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_with(file, line_number, target = false)]
+#[log_writer(capture)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert!(!args.default_log_with_target());
+    assert!(args.default_log_with_file());
+    assert!(args.default_log_with_line_number());
+    assert!(!args.default_log_with_thread_ids());
+
+    info!("log_with marker");
+
+    let captured = String::from_utf8(CAPTURED.lock().expect("poisoned").clone())?;
+    let line = captured
+        .lines()
+        .find(|line| line.contains("log_with marker"))
+        .expect("marker logged");
+    assert!(line.contains("tests/log_with.rs:"), "{line}");
+    assert!(!line.contains("log_with: log_with marker"), "{line}"); // no target
+
+    Ok(())
+}
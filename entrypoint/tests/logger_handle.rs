@@ -0,0 +1,36 @@
+//! runtime filter changes via `logger_handle`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    assert!(entrypoint::logger_handle().is_none());
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        let handle = entrypoint::logger_handle().expect("missing logger handle");
+        assert_eq!(handle.level(), Some(LevelFilter::INFO));
+        assert!(!enabled!(entrypoint::Level::DEBUG));
+
+        handle.set_level(LevelFilter::TRACE)?;
+        assert_eq!(handle.level(), Some(LevelFilter::TRACE));
+        assert!(enabled!(entrypoint::Level::TRACE));
+
+        handle.set_filter("warn,logger_handle=debug")?;
+        assert!(enabled!(entrypoint::Level::DEBUG));
+        assert!(!enabled!(target: "other", entrypoint::Level::INFO));
+
+        assert!(handle.set_filter("info,=[").is_err());
+        assert!(enabled!(entrypoint::Level::DEBUG));
+
+        Ok(())
+    })
+}
@@ -1,11 +1,14 @@
 //! verbose way, no macros... not a good usage example
 
+#![allow(unused_crate_dependencies)]
+
 use entrypoint::prelude::*;
 mod common;
 
 impl DotEnvParserConfig for common::Args {}
 
 /// entrypoint function
+#[allow(clippy::needless_pass_by_value)]
 fn entrypoint(args: common::Args) -> entrypoint::anyhow::Result<()> {
     assert!(args.additional_dotenv_files().is_none());
 
@@ -13,7 +16,7 @@ fn entrypoint(args: common::Args) -> entrypoint::anyhow::Result<()> {
 
     common::verify_log_level(
         &args,
-        &entrypoint::tracing_subscriber::filter::LevelFilter::WARN,
+        entrypoint::tracing_subscriber::filter::LevelFilter::WARN,
     )?; // default
 
     Ok(())
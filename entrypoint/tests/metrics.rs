@@ -0,0 +1,69 @@
+//! `metrics` feature serves Prometheus metrics on `METRICS_ADDR`
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "metrics")]
+
+use entrypoint::prelude::*;
+use std::io::{Read, Write};
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+/// GET `/metrics`, retrying while the exporter starts
+fn scrape(addr: std::net::SocketAddr) -> entrypoint::anyhow::Result<String> {
+    let mut attempts = 0;
+
+    loop {
+        let response = std::net::TcpStream::connect(addr).and_then(|mut stream| {
+            stream.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+            write!(
+                stream,
+                "GET /metrics HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"
+            )?;
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+            Ok(response)
+        });
+
+        match response {
+            Ok(response) => return Ok(response),
+            Err(_) if attempts < 50 => {
+                attempts += 1;
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    std::env::set_var("METRICS_ADDR", addr.to_string());
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        entrypoint::metrics::counter!("app_requests_total").increment(3);
+
+        let response = scrape(addr)?;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(
+            response.contains("entrypoint_startup_seconds "),
+            "{response}"
+        );
+        assert!(response.contains("app_requests_total 3"), "{response}");
+
+        Ok(())
+    })?;
+
+    let response = scrape(addr)?;
+    assert!(
+        response.contains("entrypoint_result_total{result=\"ok\"} 1"),
+        "{response}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! `otel` feature composes an OTLP export layer & shuts it down cleanly
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "otel")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    // nothing listening; export fails quietly and must not block shutdown
+    std::env::set_var("OTEL_EXPORTER_OTLP_ENDPOINT", "http://127.0.0.1:9");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        let span = info_span!("otel test span");
+        let _enter = span.enter();
+        info!("inside span");
+        Ok(())
+    })
+}
@@ -0,0 +1,68 @@
+//! `otel` feature describes the exporter resource from `OTEL_*` env vars & clap metadata
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "otel")]
+
+use entrypoint::prelude::*;
+use std::io::{Read, Write};
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(name = "otel-resource-app", version = "1.2.3", about, long_about = None)]
+struct Args {}
+
+/// accept one OTLP/HTTP export; respond `200 OK` and return the raw request
+fn collector(listener: std::net::TcpListener) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("export request");
+        stream
+            .set_read_timeout(Some(std::time::Duration::from_millis(500)))
+            .expect("read timeout");
+
+        let mut request = Vec::new();
+        let mut buf = [0; 4096];
+        while let Ok(read @ 1..) = stream.read(&mut buf) {
+            request.extend_from_slice(&buf[..read]);
+        }
+
+        let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+        request
+    })
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    std::env::set_var(
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        format!("http://{}", listener.local_addr()?),
+    );
+    std::env::set_var(
+        "OTEL_RESOURCE_ATTRIBUTES",
+        "deployment.environment=otel-resource-test",
+    );
+    std::env::remove_var("OTEL_SERVICE_NAME");
+    let collector = collector(listener);
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        info_span!("otel resource span").in_scope(|| info!("inside span"));
+        Ok(())
+    })?;
+
+    let request = String::from_utf8_lossy(&collector.join().expect("collector")).into_owned();
+    for expected in [
+        "service.name",
+        "otel-resource-app",
+        "service.version",
+        "1.2.3",
+        "deployment.environment",
+        "otel-resource-test",
+    ] {
+        assert!(request.contains(expected), "{expected} in {request:?}");
+    }
+
+    Ok(())
+}
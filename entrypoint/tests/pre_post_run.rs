@@ -0,0 +1,52 @@
+//! `pre_run` & `post_run` hooks run around the entrypoint function
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use std::sync::Mutex;
+
+static CALLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn call(name: &str) {
+    CALLS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(String::from(name));
+}
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(long, default_value = "app")]
+    name: String,
+}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn pre_run(&self) -> entrypoint::anyhow::Result<()> {
+        // logging is initialized
+        assert!(entrypoint::logger_handle().is_some());
+        call("pre_run");
+        Ok(())
+    }
+
+    fn post_run<T>(&self, result: &entrypoint::anyhow::Result<T>) {
+        call(&format!("post_run({}, {})", self.name, result.is_ok()));
+    }
+}
+
+#[test]
+fn pre_post_run() {
+    let result = Args::parse_args().entrypoint(|_args| {
+        call("entrypoint");
+        Ok(42)
+    });
+
+    assert_eq!(result.ok(), Some(42));
+    assert_eq!(
+        *CALLS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+        ["pre_run", "entrypoint", "post_run(app, true)"]
+    );
+}
@@ -0,0 +1,38 @@
+//! re-parse args after dotenv processing (CLI > env > dotenv > defaults), logging the resolution
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::DEBUG)]
+#[log_writer(CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(long, env = "PORT")]
+    port: Option<u16>,
+
+    #[arg(long, env = "PRECEDENCE_UNSET", default_value = "fallback")]
+    fallback: String,
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(args.precedence_policy(), PrecedencePolicy::CliEnvDotEnv);
+    assert_eq!(args.port, Some(80)); // per .env
+    assert_eq!(args.fallback, "fallback");
+
+    let lines = CaptureWriter::global().lines();
+    let logged = |needle: &str| lines.iter().any(|line| line.contains(needle));
+    assert!(logged(
+        "precedence policy: CLI > environment > dotenv > defaults"
+    ));
+    assert!(logged("port resolved from PORT (") && logged(".env)"));
+    assert!(logged("fallback resolved from default"));
+
+    Ok(())
+}
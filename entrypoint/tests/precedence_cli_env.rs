@@ -0,0 +1,40 @@
+//! `CliEnv` precedence: dotenv files don't affect args, nor override the process env
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, EntrypointDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(long, env = "PORT")]
+    port: Option<u16>,
+}
+
+impl DotEnvParserConfig for Args {
+    fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+        Some(vec![std::path::PathBuf::from(".dev")])
+    }
+
+    fn dotenv_can_override(&self) -> bool {
+        true
+    }
+
+    fn precedence_policy(&self) -> PrecedencePolicy {
+        PrecedencePolicy::CliEnv
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("IP", "10.0.0.1"); // i.e. the process environment
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(args.port, None); // not from dotenv
+        assert_eq!(std::env::var("PORT")?, "8888"); // .dev overrides .env
+        assert_eq!(std::env::var("APP_ENV")?, "development");
+        assert_eq!(std::env::var("IP")?, "10.0.0.1"); // process env is protected
+        Ok(())
+    })
+}
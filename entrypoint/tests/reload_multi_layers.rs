@@ -1,10 +1,12 @@
-//! use bypass_log_init to keep reload handle(s)
+//! use `bypass_log_init` to keep reload handle(s)
+
+#![allow(unused_crate_dependencies)]
 
 use entrypoint::prelude::*;
 
 mod common;
 
-#[derive(entrypoint::clap::Parser, DotEnvDefault, Debug)]
+#[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, Clone, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {}
 
@@ -57,7 +59,7 @@ fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
 
     ////////////////////////////////////////////////////////////////////////////
     // independent control/reload of writer & format
-    assert!(serde_json::from_slice::<serde_json::Value>(&common::OUTPUT_BUFFER.buffer()).is_err());
+    assert!(serde_json::from_slice::<serde_json::Value>(&common::global_writer().bytes()).is_err());
 
     // #FIXME - waiting on https://github.com/tokio-rs/tracing/pull/1959
     //let _ = reload_one.modify(|layer| {
@@ -66,9 +68,9 @@ fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
     //#FIXME let _ = reload_one.modify(|layer| *layer.inner_mut().writer_mut() = common::global_writer);
     //#FIXME let _ = reload_one.modify(|layer| *layer.inner_mut().writer_mut() = std::io::stdout);
 
-    common::OUTPUT_BUFFER.clear();
+    common::global_writer().clear();
     error!("error");
-    //#FIXME assert!(serde_json::from_slice::<serde_json::Value>(&common::OUTPUT_BUFFER.buffer()).is_ok());
+    //#FIXME assert!(serde_json::from_slice::<serde_json::Value>(&common::global_writer().bytes()).is_ok());
 
     Ok(())
 }
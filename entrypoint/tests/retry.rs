@@ -0,0 +1,66 @@
+//! failed entrypoint function is retried per the `RetryPolicy`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use std::time::Duration;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(entrypoint::testing::CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[derive(Debug)]
+struct Transient;
+
+impl std::fmt::Display for Transient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "database not up yet")
+    }
+}
+
+impl std::error::Error for Transient {}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(5)
+            .backoff(Duration::from_millis(1))
+            .retry_if(|e| e.downcast_ref::<Transient>().is_some())
+    }
+}
+
+#[test]
+fn retry() {
+    let policy = RetryPolicy::new(5)
+        .backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_millis(300));
+    assert_eq!(policy.delay(1), Duration::from_millis(100));
+    assert_eq!(policy.delay(2), Duration::from_millis(200));
+    assert_eq!(policy.delay(3), Duration::from_millis(300));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_millis(300));
+    assert_eq!(RetryPolicy::default().max_attempts(), 1);
+
+    let mut attempts = 0;
+    let result = Args::parse_args().entrypoint_with_retry(|_args| {
+        attempts += 1;
+        match attempts {
+            1 | 2 => Err(Transient.into()),
+            3 => Err(entrypoint::anyhow::anyhow!("fatal")),
+            _ => Ok(()),
+        }
+    });
+
+    // 2 transient failures retried, then a non-retryable error
+    assert_eq!(attempts, 3);
+    assert_eq!(
+        result.map_err(|e| e.to_string()),
+        Err(String::from("fatal"))
+    );
+
+    let output = entrypoint::testing::CaptureWriter::global().snapshot();
+    assert!(output.contains(
+        "entrypoint function failed (attempt 1/5): database not up yet; retrying in 1ms"
+    ));
+    assert!(output.contains("(attempt 2/5)"));
+    assert!(!output.contains("(attempt 3/5)"));
+}
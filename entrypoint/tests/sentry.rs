@@ -0,0 +1,30 @@
+//! `sentry` feature reports returned errors & shuts down cleanly
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "sentry")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() {
+    // nothing listening; reporting fails quietly and must not block shutdown
+    std::env::set_var("SENTRY_DSN", "http://public@127.0.0.1:9/1");
+
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        error!("sentry test event");
+        Err::<(), _>(entrypoint::anyhow::anyhow!("sentry test error"))
+    });
+
+    assert_eq!(
+        result.expect_err("error is returned").to_string(),
+        "sentry test error"
+    );
+}
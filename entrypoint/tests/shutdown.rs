@@ -0,0 +1,67 @@
+//! graceful shutdown on `SIGTERM`
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "shutdown"))]
+
+use entrypoint::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    // shared w/ the hook's clone of the args (i.e. not re-parsed)
+    #[arg(skip)]
+    hooked: Arc<AtomicBool>,
+}
+
+impl EntrypointConfig for Args {
+    fn on_shutdown(&self) {
+        self.hooked.store(true, Ordering::SeqCst);
+    }
+
+    fn shutdown_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(30)
+    }
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.shutdown_signals());
+        assert!(!entrypoint::shutdown_requested());
+        assert_eq!(entrypoint::shutdown_reason(), None);
+
+        let token = ShutdownToken::default();
+        assert_eq!(
+            token.wait_timeout(std::time::Duration::from_millis(10)),
+            None
+        );
+        let waiter = std::thread::spawn(move || token.wait());
+
+        signal_hook::low_level::raise(signal_hook::consts::SIGTERM)?;
+
+        for _ in 0..100 {
+            if args.hooked.load(Ordering::SeqCst) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        assert!(args.hooked.load(Ordering::SeqCst));
+        assert!(entrypoint::shutdown_requested());
+        assert_eq!(
+            entrypoint::shutdown_reason(),
+            Some(entrypoint::ShutdownReason::Terminate)
+        );
+        assert_eq!(entrypoint::ShutdownReason::Terminate.exit_code(), 143);
+        assert_eq!(
+            waiter.join().ok(),
+            Some(entrypoint::ShutdownReason::Terminate)
+        );
+        assert!(token.is_requested());
+
+        Ok(())
+    })
+}
@@ -0,0 +1,81 @@
+//! `signal_config` maps signals to actions (callback, dotenv reload, log file re-open)
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "signals"))]
+
+use entrypoint::prelude::*;
+use signal_hook::consts::{SIGHUP, SIGUSR1, SIGUSR2};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(entrypoint::clap::Parser, LoggerDefault, Clone, Debug)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_file(concat!(env!("CARGO_TARGET_TMPDIR"), "/signal_config/app.log"))]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl DotEnvParserConfig for Args {
+    fn dotenv_search_parents(&self) -> bool {
+        false
+    }
+}
+
+impl EntrypointConfig for Args {
+    fn signal_config(&self) -> SignalConfig {
+        SignalConfig::new()
+            .on(
+                Signal::User1,
+                SignalAction::Callback(|_| CALLED.store(true, Ordering::SeqCst)),
+            )
+            .on(Signal::Hangup, SignalAction::ReloadConfig)
+            .on(Signal::User2, SignalAction::ReopenLogs)
+    }
+}
+
+/// raise `signal` & wait for `done`
+fn signal(signal: i32, done: impl Fn() -> bool) -> entrypoint::anyhow::Result<()> {
+    signal_hook::low_level::raise(signal)?;
+
+    for _ in 0..100 {
+        if done() {
+            return Ok(());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    entrypoint::anyhow::bail!("signal {signal} not handled")
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("entrypoint-signals-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join(".env"), "SIGNAL_KEY=before\n")?;
+    std::env::set_current_dir(&dir)?;
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        signal(SIGUSR1, || CALLED.load(Ordering::SeqCst))?;
+
+        assert_eq!(std::env::var("SIGNAL_KEY")?, "before");
+        std::fs::write(dir.join(".env"), "SIGNAL_KEY=after\n")?;
+        signal(SIGHUP, || {
+            std::env::var("SIGNAL_KEY").is_ok_and(|value| value == "after")
+        })?;
+
+        let file = args.default_log_file().expect("log_file attribute");
+        let rotated = file.with_extension("log.1");
+        std::fs::rename(&file, &rotated)?;
+        signal(SIGUSR2, || {
+            std::fs::read_to_string(&file)
+                .is_ok_and(|contents| contents.contains("re-opened 1 log file(s)"))
+        })?;
+
+        let marker = format!("signal_config marker {:?}", std::time::SystemTime::now());
+        info!("{marker}");
+        assert!(std::fs::read_to_string(&file)?.contains(&marker));
+        assert!(!std::fs::read_to_string(&rotated)?.contains(&marker));
+
+        Ok(std::fs::remove_dir_all(&dir)?)
+    })
+}
@@ -0,0 +1,26 @@
+//! `#[entrypoint::entrypoint(smol)]` runs the async function on smol, after setup
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "smol")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint(smol)]
+#[test]
+async fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+
+    // logging is initialized before the runtime (threads) start
+    let enabled = entrypoint::smol::spawn(async { enabled!(entrypoint::Level::INFO) }).await;
+    assert!(enabled);
+
+    entrypoint::smol::Timer::after(std::time::Duration::from_millis(1)).await;
+    Ok(())
+}
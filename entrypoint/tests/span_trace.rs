@@ -0,0 +1,50 @@
+//! `SpanTraceExt` captures span traces for returned errors
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[instrument]
+fn load(path: &str) -> entrypoint::anyhow::Result<String> {
+    std::fs::read_to_string(path)
+        .context("load failed")
+        .with_span_trace()
+}
+
+#[test]
+fn main() {
+    let result = <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert!(args.default_log_span_trace());
+
+        info_span!("outer").in_scope(|| load("/does/not/exist"))
+    });
+
+    let error = result.expect_err("load should fail");
+    assert_eq!(error.to_string(), "load failed");
+    assert_eq!(error.chain().count(), 2);
+
+    let trace = entrypoint::span_trace(&error).expect("span trace captured");
+    assert_eq!(
+        trace.status(),
+        entrypoint::tracing_error::SpanTraceStatus::CAPTURED
+    );
+
+    let trace = trace.to_string();
+    assert!(trace.contains("span_trace::load"), "{trace}");
+    assert!(trace.contains("/does/not/exist"), "{trace}");
+    assert!(trace.contains("outer"), "{trace}");
+
+    // already captured; not re-wrapped
+    let error = Err::<(), _>(error)
+        .with_span_trace()
+        .expect_err("still an error");
+    assert_eq!(error.chain().count(), 2);
+}
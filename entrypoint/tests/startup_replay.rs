@@ -0,0 +1,45 @@
+//! setup events are replayed through the configured layers after `log_init`
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+use entrypoint::testing::CaptureWriter;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_format(json)]
+#[log_level(entrypoint::LevelFilter::DEBUG)]
+#[log_writer(CaptureWriter::global)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(_args: Args) -> entrypoint::anyhow::Result<()> {
+    let lines = CaptureWriter::global()
+        .lines()
+        .iter()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let messages: Vec<&str> = lines
+        .iter()
+        .filter_map(|line| line["fields"]["message"].as_str())
+        .collect();
+
+    let position = |needle: &str| {
+        messages
+            .iter()
+            .position(|message| message.contains(needle))
+            .unwrap_or_else(|| panic!("{needle:?} not in {messages:?}"))
+    };
+
+    // dotenv is processed (twice) before log_init
+    let dotenv = position("dotenv");
+    let level = position("log level: DEBUG");
+    let complete = position("setup/config complete");
+    assert!(dotenv < level && level < complete, "{messages:?}");
+
+    Ok(())
+}
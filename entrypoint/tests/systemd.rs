@@ -0,0 +1,43 @@
+//! `READY=1`, `WATCHDOG=1`, & `STOPPING=1` are sent to the `NOTIFY_SOCKET`
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(unix, feature = "systemd"))]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn systemd() -> entrypoint::anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("entrypoint-systemd-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let socket = std::os::unix::net::UnixDatagram::bind(&path)?;
+    socket.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+
+    std::env::set_var("NOTIFY_SOCKET", &path);
+    std::env::set_var("WATCHDOG_USEC", "20000");
+
+    Args::parse_args().entrypoint(|_args| {
+        // i.e. a few watchdog pings (every 10ms)
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        Ok(())
+    })?;
+
+    let mut states = Vec::new();
+    let mut buffer = [0; 64];
+    while states.last().map(String::as_str) != Some("STOPPING=1") {
+        let length = socket.recv(&mut buffer)?;
+        states.push(String::from_utf8_lossy(&buffer[..length]).into_owned());
+    }
+
+    assert_eq!(states.first().map(String::as_str), Some("READY=1"));
+    assert!(states.iter().any(|state| state == "WATCHDOG=1"));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! `DO_NOT_TRACK` & app specific telemetry opt-out
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, Clone, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl LoggerConfig for Args {
+    fn telemetry_opt_out_var(&self) -> Option<String> {
+        Some(String::from("ENTRYPOINT_TEST_NO_TELEMETRY"))
+    }
+}
+
+#[test]
+fn telemetry_opt_out() {
+    let args = <Args as entrypoint::clap::Parser>::parse_from(["telemetry_opt_out"]);
+
+    std::env::remove_var("DO_NOT_TRACK");
+    std::env::remove_var("ENTRYPOINT_TEST_NO_TELEMETRY");
+    assert!(!args.telemetry_disabled());
+
+    std::env::set_var("ENTRYPOINT_TEST_NO_TELEMETRY", "false");
+    assert!(!args.telemetry_disabled());
+
+    std::env::set_var("ENTRYPOINT_TEST_NO_TELEMETRY", "1");
+    assert!(args.telemetry_disabled());
+
+    std::env::remove_var("ENTRYPOINT_TEST_NO_TELEMETRY");
+    std::env::set_var("DO_NOT_TRACK", "true");
+    assert!(args.telemetry_disabled());
+}
@@ -1,8 +1,12 @@
 //! make sure async/tokio works
 
+#![allow(unused_crate_dependencies)]
+
 use entrypoint::prelude::*;
 
-#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Debug)]
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
 #[log_writer(std::io::sink)]
 #[command(author, version, about, long_about = None)]
 struct Args {}
@@ -0,0 +1,30 @@
+//! `tokio-console` feature registers the console layer when `TOKIO_CONSOLE` is set
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "tokio-console")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    std::env::set_var("TOKIO_CONSOLE", "1");
+    std::env::set_var("TOKIO_CONSOLE_BIND", "127.0.0.1:0");
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|_args| {
+        // only the console layer enables tokio's runtime instrumentation
+        assert_eq!(
+            enabled!(target: "tokio::task", Level::TRACE),
+            cfg!(tokio_unstable)
+        );
+
+        Ok(())
+    })
+}
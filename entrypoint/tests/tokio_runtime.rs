@@ -0,0 +1,44 @@
+//! `#[entrypoint::entrypoint(tokio)]` runs the async function on a runtime built (per `RuntimeConfig`) after setup
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "tokio")]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, EntrypointDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+impl DotEnvParserConfig for Args {
+    // i.e. runtime config sourced from dotenv
+    fn builtin_dotenv(&self) -> Option<Box<dyn std::io::Read>> {
+        Some(Box::new(
+            "TOKIO_WORKER_THREADS=2\nTOKIO_THREAD_NAME=entrypoint-test-worker\n".as_bytes(),
+        ))
+    }
+}
+
+impl RuntimeConfig for Args {}
+
+#[entrypoint::entrypoint(tokio)]
+#[test]
+async fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+    assert_eq!(args.runtime_worker_threads(), Some(2));
+
+    // logging is initialized before the runtime (threads) start
+    let (enabled, thread) = tokio::spawn(async {
+        (
+            enabled!(entrypoint::Level::INFO),
+            std::thread::current().name().map(String::from),
+        )
+    })
+    .await?;
+    assert!(enabled);
+    assert_eq!(thread.as_deref(), Some("entrypoint-test-worker"));
+
+    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    assert_eq!(tokio::runtime::Handle::current().metrics().num_workers(), 2);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! `-v`/`-q` verbosity flags
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_level(entrypoint::LevelFilter::INFO)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[test]
+fn main() -> entrypoint::anyhow::Result<()> {
+    let args = Args::try_parse_from(["verbosity", "-vv", "-q"])?;
+    assert_eq!(
+        args.verbosity,
+        Verbosity {
+            verbose: 2,
+            quiet: 1
+        }
+    );
+    assert_eq!(
+        args.verbosity.log_level(LevelFilter::INFO),
+        LevelFilter::DEBUG
+    );
+    assert_eq!(
+        args.verbosity.log_level(LevelFilter::ERROR),
+        LevelFilter::WARN
+    );
+
+    let quiet = Verbosity {
+        verbose: 0,
+        quiet: 9,
+    };
+    assert_eq!(quiet.log_level(LevelFilter::INFO), LevelFilter::OFF);
+
+    let verbose = Verbosity {
+        verbose: 9,
+        quiet: 0,
+    };
+    assert_eq!(verbose.log_level(LevelFilter::OFF), LevelFilter::TRACE);
+
+    // no flags parsed by `parse_args` (test harness args aside) => unchanged
+    assert_eq!(
+        Verbosity::parsed_log_level(LevelFilter::INFO),
+        LevelFilter::INFO
+    );
+
+    <Args as entrypoint::Entrypoint>::parse_args().entrypoint(|args| {
+        assert_eq!(args.default_log_level(), LevelFilter::INFO);
+        assert!(enabled!(entrypoint::Level::INFO));
+        assert!(!enabled!(entrypoint::Level::DEBUG));
+        Ok(())
+    })
+}
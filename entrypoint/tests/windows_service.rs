@@ -0,0 +1,27 @@
+//! not started by the Service Control Manager, the entrypoint function runs as a console app
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "windows-service")]
+
+use entrypoint::prelude::*;
+
+#[derive(
+    entrypoint::clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug,
+)]
+#[log_writer(std::io::sink)]
+#[command(name = "windows-service-test")]
+struct Args {}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    assert_eq!(
+        args.windows_service_name().as_deref(),
+        Some("windows-service-test")
+    );
+
+    assert!(!entrypoint::service_paused());
+    assert!(!entrypoint::shutdown_requested());
+
+    Ok(())
+}
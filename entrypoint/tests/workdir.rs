@@ -0,0 +1,49 @@
+//! working directory (created if missing) & umask are set before the entrypoint function runs
+
+#![allow(unused_crate_dependencies)]
+
+use entrypoint::prelude::*;
+
+#[derive(entrypoint::clap::Parser, DotEnvDefault, LoggerDefault, Clone, Debug)]
+#[log_writer(std::io::sink)]
+#[command(author, version, about, long_about = None)]
+struct Args {}
+
+fn workdir() -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("entrypoint-workdir-{}", std::process::id()))
+        .join("missing")
+}
+
+impl entrypoint::EntrypointConfig for Args {
+    fn workdir_config(&self) -> WorkdirConfig {
+        WorkdirConfig::new()
+            .chdir(workdir())
+            .create(true)
+            .umask(0o077)
+    }
+}
+
+#[entrypoint::entrypoint]
+#[test]
+fn entrypoint(args: Args) -> entrypoint::anyhow::Result<()> {
+    info!("{args:?}");
+    assert_eq!(
+        std::env::current_dir()?.canonicalize()?,
+        workdir().canonicalize()?
+    );
+
+    std::fs::write("file", "")?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(
+            std::fs::metadata("file")?.permissions().mode() & 0o777,
+            0o600
+        );
+    }
+
+    std::env::set_current_dir(std::env::temp_dir())?;
+    std::fs::remove_dir_all(workdir().parent().expect("temp dir"))?;
+    Ok(())
+}
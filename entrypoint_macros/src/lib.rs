@@ -6,7 +6,7 @@
 //! ```
 //! use entrypoint::prelude::*;
 //!
-//! #[derive(clap::Parser, DotEnvDefault, LoggerDefault, Debug)]
+//! #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug)]
 //! struct Args {}
 //!
 //! // this function replaces `main()`
@@ -20,15 +20,31 @@
 
 #![no_std]
 
+#[cfg(test)]
+use entrypoint as _; // doctests only
+
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, parse_quote, DeriveInput, FnArg, Ident, ItemFn, Pat, PatIdent, PatType,
-    Path, Type, TypePath,
+    parse_macro_input, parse_quote, punctuated::Punctuated, DeriveInput, FnArg, Ident, ItemFn, Pat,
+    PatIdent, PatType, Path, ReturnType, Token, Type, TypePath,
 };
 
 /// derive default impl(s) for [`entrypoint::DotEnvParserConfig`]
 ///
+/// # Attributes
+/// * `#[dotenv_files]` sets additional dotenv files to process, in order (e.g. `#[dotenv_files(".env.shared", "conf/*.env")]`).
+///   Wrap a file in `optional()` to skip it if missing (e.g. `#[dotenv_files(".env.shared", optional(".env.overlay"))]`).
+///   Defaults to none.
+/// * `#[dotenv_override]` allows successive dotenv files to override already defined variables. Defaults to no override.
+/// * `#[dotenv_required]` fails startup if the `.env` file is missing (or invalid). Defaults to optional.
+///
+/// When compiled for tests (i.e. `cfg(test)`), the `.env.test` file is preferred over the `.env` file
+/// (refer to [`entrypoint::DotEnvParserConfig::dotenv_test`]).
+///
+/// # Panics
+/// * `#[dotenv_files]` has missing or malformed input
+///
 /// # Examples
 /// ```
 /// # use entrypoint::prelude::*;
@@ -38,14 +54,141 @@ use syn::{
 /// // uses default implementation(s)
 /// assert_eq!(Args::parse().additional_dotenv_files(), None);
 /// ```
+///
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[derive(clap::Parser, DotEnvDefault)]
+/// #[dotenv_files(".env.shared", optional(".env.overlay"))]
+/// #[dotenv_override]
+/// struct Args {}
+///
+/// let args = Args::parse();
+/// assert_eq!(args.dotenv_files().len(), 2);
+/// assert!(args.dotenv_can_override());
+/// ```
 /// [`entrypoint::DotEnvParserConfig`]: https://docs.rs/entrypoint/latest/entrypoint/trait.DotEnvParserConfig.html
-#[proc_macro_derive(DotEnvDefault)]
+/// [`entrypoint::DotEnvParserConfig::dotenv_test`]: https://docs.rs/entrypoint/latest/entrypoint/trait.DotEnvParserConfig.html#method.dotenv_test
+#[proc_macro_derive(
+    DotEnvDefault,
+    attributes(dotenv_files, dotenv_override, dotenv_required)
+)]
 pub fn derive_dotenv_parser(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
+    let mut dotenv_files: Option<Punctuated<syn::Expr, Token![,]>> = None;
+    let mut dotenv_override = false;
+    let mut dotenv_required = false;
+
+    for attr in input.attrs {
+        if attr.path().is_ident("dotenv_files") {
+            dotenv_files = Some(
+                attr.parse_args_with(Punctuated::parse_terminated)
+                    .expect("required dotenv_files input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("dotenv_override") {
+            dotenv_override = true;
+        } else if attr.path().is_ident("dotenv_required") {
+            dotenv_required = true;
+        }
+    }
+
+    let dotenv_files = dotenv_files.map(|files| {
+        // (file, whether it's optional)
+        let files = files.into_iter().map(|file| match file {
+            syn::Expr::Lit(_) => (file, false),
+            syn::Expr::Call(syn::ExprCall { func, mut args, .. })
+                if args.len() == 1
+                    && matches!(func.as_ref(), syn::Expr::Path(path) if path.path.is_ident("optional")) =>
+            {
+                (args.pop().expect("single argument").into_value(), true)
+            }
+            _ => panic!("dotenv_files input parameter is malformed"),
+        });
+        let files = files.collect::<Punctuated<_, Token![,]>>();
+
+        if files.iter().any(|(_, optional)| *optional) {
+            let files = files.into_iter().map(|(file, optional)| {
+                if optional {
+                    quote! { entrypoint::DotEnvFile::Optional(std::path::PathBuf::from(#file)) }
+                } else {
+                    quote! { entrypoint::DotEnvFile::Required(std::path::PathBuf::from(#file)) }
+                }
+            });
+            quote! {
+              fn dotenv_files(&self) -> Vec<entrypoint::DotEnvFile> {
+                  vec![#(#files),*]
+              }
+            }
+        } else {
+            let files = files.into_iter().map(|(file, _)| file);
+            quote! {
+              fn additional_dotenv_files(&self) -> Option<Vec<std::path::PathBuf>> {
+                  Some(vec![#(std::path::PathBuf::from(#files)),*])
+              }
+            }
+        }
+    });
+
+    let dotenv_override = dotenv_override.then(|| {
+        quote! {
+          fn dotenv_can_override(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let dotenv_required = dotenv_required.then(|| {
+        quote! {
+          fn dotenv_required(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let output = quote! {
+      impl entrypoint::DotEnvParserConfig for #name {
+          fn dotenv_test(&self) -> bool {
+              cfg!(test) || self.dotenv_environment().as_deref() == Some("test")
+          }
+          #dotenv_files
+          #dotenv_override
+          #dotenv_required
+      }
+    };
+
+    TokenStream::from(output)
+}
+
+/// derive default impl(s) for [`entrypoint::EntrypointConfig`]
+///
+/// [`entrypoint::EntrypointConfig::build_info`] is implemented w/ [`entrypoint::build_info!`]
+/// (i.e. populated by [`entrypoint::emit_build_info`] in the build script, if any).
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[derive(clap::Parser, EntrypointDefault)]
+/// struct Args {}
+///
+/// // uses default implementation(s)
+/// assert!(Args::capabilities_flag());
+/// ```
+/// [`entrypoint::EntrypointConfig`]: https://docs.rs/entrypoint/latest/entrypoint/trait.EntrypointConfig.html
+/// [`entrypoint::EntrypointConfig::build_info`]: https://docs.rs/entrypoint/latest/entrypoint/trait.EntrypointConfig.html#method.build_info
+/// [`entrypoint::build_info!`]: https://docs.rs/entrypoint/latest/entrypoint/macro.build_info.html
+/// [`entrypoint::emit_build_info`]: https://docs.rs/entrypoint/latest/entrypoint/fn.emit_build_info.html
+#[proc_macro_derive(EntrypointDefault)]
+pub fn derive_entrypoint(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
     let output = quote! {
-      impl entrypoint::DotEnvParserConfig for #name {}
+      impl entrypoint::EntrypointConfig for #name {
+          fn build_info() -> entrypoint::BuildInfo {
+              entrypoint::build_info!()
+          }
+      }
     };
 
     TokenStream::from(output)
@@ -55,18 +198,85 @@ pub fn derive_dotenv_parser(input: TokenStream) -> TokenStream {
 ///
 /// # Attributes
 /// * `#[log_format]` sets the default [`tracing_subscriber::Format`]. Defaults to `default`. Valid options are:
+///   * [`bunyan`] (requires the `bunyan` feature)
 ///   * [`compact`]
 ///   * [`default`]
 ///   * [`full`]
 ///   * [`json`]
+///   * [`logfmt`]
 ///   * [`pretty`]
+///   * [`tree`] (requires the `tree` feature)
+///   * `custom = path::to::formatter`, a function taking `&Self` and returning an `impl FormatEvent<S, N>`
+///     (e.g. `fn formatter<S, N>(args: &Args) -> impl FormatEvent<S, N> + Send + Sync + 'static`)
+/// * `#[log_timestamp]` sets the default [`entrypoint::Timer`]. Defaults to `rfc3339`. Valid options are:
+///   * `rfc3339`
+///   * `epoch`
+///   * `none`
+///   * a custom `strftime` format string (e.g. `"%Y-%m-%d %H:%M:%S"`)
+/// * `#[log_time_zone]` sets the default [`entrypoint::TimeZone`] of timestamps. Defaults to `utc`. Valid options are:
+///   * `utc`
+///   * `local`
+///   * a fixed offset, in seconds east of UTC (e.g. `-5 * 3600`)
+/// * `#[log_span_events]` sets the default span lifecycle [`tracing_subscriber::FmtSpan`] events (e.g. `#[log_span_events(new, close)]`).
+///   Defaults to `none`. Valid options are `new`, `enter`, `exit`, `close`, `active`, `full`, and `none`.
+/// * `#[log_with]`   toggles the logged fields (e.g. `#[log_with(file, line_number, target = false)]`).
+///   Valid options are `target` (defaults to `true`), `file`, `line_number`, `thread_ids`, and `thread_names` (default to `false`).
+/// * `#[log_json]`   customizes the `json` format (e.g. `#[log_json(flatten_event, with_span_list = false, rename(message = "msg"))]`).
+///   Valid options are `flatten_event` (defaults to `false`), `with_current_span` & `with_span_list` (default to `true`),
+///   and `rename` (i.e. `(from = "to", ...)` key renames; defaults to none).
 /// * `#[log_level]`  sets the default [`tracing_subscriber::LevelFilter`]. Defaults to [`DEFAULT_MAX_LEVEL`].
+///   The level is raised/lowered per any parsed [`entrypoint::Verbosity`] `-v`/`-q` flags.
+/// * `#[log_level_env]` sets the env var (read after dotenv processing) that overrides `#[log_level]`.
+///   Defaults to none. `LOG_LEVEL` is used if no var name is supplied.
+/// * `#[log_env_filter]` sets the default [`tracing_subscriber::EnvFilter`], built from `RUST_LOG` (or the supplied env var name)
+///   w/ `#[log_level]` as the default directive. Defaults to none (i.e. only `#[log_level]` is used).
+/// * `#[log_directives]` sets static per-target [`tracing_subscriber::EnvFilter`] directives (e.g. `"mycrate=debug,sqlx=warn"`)
+///   w/ `#[log_level]` as the default directive. `#[log_env_filter]` directives take precedence. Defaults to none.
 /// * `#[log_writer]` sets the default [`tracing_subscriber::MakeWriter`]. Defaults to [`std::io::stdout`].
+///   `#[log_writer(journald)]` writes to the systemd journal instead (requires the `journald` feature).
+///   `#[log_writer(syslog)]` writes to the default syslog target instead (requires the `syslog` feature).
+///   `#[log_writer(eventlog)]` writes to the Windows Event Log instead (requires the `eventlog` feature).
+///   `#[log_writer(split)]` writes `WARN`/`ERROR` to stderr and everything else to stdout (i.e. [`entrypoint::SplitWriter`]).
+///   `#[log_writer(console)]` writes to the browser console (i.e. `entrypoint::ConsoleWriter`; requires the `wasm` feature).
+///   `#[log_writer(entrypoint::testing::CaptureWriter::global)]` captures output in memory for tests.
+/// * `#[log_file]`   sets the default log file (i.e. anything `Into<PathBuf>`). Defaults to none.
+///   When both `#[log_writer]` and `#[log_file]` are supplied, logs are written to both.
+/// * `#[log_writer_level]` sets the [`tracing_subscriber::LevelFilter`] of the log writer (e.g. `#[log_writer_level(INFO)]`).
+///   Defaults to `#[log_level]`.
+/// * `#[log_file_level]` sets the [`tracing_subscriber::LevelFilter`] of the log file (e.g. `#[log_file_level(DEBUG)]`).
+///   Defaults to `#[log_level]`.
+/// * `#[log_rotation]` sets the default log file [`entrypoint::Rotation`]. Defaults to `Never`.
+/// * `#[log_non_blocking]` enables a non-blocking log writer. Defaults to blocking.
+/// * `#[log_rate_limit]` sets the max events per second, per callsite, written by the log writer (e.g. `#[log_rate_limit(100)]`).
+///   Suppressed events are periodically summarized. Defaults to unlimited.
+/// * `#[log_ring_buffer]` keeps the last N events, at any level, in memory (e.g. `#[log_ring_buffer(1000)]`).
+///   They're dumped to the log writer on an `ERROR` event, or if the entrypoint function fails. Defaults to none.
+/// * `#[log_redact]` redacts the values of env vars w/ matching keys from log output (e.g. `#[log_redact("*_SECRET", "PASSWORD")]`).
+///   Defaults to none. `*_SECRET`, `*_TOKEN`, `*_KEY`, and `*PASSWORD*` are used if no patterns are supplied.
+/// * `#[log_flame]`  sets the file to record flamegraph samples to (requires the `flame` feature). Defaults to none.
+/// * `#[log_app_metadata]` attaches `app.name`, `app.version`, `pid`, and `hostname` fields to events. Defaults to disabled.
+/// * `#[log_signals]` enables `SIGUSR1`/`SIGUSR2`/`SIGHUP` log level changes (requires the `signals` feature). Defaults to disabled.
 ///
 /// # Panics
 /// * `#[log_format]` has missing or malformed input
+/// * `#[log_timestamp]` has missing or malformed input
+/// * `#[log_time_zone]` has missing or malformed input
+/// * `#[log_span_events]` has missing or malformed input
+/// * `#[log_with]`   has missing or malformed input
+/// * `#[log_json]`   has missing or malformed input
 /// * `#[log_level]`  has missing or malformed input
+/// * `#[log_level_env]` has malformed input
+/// * `#[log_env_filter]` has malformed input
+/// * `#[log_directives]` has missing or malformed input
 /// * `#[log_writer]` has missing or malformed input
+/// * `#[log_file]`   has missing or malformed input
+/// * `#[log_writer_level]` has missing or malformed input
+/// * `#[log_file_level]` has missing or malformed input
+/// * `#[log_rotation]` has missing or malformed input
+/// * `#[log_rate_limit]` has missing or malformed input
+/// * `#[log_ring_buffer]` has missing or malformed input
+/// * `#[log_redact]` has malformed input
+/// * `#[log_flame]`  has missing or malformed input
 ///
 /// # Examples
 /// ```
@@ -81,57 +291,566 @@ pub fn derive_dotenv_parser(input: TokenStream) -> TokenStream {
 /// # //#FIXME - test writer #
 /// # //#FIXME - test level  # assert!(enabled!(entrypoint::Level::DEBUG));
 /// ```
+/// [`bunyan`]: https://docs.rs/tracing-bunyan-formatter
 /// [`compact`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Compact.html
 /// [`default`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Format.html#method.default
 /// [`full`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Full.html
 /// [`json`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Json.html
+/// [`logfmt`]: https://brandur.org/logfmt
 /// [`pretty`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Pretty.html
+/// [`tree`]: https://docs.rs/tracing-tree
 /// [`DEFAULT_MAX_LEVEL`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/struct.Subscriber.html#associatedconstant.DEFAULT_MAX_LEVEL
 /// [`std::io::stdout`]: https://doc.rust-lang.org/std/io/fn.stdout.html
 /// [`entrypoint::LoggerConfig`]: https://docs.rs/entrypoint/latest/entrypoint/trait.LoggerConfig.html
+/// [`entrypoint::Rotation`]: https://docs.rs/entrypoint/latest/entrypoint/enum.Rotation.html
+/// [`entrypoint::SplitWriter`]: https://docs.rs/entrypoint/latest/entrypoint/struct.SplitWriter.html
+/// [`entrypoint::Timer`]: https://docs.rs/entrypoint/latest/entrypoint/enum.Timer.html
+/// [`entrypoint::TimeZone`]: https://docs.rs/entrypoint/latest/entrypoint/enum.TimeZone.html
+/// [`entrypoint::Verbosity`]: https://docs.rs/entrypoint/latest/entrypoint/struct.Verbosity.html
 /// [`tracing_subscriber::Format`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.Format.html
+/// [`tracing_subscriber::FmtSpan`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/format/struct.FmtSpan.html
+/// [`tracing_subscriber::EnvFilter`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html
 /// [`tracing_subscriber::LevelFilter`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.LevelFilter.html
 /// [`tracing_subscriber::MakeWriter`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/writer/trait.MakeWriter.html
-#[proc_macro_derive(LoggerDefault, attributes(log_format, log_level, log_writer))]
+#[allow(clippy::too_many_lines)]
+#[proc_macro_derive(
+    LoggerDefault,
+    attributes(
+        log_format,
+        log_timestamp,
+        log_time_zone,
+        log_span_events,
+        log_with,
+        log_json,
+        log_level,
+        log_level_env,
+        log_env_filter,
+        log_directives,
+        log_writer,
+        log_file,
+        log_writer_level,
+        log_file_level,
+        log_rotation,
+        log_non_blocking,
+        log_rate_limit,
+        log_ring_buffer,
+        log_redact,
+        log_flame,
+        log_app_metadata,
+        log_signals
+    )
+)]
 pub fn derive_logger(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
-    let mut log_format: syn::ExprCall = parse_quote! { clone() };
+    let mut log_format: syn::Expr = parse_quote! { self.default_log_format_base() };
+    let mut log_format_json = false;
+    let mut log_format_tree = false;
+    let mut log_format_bunyan = false;
     let mut log_level: syn::ExprPath =
         parse_quote! { tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL };
     let mut log_writer: syn::ExprPath = parse_quote! { std::io::stdout };
+    let mut log_tee = false;
+    let mut log_journald = false;
+    let mut log_syslog = false;
+    let mut log_eventlog = false;
+    let mut log_file: Option<syn::Expr> = None;
+    let mut log_writer_level: Option<syn::ExprPath> = None;
+    let mut log_file_level: Option<syn::ExprPath> = None;
+    let mut log_rotation: Option<syn::Expr> = None;
+    let mut log_flame: Option<syn::Expr> = None;
+    let mut log_signals = false;
+    let mut log_app_metadata = false;
+    let mut log_timestamp: Option<syn::Expr> = None;
+    let mut log_time_zone: Option<syn::Expr> = None;
+    let mut log_span_events: Option<Punctuated<Ident, Token![,]>> = None;
+    let mut log_with: Punctuated<syn::Meta, Token![,]> = Punctuated::new();
+    let mut log_json: Punctuated<syn::Meta, Token![,]> = Punctuated::new();
+    let mut log_non_blocking = false;
+    let mut log_rate_limit: Option<syn::Expr> = None;
+    let mut log_ring_buffer: Option<syn::Expr> = None;
+    let mut log_redact: Option<Punctuated<syn::LitStr, Token![,]>> = None;
+    let mut log_level_env: Option<syn::LitStr> = None;
+    let mut log_env_filter: Option<syn::LitStr> = None;
+    let mut log_directives: Option<syn::LitStr> = None;
 
     for attr in input.attrs {
         if attr.path().is_ident("log_format") {
-            let key: syn::ExprPath = attr
+            let key: syn::Meta = attr
                 .parse_args()
                 .expect("required log_format input parameter is missing or malformed");
-            log_format = if key.path.is_ident("compact") {
-                parse_quote! { compact() }
-            } else if key.path.is_ident("default") || key.path.is_ident("full") {
-                parse_quote! { clone() }
-            } else if key.path.is_ident("json") {
-                parse_quote! { json() }
-            } else if key.path.is_ident("pretty") {
-                parse_quote! { pretty() }
+            let key = match key {
+                syn::Meta::Path(path) => path,
+                syn::Meta::NameValue(custom) if custom.path.is_ident("custom") => {
+                    let formatter = custom.value;
+                    log_format = parse_quote! { #formatter(self) };
+                    continue;
+                }
+                syn::Meta::NameValue(_) | syn::Meta::List(_) => {
+                    panic!("log_format input parameter is malformed")
+                }
+            };
+            log_format = if key.is_ident("bunyan") {
+                log_format_bunyan = true;
+                parse_quote! { self.default_log_format_base() }
+            } else if key.is_ident("compact") {
+                parse_quote! { self.default_log_format_base().compact() }
+            } else if key.is_ident("default") || key.is_ident("full") {
+                parse_quote! { self.default_log_format_base() }
+            } else if key.is_ident("json") {
+                log_format_json = true;
+                parse_quote! { self.default_log_format_json() }
+            } else if key.is_ident("logfmt") {
+                parse_quote! { self.default_log_format_logfmt() }
+            } else if key.is_ident("tree") {
+                log_format_tree = true;
+                parse_quote! { self.default_log_format_base() }
+            } else if key.is_ident("pretty") {
+                parse_quote! { self.default_log_format_base().pretty() }
             } else {
                 panic!(
                     "log_format input parameter is unknown type: {:?}",
-                    key.path.get_ident()
+                    key.get_ident()
                 );
             };
+        } else if attr.path().is_ident("log_timestamp") {
+            let key: syn::Expr = attr
+                .parse_args()
+                .expect("required log_timestamp input parameter is missing or malformed");
+            log_timestamp = Some(match key {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(format),
+                    ..
+                }) => parse_quote! { entrypoint::Timer::Custom(String::from(#format)) },
+                syn::Expr::Path(key) if key.path.is_ident("rfc3339") => {
+                    parse_quote! { entrypoint::Timer::Rfc3339 }
+                }
+                syn::Expr::Path(key) if key.path.is_ident("epoch") => {
+                    parse_quote! { entrypoint::Timer::Epoch }
+                }
+                syn::Expr::Path(key) if key.path.is_ident("none") => {
+                    parse_quote! { entrypoint::Timer::None }
+                }
+                _ => panic!("log_timestamp input parameter is unknown type"),
+            });
+        } else if attr.path().is_ident("log_time_zone") {
+            let key: syn::Expr = attr
+                .parse_args()
+                .expect("required log_time_zone input parameter is missing or malformed");
+            log_time_zone = Some(match key {
+                syn::Expr::Path(key) if key.path.is_ident("utc") => {
+                    parse_quote! { entrypoint::TimeZone::Utc }
+                }
+                syn::Expr::Path(key) if key.path.is_ident("local") => {
+                    parse_quote! { entrypoint::TimeZone::Local }
+                }
+                offset => parse_quote! { entrypoint::TimeZone::Fixed(#offset) },
+            });
+        } else if attr.path().is_ident("log_span_events") {
+            log_span_events = Some(
+                attr.parse_args_with(Punctuated::parse_terminated)
+                    .expect("required log_span_events input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_with") {
+            log_with.extend(
+                attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                    .expect("required log_with input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_json") {
+            log_json.extend(
+                attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                    .expect("required log_json input parameter is missing or malformed"),
+            );
         } else if attr.path().is_ident("log_level") {
             log_level = attr
                 .parse_args()
                 .expect("required log_level input parameter is missing or malformed");
+        } else if attr.path().is_ident("log_level_env") {
+            log_level_env = Some(match attr.meta {
+                syn::Meta::Path(_) => parse_quote! { "LOG_LEVEL" },
+                _ => attr
+                    .parse_args()
+                    .expect("log_level_env input parameter is malformed"),
+            });
+        } else if attr.path().is_ident("log_env_filter") {
+            log_env_filter = Some(match attr.meta {
+                syn::Meta::Path(_) => parse_quote! { "RUST_LOG" },
+                _ => attr
+                    .parse_args()
+                    .expect("log_env_filter input parameter is malformed"),
+            });
+        } else if attr.path().is_ident("log_directives") {
+            log_directives = Some(
+                attr.parse_args()
+                    .expect("required log_directives input parameter is missing or malformed"),
+            );
         } else if attr.path().is_ident("log_writer") {
-            log_writer = attr
+            let key: syn::ExprPath = attr
                 .parse_args()
                 .expect("required log_writer input parameter is missing or malformed");
+            if key.path.is_ident("journald") {
+                log_journald = true;
+            } else if key.path.is_ident("syslog") {
+                log_syslog = true;
+            } else if key.path.is_ident("eventlog") {
+                log_eventlog = true;
+            } else if key.path.is_ident("split") {
+                log_writer = parse_quote! { entrypoint::SplitWriter };
+                log_tee = true;
+            } else if key.path.is_ident("console") {
+                log_writer = parse_quote! { entrypoint::ConsoleWriter };
+                log_tee = true;
+            } else {
+                log_writer = key;
+                log_tee = true;
+            }
+        } else if attr.path().is_ident("log_file") {
+            log_file = Some(
+                attr.parse_args()
+                    .expect("required log_file input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_rotation") {
+            log_rotation = Some(
+                attr.parse_args()
+                    .expect("required log_rotation input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_flame") {
+            log_flame = Some(
+                attr.parse_args()
+                    .expect("required log_flame input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_writer_level") {
+            log_writer_level = Some(
+                attr.parse_args()
+                    .expect("required log_writer_level input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_file_level") {
+            log_file_level = Some(
+                attr.parse_args()
+                    .expect("required log_file_level input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_non_blocking") {
+            log_non_blocking = true;
+        } else if attr.path().is_ident("log_rate_limit") {
+            log_rate_limit = Some(
+                attr.parse_args()
+                    .expect("required log_rate_limit input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_ring_buffer") {
+            log_ring_buffer = Some(
+                attr.parse_args()
+                    .expect("required log_ring_buffer input parameter is missing or malformed"),
+            );
+        } else if attr.path().is_ident("log_redact") {
+            log_redact = Some(match attr.meta {
+                syn::Meta::Path(_) => parse_quote! { "*_SECRET", "*_TOKEN", "*_KEY", "*PASSWORD*" },
+                _ => attr
+                    .parse_args_with(Punctuated::parse_terminated)
+                    .expect("log_redact input parameter is malformed"),
+            });
+        } else if attr.path().is_ident("log_app_metadata") {
+            log_app_metadata = true;
+        } else if attr.path().is_ident("log_signals") {
+            log_signals = true;
         }
     }
 
+    let log_file = log_file.map(|log_file| {
+        let log_tee = log_tee.then(|| {
+            quote! {
+              fn default_log_tee(&self) -> bool {
+                  true
+              }
+            }
+        });
+
+        quote! {
+          fn default_log_file(&self) -> Option<std::path::PathBuf> {
+              Some(std::path::PathBuf::from(#log_file))
+          }
+
+          #log_tee
+        }
+    });
+
+    let log_rotation = log_rotation.map(|log_rotation| {
+        quote! {
+          fn default_log_rotation(&self) -> entrypoint::Rotation {
+              #log_rotation
+          }
+        }
+    });
+
+    let log_level_env = log_level_env.map(|var| {
+        quote! {
+          fn default_log_level_env(&self) -> Option<String> {
+              Some(String::from(#var))
+          }
+        }
+    });
+
+    let env_filter = (log_env_filter.is_some() || log_directives.is_some()).then(|| {
+        let directives = log_directives.map_or_else(|| quote! { "" }, |d| quote! { #d });
+        let env = log_env_filter.map_or_else(
+            || quote! { "" },
+            |var| quote! { std::env::var(#var).unwrap_or_default() },
+        );
+
+        quote! {
+          fn default_env_filter(&self) -> Option<entrypoint::tracing_subscriber::EnvFilter> {
+              // later directives take precedence, so #[log_level] is only the fallback
+              Some(entrypoint::tracing_subscriber::EnvFilter::builder().parse_lossy(format!(
+                  "{},{},{}",
+                  self.default_log_level(),
+                  #directives,
+                  #env
+              )))
+          }
+        }
+    });
+
+    let log_journald = log_journald.then(|| {
+        quote! {
+          fn default_log_journald(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_syslog = log_syslog.then(|| {
+        quote! {
+          fn default_log_syslog(&self) -> Option<entrypoint::Syslog> {
+              Some(entrypoint::Syslog::default())
+          }
+        }
+    });
+
+    let log_eventlog = log_eventlog.then(|| {
+        quote! {
+          fn default_log_eventlog(&self) -> Option<String> {
+              Some(<Self as entrypoint::clap::CommandFactory>::command().get_name().to_owned())
+          }
+        }
+    });
+
+    let log_flame = log_flame.map(|log_flame| {
+        quote! {
+          fn default_log_flame(&self) -> Option<std::path::PathBuf> {
+              Some(std::path::PathBuf::from(#log_flame))
+          }
+        }
+    });
+
+    let log_redact = log_redact.map(|patterns| {
+        let patterns = patterns.into_iter();
+        quote! {
+          fn default_log_redact(&self) -> Vec<String> {
+              vec![#(String::from(#patterns)),*]
+          }
+        }
+    });
+
+    let log_app_metadata = log_app_metadata.then(|| {
+        quote! {
+          fn default_log_app_metadata(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_signals = log_signals.then(|| {
+        quote! {
+          fn default_log_signals(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_timestamp = log_timestamp.map(|log_timestamp| {
+        quote! {
+          fn default_log_timer(&self) -> entrypoint::Timer {
+              #log_timestamp
+          }
+        }
+    });
+
+    let log_time_zone = log_time_zone.map(|log_time_zone| {
+        quote! {
+          fn default_log_time_zone(&self) -> entrypoint::TimeZone {
+              #log_time_zone
+          }
+        }
+    });
+
+    let log_span_events = log_span_events.map(|log_span_events| {
+        let log_span_events = log_span_events.into_iter().map(|key| {
+            ["new", "enter", "exit", "close", "active", "full", "none"]
+                .into_iter()
+                .find(|span| key == span)
+                .map_or_else(
+                    || panic!("log_span_events input parameter is unknown type: {key}"),
+                    |span| format_ident!("{}", span.to_ascii_uppercase()),
+                )
+        });
+
+        quote! {
+          fn default_log_span_events(&self) -> entrypoint::tracing_subscriber::fmt::format::FmtSpan {
+              entrypoint::tracing_subscriber::fmt::format::FmtSpan::NONE
+                  #(| entrypoint::tracing_subscriber::fmt::format::FmtSpan::#log_span_events)*
+          }
+        }
+    });
+
+    let log_with = log_with.into_iter().map(|meta| {
+        let (path, value): (Path, syn::Expr) = match meta {
+            syn::Meta::Path(path) => (path, parse_quote! { true }),
+            syn::Meta::NameValue(kv) => (kv.path, kv.value),
+            syn::Meta::List(_) => panic!("log_with input parameter is malformed"),
+        };
+        let key = path
+            .get_ident()
+            .expect("log_with input parameter is malformed");
+
+        assert!(
+            [
+                "target",
+                "file",
+                "line_number",
+                "thread_ids",
+                "thread_names"
+            ]
+            .into_iter()
+            .any(|valid| key == valid),
+            "log_with input parameter is unknown type: {key}"
+        );
+
+        let method = format_ident!("default_log_with_{}", key);
+        quote! {
+          fn #method(&self) -> bool {
+              #value
+          }
+        }
+    });
+
+    let log_json = log_json.into_iter().map(|meta| match meta {
+        syn::Meta::List(list) if list.path.is_ident("rename") => {
+            let keys = list
+                .parse_args_with(Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated)
+                .expect("log_json rename input parameter is malformed");
+            let keys = keys.into_iter().map(|kv| {
+                let from = kv
+                    .path
+                    .get_ident()
+                    .expect("log_json rename input parameter is malformed");
+                let to = kv.value;
+                quote! { (String::from(stringify!(#from)), String::from(#to)) }
+            });
+
+            quote! {
+              fn default_log_json_rename(&self) -> Vec<(String, String)> {
+                  vec![#(#keys),*]
+              }
+            }
+        }
+        meta => {
+            let (path, value): (Path, syn::Expr) = match meta {
+                syn::Meta::Path(path) => (path, parse_quote! { true }),
+                syn::Meta::NameValue(kv) => (kv.path, kv.value),
+                syn::Meta::List(_) => panic!("log_json input parameter is malformed"),
+            };
+            let key = path
+                .get_ident()
+                .expect("log_json input parameter is malformed");
+
+            assert!(
+                ["flatten_event", "with_current_span", "with_span_list"]
+                    .into_iter()
+                    .any(|valid| key == valid),
+                "log_json input parameter is unknown type: {key}"
+            );
+
+            let method = format_ident!("default_log_json_{}", key);
+            quote! {
+              fn #method(&self) -> bool {
+                  #value
+              }
+            }
+        }
+    });
+
+    let log_format_json = log_format_json.then(|| {
+        quote! {
+          fn default_log_json(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_format_bunyan = log_format_bunyan.then(|| {
+        quote! {
+          fn default_log_bunyan(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_format_tree = log_format_tree.then(|| {
+        quote! {
+          fn default_log_tree(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    let log_non_blocking = log_non_blocking.then(|| {
+        quote! {
+          fn default_log_non_blocking(&self) -> bool {
+              true
+          }
+        }
+    });
+
+    // bare levels (e.g. `DEBUG`) are shorthand for `entrypoint::LevelFilter::DEBUG`
+    let level_path = |level: syn::ExprPath| -> syn::ExprPath {
+        level.path.get_ident().cloned().map_or(
+            level,
+            |level| parse_quote! { entrypoint::LevelFilter::#level },
+        )
+    };
+
+    let log_writer_level = log_writer_level.map(level_path).map(|level| {
+        quote! {
+          fn default_log_writer_level(&self) -> Option<entrypoint::LevelFilter> {
+              Some(#level)
+          }
+        }
+    });
+
+    let log_file_level = log_file_level.map(level_path).map(|level| {
+        quote! {
+          fn default_log_file_level(&self) -> Option<entrypoint::LevelFilter> {
+              Some(#level)
+          }
+        }
+    });
+
+    let log_rate_limit = log_rate_limit.map(|log_rate_limit| {
+        quote! {
+          fn default_log_rate_limit(&self) -> Option<u32> {
+              Some(#log_rate_limit)
+          }
+        }
+    });
+
+    let log_ring_buffer = log_ring_buffer.map(|log_ring_buffer| {
+        quote! {
+          fn default_log_ring_buffer(&self) -> Option<usize> {
+              Some(#log_ring_buffer)
+          }
+        }
+    });
+
     let output = quote! {
       impl entrypoint::LoggerConfig for #name {
           fn default_log_format<S, N>(&self) -> impl FormatEvent<S, N> + Send + Sync + 'static
@@ -139,33 +858,245 @@ pub fn derive_logger(input: TokenStream) -> TokenStream {
               S: Subscriber + for<'a> LookupSpan<'a>,
               N: for<'writer> FormatFields<'writer> + 'static,
           {
-              Format::default().#log_format
+              #log_format
           }
 
           fn default_log_level(&self) -> entrypoint::tracing_subscriber::filter::LevelFilter {
-              #log_level
+              entrypoint::Verbosity::parsed_log_level(
+                  self.default_log_level_env()
+                      .and_then(|var| std::env::var(var).ok())
+                      .and_then(|level| level.parse().ok())
+                      .unwrap_or(#log_level),
+              )
           }
 
           fn default_log_writer(&self) -> impl for<'writer> MakeWriter<'writer> + Send + Sync + 'static {
               #log_writer
           }
+
+          #log_format_json
+          #log_format_tree
+          #log_format_bunyan
+          #log_timestamp
+          #log_time_zone
+          #log_span_events
+          #(#log_with)*
+          #(#log_json)*
+          #log_level_env
+          #env_filter
+          #log_file
+          #log_writer_level
+          #log_file_level
+          #log_rotation
+          #log_non_blocking
+          #log_rate_limit
+          #log_ring_buffer
+          #log_redact
+          #log_journald
+          #log_syslog
+          #log_eventlog
+          #log_flame
+          #log_app_metadata
+          #log_signals
+      }
+    };
+
+    TokenStream::from(output)
+}
+
+/// derive [`entrypoint::Dispatch`] for a (clap) subcommand enum, routing each variant to its own handler function
+///
+/// Each variant calls a handler function named after it, in snake case (e.g. `ServeHttp` calls `serve_http`),
+/// w/ a reference to the shared args and to each of the variant's fields (in order).
+/// Handlers return `anyhow::Result<()>`.
+///
+/// # Attributes
+/// * `#[dispatch]` on the enum sets the shared args type (e.g. `#[dispatch(Args)]`). Required.
+/// * `#[dispatch]` on a variant sets its handler function (e.g. `#[dispatch(commands::serve)]`).
+///   Defaults to the snake case variant name.
+///
+/// # Panics
+/// * not an enum
+/// * `#[dispatch]` is missing from the enum
+/// * `#[dispatch]` has missing or malformed input
+///
+/// # Examples
+/// ```
+/// # use entrypoint::prelude::*;
+/// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone)]
+/// struct Args {
+///     #[arg(long)]
+///     verbose: bool,
+///
+///     #[command(subcommand)]
+///     command: Command,
+/// }
+///
+/// #[derive(clap::Subcommand, Dispatch, Clone)]
+/// #[dispatch(Args)]
+/// enum Command {
+///     Add { name: String },
+///     #[dispatch(list_all)]
+///     List,
+/// }
+///
+/// fn add(args: &Args, name: &String) -> anyhow::Result<()> {
+///     anyhow::ensure!(!name.is_empty(), "empty name");
+///     Ok(())
+/// }
+///
+/// fn list_all(args: &Args) -> anyhow::Result<()> {
+///     anyhow::bail!("nothing to list")
+/// }
+///
+/// let args = Args::parse_from(["app", "add", "widget"]);
+/// assert!(args.command.dispatch(&args).is_ok());
+///
+/// let args = Args::parse_from(["app", "list"]);
+/// assert!(args.command.dispatch(&args).is_err());
+/// ```
+/// [`entrypoint::Dispatch`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Dispatch.html
+#[proc_macro_derive(Dispatch, attributes(dispatch))]
+pub fn derive_dispatch(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let args: Type = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("dispatch"))
+        .expect("required dispatch attribute (i.e. the shared args type) is missing")
+        .parse_args()
+        .expect("dispatch input parameter is missing or malformed");
+
+    let syn::Data::Enum(data) = input.data else {
+        panic!("Dispatch can only be derived for enums");
+    };
+
+    let arms = data.variants.into_iter().map(|variant| {
+        let handler: Path = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("dispatch"))
+            .map_or_else(
+                || {
+                    format_ident!("{}", SnakeCase(&variant.ident), span = variant.ident.span())
+                        .into()
+                },
+                |attr| {
+                    attr.parse_args()
+                        .expect("dispatch input parameter is missing or malformed")
+                },
+            );
+
+        let variant_name = variant.ident;
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                field
+                    .ident
+                    .clone()
+                    .unwrap_or_else(|| format_ident!("field{i}"))
+            })
+            .collect::<Punctuated<_, Token![,]>>();
+        let pattern = match variant.fields {
+            syn::Fields::Named(_) => quote! { Self::#variant_name { #fields } },
+            syn::Fields::Unnamed(_) => quote! { Self::#variant_name(#fields) },
+            syn::Fields::Unit => quote! { Self::#variant_name },
+        };
+        let fields = fields.iter();
+
+        quote! {
+          #pattern => {
+              entrypoint::tracing::debug!("dispatching {} subcommand", stringify!(#variant_name));
+              #handler(args #(, #fields)*)
+          }
+        }
+    });
+
+    let output = quote! {
+      impl #impl_generics entrypoint::Dispatch<#args> for #name #type_generics #where_clause {
+          fn dispatch(&self, args: &#args) -> entrypoint::anyhow::Result<()> {
+              match self {
+                  #(#arms)*
+              }
+          }
       }
     };
 
     TokenStream::from(output)
 }
 
+/// an `UpperCamelCase` identifier, formatted in `snake_case` (i.e. w/o allocating; this crate is `no_std`)
+struct SnakeCase<'a>(&'a Ident);
+
+impl quote::IdentFragment for SnakeCase<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        /// lowercases chars, prefixing (non-leading) uppercase chars w/ `_`
+        struct Writer<'a, 'b> {
+            f: &'a mut core::fmt::Formatter<'b>,
+            leading: bool,
+        }
+
+        impl core::fmt::Write for Writer<'_, '_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                for c in s.chars() {
+                    if c.is_uppercase() && !self.leading {
+                        self.f.write_char('_')?;
+                    }
+                    self.leading = false;
+                    for c in c.to_lowercase() {
+                        self.f.write_char(c)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        core::fmt::Write::write_fmt(&mut Writer { f, leading: true }, format_args!("{}", self.0))
+    }
+}
+
 /// marks function as [`entrypoint`] `function` (i.e. the `main()` replacement)
 ///
 /// **Ordering may matter when used with other attribute macros.**
 ///
+/// An optional second parameter receives a typed (i.e. [`serde`](https://serde.rs)) config,
+/// extracted from the environment (refer to [`entrypoint_with_config`]).
+/// With the `env` argument (i.e. `#[entrypoint::entrypoint(env)]`), the second parameter is an `EnvMap` of the
+/// isolated dotenv variables instead (refer to [`entrypoint_with_env`]).
+/// With the `context` argument (i.e. `#[entrypoint::entrypoint(context)]`), the second parameter is an
+/// `entrypoint::Context` w/ the setup context instead (refer to [`entrypoint_with_context`]).
+/// With the `config_files` argument (i.e. `#[entrypoint::entrypoint(config_files)]`), the config is merged from
+/// config files & the environment instead (refer to [`entrypoint_with_config_files`]).
+///
+/// The generated `main` reports the function's output via [`Entrypoint::exit`], so any
+/// [`Termination`](std::process::Termination) value (e.g. [`ExitCode`](std::process::ExitCode)) sets the process exit code,
+/// and errors are mapped to an exit code via [`EntrypointConfig::exit_code`].
+///
+/// With the `tokio` argument (i.e. `#[entrypoint::entrypoint(tokio)]`) & the `tokio` [feature](https://docs.rs/entrypoint/latest/entrypoint/#feature-flags),
+/// an `async` function is run on a tokio runtime, built after setup (the args must implement [`RuntimeConfig`]).
+/// This replaces stacking `#[tokio::main]` (where ordering matters).
+/// Likewise, the `async_std` & `smol` arguments (w/ the `async-std` & `smol` features) run an `async` function on those runtimes.
+///
+/// With the `timeout` argument (e.g. `#[entrypoint::entrypoint(timeout = "30s")]`), the process is aborted if the
+/// function doesn't return in time (refer to [`EntrypointConfig::execution_timeout`]).
+/// Units are `ms`, `s`, `m`, and `h`.
+///
 /// # Panics
-/// * candidate function has missing or malformed input parameter
+/// * candidate function has missing or malformed input parameter(s)
+/// * unexpected argument (i.e. other than `config_files`/`env`/`context`, `tokio`/`async_std`/`smol`, & `timeout`)
+/// * more than one of `config_files`, `env`, or `context`, or one of them w/o a second parameter
+/// * runtime argument w/o an `async` function (or vice versa), or more than one runtime argument
+/// * malformed `timeout` argument
 ///
 /// # Examples
 /// ```
 /// # use entrypoint::prelude::*;
-/// #[derive(clap::Parser, DotEnvDefault, LoggerDefault, Debug)]
+/// #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug)]
 /// struct Args {}
 ///
 /// // this function replaces `main`
@@ -176,62 +1107,216 @@ pub fn derive_logger(input: TokenStream) -> TokenStream {
 ///     Ok(())
 /// }
 /// ```
+///
+/// Exit w/ a specific code:
+/// ```no_run
+/// # use entrypoint::prelude::*;
+/// # #[derive(clap::Parser, DotEnvDefault, EntrypointDefault, LoggerDefault, Clone, Debug)]
+/// # struct Args {}
+/// #[entrypoint::entrypoint]
+/// fn main(args: Args) -> entrypoint::anyhow::Result<std::process::ExitCode> {
+///     # let args = args;
+///     // e.g. partial failure
+///     Ok(std::process::ExitCode::from(3))
+/// }
+/// ```
 /// [`entrypoint`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.entrypoint
+/// [`entrypoint_with_config`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.entrypoint_with_config
+/// [`entrypoint_with_env`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.entrypoint_with_env
+/// [`entrypoint_with_context`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.entrypoint_with_context
+/// [`entrypoint_with_config_files`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.entrypoint_with_config_files
+/// [`Entrypoint::exit`]: https://docs.rs/entrypoint/latest/entrypoint/trait.Entrypoint.html#method.exit
+/// [`EntrypointConfig::exit_code`]: https://docs.rs/entrypoint/latest/entrypoint/trait.EntrypointConfig.html#method.exit_code
+/// [`RuntimeConfig`]: https://docs.rs/entrypoint/latest/entrypoint/trait.RuntimeConfig.html
+/// [`EntrypointConfig::execution_timeout`]: https://docs.rs/entrypoint/latest/entrypoint/trait.EntrypointConfig.html#method.execution_timeout
+#[allow(clippy::too_many_lines)]
 #[proc_macro_attribute]
-pub fn entrypoint(_args: TokenStream, item: TokenStream) -> TokenStream {
+pub fn entrypoint(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut method: Option<Ident> = None;
+    let mut runtime: Option<Ident> = None;
+    let mut timeout: Option<syn::Stmt> = None;
+    for arg in parse_macro_input!(args with Punctuated::<syn::Meta, Token![,]>::parse_terminated) {
+        match arg {
+            syn::Meta::Path(path)
+                if ["config_files", "env", "context"]
+                    .iter()
+                    .any(|param| path.is_ident(param)) =>
+            {
+                assert!(
+                    method.is_none(),
+                    "more than one second parameter argument; expected one of `config_files`, `env`, or `context`"
+                );
+                method = path
+                    .get_ident()
+                    .map(|param| format_ident!("entrypoint_with_{}", param));
+            }
+            syn::Meta::Path(path)
+                if ["tokio", "async_std", "smol"]
+                    .iter()
+                    .any(|runtime| path.is_ident(runtime)) =>
+            {
+                assert!(
+                    runtime.is_none(),
+                    "more than one runtime argument; expected one of `tokio`, `async_std`, or `smol`"
+                );
+                runtime = path.get_ident().cloned();
+            }
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("timeout") => {
+                let millis = parse_duration_millis(&value.value())
+                    .expect("timeout argument is malformed; expected e.g. \"500ms\", \"30s\", \"5m\", or \"1h\"");
+                timeout = Some(parse_quote! {
+                    let _watchdog = entrypoint::Watchdog::start(std::time::Duration::from_millis(#millis));
+                });
+            }
+            _ => panic!(
+                "unexpected entrypoint argument; expected `config_files`/`env`/`context`, `tokio`/`async_std`/`smol`, or `timeout = \"..\"`"
+            ),
+        }
+    }
     let tokens = parse_macro_input!(item as ItemFn);
+    assert!(
+        runtime.is_some() == tokens.sig.asyncness.is_some(),
+        "a runtime argument (i.e. `tokio`, `async_std`, or `smol`) requires an `async` function (& vice versa)"
+    );
 
     let attrs = { tokens.attrs };
 
     // you think there'd be a cleaner/easier way to do this...
-    let (input_param_ident, input_param_type) = {
-        let mut input_param_ident: Option<Ident> = None;
-        let mut input_param_type: Option<Path> = None;
-
-        for input in tokens.sig.inputs.clone() {
-            if let FnArg::Typed(PatType {
-                pat: name,
-                ty: r#type,
-                ..
-            }) = input
+    let mut input_params = tokens.sig.inputs.iter().filter_map(|input| {
+        if let FnArg::Typed(PatType {
+            pat: name,
+            ty: r#type,
+            ..
+        }) = input
+        {
+            // 2nd match to get boxed values
+            if let (
+                Pat::Ident(PatIdent { ident: name, .. }),
+                Type::Path(TypePath { path: r#type, .. }),
+            ) = (name.as_ref(), r#type.as_ref())
             {
-                match (*name, *r#type) {
-                    // 2nd match to get boxed values
-                    (
-                        Pat::Ident(PatIdent { ident: name, .. }),
-                        Type::Path(TypePath { path: r#type, .. }),
-                    ) => {
-                        input_param_ident = Some(name);
-                        input_param_type = Some(r#type.clone());
-                    }
-                    (_, _) => {
-                        continue;
-                    }
-                }
+                return Some((name.clone(), r#type.clone()));
             }
         }
+        None
+    });
 
-        (
-            input_param_ident.expect("required entrypoint input parameter is missing or malformed"),
-            input_param_type.expect("required entrypoint input parameter is missing or malformed"),
-        )
+    // args, then an optional config
+    let (mut input_param_ident, input_param_type) = input_params
+        .next()
+        .expect("required entrypoint input parameter is missing or malformed");
+    let mut config_param = input_params.next();
+    assert!(
+        input_params.next().is_none(),
+        "unexpected entrypoint input parameter(s); expected args & an optional config"
+    );
+
+    // keep the declared output (e.g. `anyhow::Result<ExitCode>`) for the function itself
+    let function_output: Type = match &tokens.sig.output {
+        ReturnType::Default => parse_quote! { entrypoint::anyhow::Result<()> },
+        ReturnType::Type(_, output) => output.as_ref().clone(),
     };
 
+    // `main` reports the result (i.e. errors are mapped to an exit code)
     let signature = {
         let mut signature = tokens.sig.clone();
         signature.ident = format_ident!("main");
+        signature.asyncness = None;
         signature.inputs.clear();
-        signature.output = parse_quote! {-> entrypoint::anyhow::Result<()>};
+        signature.output = parse_quote! {-> std::process::ExitCode};
         signature
     };
 
-    let block = { tokens.block };
+    // the (async) function is run on the runtime, w/in the (sync) setup closure
+    let block = if let Some(runtime) = runtime {
+        let function = {
+            let mut function = tokens.sig.clone();
+            function.ident = format_ident!("function");
+            function
+        };
+        let block = tokens.block;
+
+        // the function binds the declared params (e.g. `_args`); the closure passes them through
+        input_param_ident = format_ident!("args");
+        if let Some((config_param_ident, _)) = config_param.as_mut() {
+            *config_param_ident = format_ident!("config");
+        }
+        let params = config_param
+            .iter()
+            .map(|(config_param_ident, _)| config_param_ident);
+        let function_call = quote! { function(#input_param_ident #(, #params)*) };
+        let block_on = if runtime == "tokio" {
+            quote! { entrypoint::RuntimeConfig::tokio_runtime(&#input_param_ident)?.block_on(#function_call) }
+        } else if runtime == "async_std" {
+            quote! { entrypoint::async_std::task::block_on(#function_call) }
+        } else {
+            quote! { entrypoint::smol::block_on(#function_call) }
+        };
+        quote! {{
+          #timeout
+          #function #block
+          #block_on
+        }}
+    } else {
+        // w/in the function's own block (i.e. no extra braces around single expression bodies)
+        let mut block = tokens.block;
+        block.stmts.splice(0..0, timeout);
+        quote! { #block }
+    };
+
+    let output = quote! {
+      #(#attrs)*
+      #signature {
+        <#input_param_type as entrypoint::Entrypoint>::exit(
+          <#input_param_type as entrypoint::Entrypoint>::parse_args()
+            .entrypoint(|#input_param_ident| -> #function_output #block)
+        )
+      }
+    };
+
+    let Some((config_param_ident, config_param_type)) = config_param else {
+        assert!(
+            method.is_none(),
+            "a `config_files`, `env`, or `context` argument requires a second entrypoint input parameter"
+        );
+        return output.into();
+    };
+
+    // the argument (not the param's type) picks what the second param receives; a typed config by default
+    let method = method.unwrap_or_else(|| format_ident!("entrypoint_with_config"));
 
     quote! {
       #(#attrs)*
       #signature {
-        <#input_param_type as entrypoint::clap::Parser>::parse().entrypoint(|#input_param_ident| { #block })
+        <#input_param_type as entrypoint::Entrypoint>::exit(
+          <#input_param_type as entrypoint::Entrypoint>::parse_args()
+            .#method(|#input_param_ident, #config_param_ident: #config_param_type| -> #function_output #block)
+        )
       }
     }
     .into()
 }
+
+/// parse a duration (e.g. `500ms`, `30s`, `5m`, `1h`) into milliseconds
+fn parse_duration_millis(duration: &str) -> Option<u64> {
+    let split = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (value, unit) = duration.split_at(split);
+    let value: u64 = value.parse().ok()?;
+
+    let millis = match unit.trim() {
+        "ms" => 1,
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        _ => return None,
+    };
+    value.checked_mul(millis)
+}